@@ -2,10 +2,12 @@
 // See Notices.txt for copyright information
 
 use crate::{
-    interval_arithmetic::DyadicFractionInterval,
-    polynomial::Polynomial,
-    traits::{AlwaysExactDiv, AlwaysExactDivAssign, CeilLog2, ExactDiv, ExactDivAssign, FloorLog2},
-    util::{DebugAsDisplay, Sign},
+    interval_arithmetic::{format_decimal, DyadicFractionInterval},
+    polynomial::{Polynomial, RootIsolationAlgorithm},
+    traits::{
+        AlwaysExactDiv, AlwaysExactDivAssign, CeilLog2, ExactDiv, ExactDivAssign, FloorLog2, GCD,
+    },
+    util::{factorize, DebugAsDisplay, Sign},
 };
 use num_bigint::{BigInt, BigUint};
 use num_integer::Integer;
@@ -14,12 +16,16 @@ use num_traits::{Num, One, Pow, Signed, ToPrimitive, Zero};
 use std::{
     borrow::Cow,
     cmp::Ordering,
+    convert::TryFrom,
     error::Error,
+    cell::RefCell,
+    collections::HashSet,
     fmt, hash, mem,
     ops::{
         Add, AddAssign, Deref, DerefMut, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub,
         SubAssign,
     },
+    sync::Arc,
 };
 
 pub trait IntoRationalExponent {
@@ -41,7 +47,7 @@ impl<N: Into<BigInt>, D: Into<BigInt>> IntoRationalExponent for (N, D) {
 
 #[derive(Clone)]
 pub struct RealAlgebraicNumberData {
-    pub minimal_polynomial: Polynomial<BigInt>,
+    pub minimal_polynomial: Arc<Polynomial<BigInt>>,
     pub interval: DyadicFractionInterval,
 }
 
@@ -68,6 +74,16 @@ impl PartialEq for RealAlgebraicNumberData {
 
 impl Eq for RealAlgebraicNumberData {}
 
+/// renders a rational value as a LaTeX expression, using `\frac{}{}` for
+/// non-integer values
+fn ratio_to_latex(value: &Ratio<BigInt>) -> String {
+    if value.is_integer() {
+        format!("{}", value.numer())
+    } else {
+        format!("\\frac{{{}}}{{{}}}", value.numer(), value.denom())
+    }
+}
+
 fn debug_real_algebraic_number(
     data: &RealAlgebraicNumberData,
     f: &mut fmt::Formatter,
@@ -88,6 +104,36 @@ impl fmt::Debug for RealAlgebraicNumberData {
     }
 }
 
+/// a pool of interned minimal polynomials, shared (via [`canonicalize_in`])
+/// among every [`RealAlgebraicNumber`] that has an equal one
+///
+/// constructing the same algebraic number over and over -- e.g. `sqrt(2)`,
+/// thousands of times over the course of some workload -- otherwise
+/// allocates and stores an identical minimal polynomial every time; running
+/// each result through the same [`Context`] instead makes all of them share
+/// one underlying allocation, and makes comparing any two of them cheap
+/// whenever they happen to be the exact same [`Arc`]
+///
+/// [`canonicalize_in`]: RealAlgebraicNumber::canonicalize_in
+#[derive(Debug, Default)]
+pub struct Context {
+    interned_minimal_polynomials: RefCell<HashSet<Arc<Polynomial<BigInt>>>>,
+}
+
+impl Context {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    fn intern(&self, minimal_polynomial: Arc<Polynomial<BigInt>>) -> Arc<Polynomial<BigInt>> {
+        let mut interned = self.interned_minimal_polynomials.borrow_mut();
+        if let Some(existing) = interned.get(&minimal_polynomial) {
+            return existing.clone();
+        }
+        interned.insert(minimal_polynomial.clone());
+        minimal_polynomial
+    }
+}
+
 #[derive(Clone)]
 pub struct RealAlgebraicNumber {
     data: RealAlgebraicNumberData,
@@ -99,13 +145,23 @@ impl fmt::Debug for RealAlgebraicNumber {
     }
 }
 
+impl fmt::Display for RealAlgebraicNumber {
+    /// formats `self` as a decimal number using [`Self::to_decimal_string`];
+    /// use `{:.N}` to request `N` digits after the decimal point, defaulting
+    /// to 10 digits if no precision is given
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_decimal_string(f.precision().unwrap_or(10)))
+    }
+}
+
 macro_rules! impl_from_int_or_ratio {
     ($t:ident) => {
         impl From<$t> for RealAlgebraicNumber {
             fn from(value: $t) -> Self {
                 let value = BigInt::from(value);
+                let minimal_polynomial: Polynomial<BigInt> = [-&value, BigInt::one()].into();
                 Self::new_unchecked(
-                    [-&value, BigInt::one()].into(),
+                    minimal_polynomial,
                     DyadicFractionInterval::from_int(value, 0),
                 )
             }
@@ -127,8 +183,9 @@ macro_rules! impl_from_int_or_ratio {
                 let denom = BigInt::from(denom);
                 let neg_numer = -&numer;
                 let ratio = Ratio::new_raw(numer, denom.clone());
+                let minimal_polynomial: Polynomial<BigInt> = [neg_numer, denom].into();
                 Self::new_unchecked(
-                    [neg_numer, denom].into(),
+                    minimal_polynomial,
                     DyadicFractionInterval::from_ratio(ratio, 0),
                 )
             }
@@ -158,6 +215,55 @@ impl_from_int_or_ratio!(i128);
 impl_from_int_or_ratio!(isize);
 impl_from_int_or_ratio!(BigInt);
 
+/// the reason converting a [`RealAlgebraicNumber`] from a floating-point
+/// value failed
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct FloatIsNotFiniteError;
+
+impl fmt::Display for FloatIsNotFiniteError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("can't convert a non-finite floating-point value (NaN or infinity) to a RealAlgebraicNumber")
+    }
+}
+
+impl Error for FloatIsNotFiniteError {}
+
+/// decomposes a finite floating-point value into the exact rational
+/// value it represents; `value` must be finite, since NaN and infinity
+/// have no such representation
+fn exact_ratio_from_finite_float<F: num_traits::Float>(value: F) -> Ratio<BigInt> {
+    debug_assert!(value.is_finite());
+    let (mantissa, exponent, sign) = value.integer_decode();
+    let mantissa = BigInt::from(sign) * BigInt::from(mantissa);
+    if exponent >= 0 {
+        Ratio::from(mantissa << exponent)
+    } else {
+        Ratio::new(mantissa, BigInt::one() << (-exponent) as usize)
+    }
+}
+
+macro_rules! impl_try_from_float {
+    ($t:ident) => {
+        impl TryFrom<$t> for RealAlgebraicNumber {
+            type Error = FloatIsNotFiniteError;
+            /// converts the exact dyadic value that `value` represents into
+            /// a [`RealAlgebraicNumber`], failing if `value` is NaN or
+            /// infinite; unlike converting through a decimal string, this
+            /// is always exact since every finite floating-point value is
+            /// itself a dyadic rational
+            fn try_from(value: $t) -> Result<Self, Self::Error> {
+                if !value.is_finite() {
+                    return Err(FloatIsNotFiniteError);
+                }
+                Ok(exact_ratio_from_finite_float(value).into())
+            }
+        }
+    };
+}
+
+impl_try_from_float!(f32);
+impl_try_from_float!(f64);
+
 #[derive(Copy, Clone, Debug)]
 enum ValueOrInfinity<T> {
     #[allow(dead_code)]
@@ -202,7 +308,7 @@ fn sign_changes_at(
                 let degree = polynomial.degree().unwrap_or(0);
                 let s = Sign::new(&polynomial.highest_power_coefficient());
                 if degree.is_odd() {
-                    s.map(|| -s)
+                    s.map(|s| -s)
                 } else {
                     s
                 }
@@ -315,6 +421,28 @@ fn distance(a: usize, b: usize) -> usize {
     }
 }
 
+/// the smallest integer `>= sqrt(value)`; `value` must be non-negative
+fn ceil_sqrt(value: &BigInt) -> BigInt {
+    let floor_sqrt = value.sqrt();
+    if &floor_sqrt * &floor_sqrt == *value {
+        floor_sqrt
+    } else {
+        floor_sqrt + 1
+    }
+}
+
+/// formats `value` as a decimal expansion with `digits` digits after the
+/// decimal point, appending `\u{2026}` unless `value` is exactly
+/// representable with that many digits
+fn into_decimal_string_from_rational(value: Ratio<BigInt>, digits: usize) -> String {
+    let scale = BigInt::from(10).pow(digits as u32);
+    if (&value * &scale).is_integer() {
+        format_decimal(&value, digits)
+    } else {
+        format!("{}\u{2026}", format_decimal(&value, digits))
+    }
+}
+
 #[derive(Debug)]
 struct IntervalShrinker<'a> {
     minimal_polynomial: &'a Polynomial<BigInt>,
@@ -424,14 +552,167 @@ impl<'a> IntervalShrinker<'a> {
     }
 }
 
+/// how to handle raising a negative [`RealAlgebraicNumber`] to a
+/// non-integer rational power, where the mathematically "correct" answer
+/// depends on convention
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum NegativeBasePowPolicy {
+    /// return `None`; this is what [`RealAlgebraicNumber::checked_pow`]
+    /// and [`RealAlgebraicNumber::checked_into_pow`] use
+    Error,
+    /// take the real root when the exponent's reduced denominator is
+    /// odd (e.g. `(-8).checked_pow_with_policy((1, 3), RealOddRoot)`
+    /// gives `-2`), and return `None` when it's even, since no real
+    /// result exists then
+    ///
+    /// [`RealOddRoot`]: NegativeBasePowPolicy::RealOddRoot
+    RealOddRoot,
+    /// return `None`, since [`RealAlgebraicNumber`] doesn't have a
+    /// complex number type to return the principal branch's value in;
+    /// reserved for when it does
+    ComplexPrincipalBranch,
+}
+
+/// the reason [`RealAlgebraicNumber::try_new`] failed to construct a value
+/// from a polynomial and an interval
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum RootIsolationError {
+    /// the polynomial is zero, so it has no roots at all
+    ZeroPolynomial,
+    /// the interval doesn't contain any real root of the polynomial
+    NoRootInInterval,
+    /// the interval contains more than one distinct real root of the
+    /// polynomial, so it doesn't isolate a single one
+    AmbiguousRoot,
+}
+
+impl fmt::Display for RootIsolationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            RootIsolationError::ZeroPolynomial => {
+                "can't construct a RealAlgebraicNumber from the zero polynomial"
+            }
+            RootIsolationError::NoRootInInterval => {
+                "interval doesn't contain any real root of the polynomial"
+            }
+            RootIsolationError::AmbiguousRoot => {
+                "interval contains more than one distinct real root of the polynomial"
+            }
+        })
+    }
+}
+
+impl Error for RootIsolationError {}
+
+/// one of the roots returned by [`real_roots`]
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct RealRoot {
+    /// the value of the root
+    pub value: RealAlgebraicNumber,
+    /// how many times `value` is a root of the polynomial passed to
+    /// [`real_roots`]
+    pub multiplicity: usize,
+}
+
+/// finds every distinct real root of `polynomial`, together with each
+/// root's multiplicity
+///
+/// squarefree decomposition, irreducible factorization, and root
+/// isolation are all done once and shared across every returned root,
+/// rather than redone from scratch for each one individually
+pub fn real_roots(polynomial: &Polynomial<BigInt>) -> Vec<RealRoot> {
+    if polynomial.is_zero() {
+        return Vec::new();
+    }
+    let mut roots = Vec::new();
+    for factor in polynomial.factor().polynomial_factors {
+        for interval in factor
+            .polynomial
+            .isolate_real_roots(64, RootIsolationAlgorithm::Sturm)
+        {
+            roots.push(RealRoot {
+                value: RealAlgebraicNumber::new_unchecked(factor.polynomial.clone(), interval),
+                multiplicity: factor.power,
+            });
+        }
+    }
+    roots.sort_unstable_by(|a, b| a.value.cmp(&b.value));
+    roots
+}
+
 impl RealAlgebraicNumber {
+    /// constructs a [`RealAlgebraicNumber`] from `polynomial` and an
+    /// `interval` that must isolate exactly one of its real roots,
+    /// checking that invariant instead of assuming it like
+    /// [`Self::new_unchecked`] does
+    ///
+    /// `polynomial` doesn't need to already be the minimal polynomial of
+    /// the resulting value -- it's factored internally and the
+    /// irreducible factor with a root in `interval` is used instead
+    pub fn try_new(
+        polynomial: impl Into<Arc<Polynomial<BigInt>>>,
+        interval: DyadicFractionInterval,
+    ) -> Result<Self, RootIsolationError> {
+        let polynomial = polynomial.into();
+        if polynomial.is_zero() {
+            return Err(RootIsolationError::ZeroPolynomial);
+        }
+        let (lower_bound, upper_bound) = interval.to_ratio_range();
+        let mut matching_factor = None;
+        let mut exact_root = None;
+        let mut roots_found = 0;
+        for factor in polynomial.factor().polynomial_factors {
+            let factor = ResultFactor {
+                primitive_sturm_sequence: factor.polynomial.into_primitive_sturm_sequence(),
+            };
+            let lower_bound_sign_changes = sign_changes_at(
+                &factor.primitive_sturm_sequence,
+                ValueOrInfinity::Value(&lower_bound),
+            );
+            if lower_bound_sign_changes.is_root {
+                roots_found += 1;
+                exact_root = Some(lower_bound.clone());
+                continue;
+            }
+            let upper_bound_sign_changes = sign_changes_at(
+                &factor.primitive_sturm_sequence,
+                ValueOrInfinity::Value(&upper_bound),
+            );
+            if upper_bound_sign_changes.is_root {
+                roots_found += 1;
+                exact_root = Some(upper_bound.clone());
+                continue;
+            }
+            let num_roots = distance(
+                lower_bound_sign_changes.sign_change_count,
+                upper_bound_sign_changes.sign_change_count,
+            );
+            roots_found += num_roots;
+            if num_roots != 0 {
+                matching_factor = Some(factor);
+            }
+        }
+        match roots_found {
+            0 => Err(RootIsolationError::NoRootInInterval),
+            1 => Ok(match exact_root {
+                Some(exact_root) => exact_root.into(),
+                None => RealAlgebraicNumber::new_unchecked(
+                    matching_factor
+                        .expect("known to have exactly one matching factor")
+                        .into_factor(),
+                    interval,
+                ),
+            }),
+            _ => Err(RootIsolationError::AmbiguousRoot),
+        }
+    }
     pub fn new_unchecked(
-        minimal_polynomial: Polynomial<BigInt>,
+        minimal_polynomial: impl Into<Arc<Polynomial<BigInt>>>,
         interval: DyadicFractionInterval,
     ) -> Self {
         Self {
             data: RealAlgebraicNumberData {
-                minimal_polynomial,
+                minimal_polynomial: minimal_polynomial.into(),
                 interval,
             },
         }
@@ -477,10 +758,275 @@ impl RealAlgebraicNumber {
             None
         }
     }
+    /// the sum of `self` and all of its conjugates (real and complex);
+    /// equal to `-coefficient(degree - 1) / coefficient(degree)` of the
+    /// minimal polynomial, since that's the sum of all of the minimal
+    /// polynomial's roots
+    pub fn trace(&self) -> Ratio<BigInt> {
+        let degree = self.degree();
+        Ratio::new_raw(
+            -self.minimal_polynomial().coefficient(degree - 1),
+            self.minimal_polynomial().coefficient(degree),
+        )
+    }
+    /// the product of `self` and all of its conjugates (real and
+    /// complex); equal to `(-1)^degree * coefficient(0) / coefficient(degree)`
+    /// of the minimal polynomial, since that's the product of all of the
+    /// minimal polynomial's roots
+    pub fn norm(&self) -> Ratio<BigInt> {
+        let degree = self.degree();
+        let ratio = Ratio::new_raw(
+            self.minimal_polynomial().coefficient(0),
+            self.minimal_polynomial().coefficient(degree),
+        );
+        if degree % 2 == 0 {
+            ratio
+        } else {
+            -ratio
+        }
+    }
+    /// the naive height of `self`: the largest absolute value of any
+    /// coefficient of its minimal polynomial
+    pub fn naive_height(&self) -> BigInt {
+        self.minimal_polynomial()
+            .iter()
+            .map(|coefficient| coefficient.abs())
+            .max()
+            .unwrap_or_else(BigInt::zero)
+    }
+    /// a rigorous enclosure of the [Mahler measure] of `self`'s minimal
+    /// polynomial `P`: `|a_d| * prod(max(1, |root|))` over every complex
+    /// root of `P`, where `a_d` is `P`'s leading coefficient
+    ///
+    /// since this crate doesn't have a complex algebraic number type yet,
+    /// the roots contributing to the product can't all be found exactly;
+    /// instead this uses Landau's inequality (the Mahler measure is at
+    /// most the `l2` norm of the coefficients, i.e. [`Self::naive_height`]
+    /// scaled up by at most `sqrt(degree + 1)`) for the upper bound, and
+    /// `|a_d| <= M(P)` for the lower bound, both computed exactly with
+    /// integer arithmetic
+    ///
+    /// [Mahler measure]: https://en.wikipedia.org/wiki/Mahler_measure
+    pub fn mahler_measure(&self) -> DyadicFractionInterval {
+        let lower_bound = self.minimal_polynomial().highest_power_coefficient().abs();
+        let sum_of_squares: BigInt = self
+            .minimal_polynomial()
+            .iter()
+            .map(|coefficient| &coefficient * &coefficient)
+            .sum();
+        let upper_bound = ceil_sqrt(&sum_of_squares);
+        DyadicFractionInterval::new(lower_bound, upper_bound, 0)
+    }
+    /// a rigorous enclosure of `self`'s absolute logarithmic (Weil)
+    /// height, `log2(mahler_measure()) / degree()`
+    ///
+    /// uses log base 2 (rather than the natural log usually used in the
+    /// literature) since that's the unit precision-driven algorithms
+    /// like [`Self::recognize`] and the lattice-reduction code already
+    /// work in
+    pub fn logarithmic_height(&self) -> DyadicFractionInterval {
+        let mahler_measure = self.mahler_measure();
+        let log2_lower = BigInt::from(
+            mahler_measure
+                .lower_bound_numer()
+                .floor_log2()
+                .expect("Mahler measure is always positive") as u64,
+        );
+        let log2_upper = BigInt::from(
+            mahler_measure
+                .upper_bound_numer()
+                .ceil_log2()
+                .expect("Mahler measure is always positive") as u64,
+        );
+        const PRECISION: usize = 64;
+        let scale = BigInt::one() << PRECISION;
+        let degree = BigInt::from(self.degree());
+        let lower_bound = (log2_lower * &scale).div_floor(&degree);
+        let upper_bound = (log2_upper * scale).div_ceil(&degree);
+        DyadicFractionInterval::new(lower_bound, upper_bound, PRECISION)
+    }
     #[inline]
     pub fn interval(&self) -> &DyadicFractionInterval {
         &self.data().interval
     }
+    /// repeatedly bisects a copy of `self`'s isolating interval until
+    /// `should_stop` returns `true` for it, then returns that interval;
+    /// stops early if the interval collapses to `self`'s exact value
+    /// first, even if `should_stop` never returns `true`, so that a
+    /// `should_stop` that's only satisfiable at a precision `self`
+    /// doesn't actually need can't cause an infinite loop
+    ///
+    /// lets a caller (e.g. something plotting `self`) drive isolating
+    /// precision explicitly, rather than only getting whatever precision
+    /// some other operation's internal comparisons happened to reach
+    pub fn refine_until(
+        &self,
+        mut should_stop: impl FnMut(&DyadicFractionInterval) -> bool,
+    ) -> DyadicFractionInterval {
+        let mut value = self.clone();
+        let mut interval_shrinker = value.interval_shrinker();
+        while !should_stop(&interval_shrinker.interval) {
+            if interval_shrinker.shrink() == IntervalShrinkResult::Exact {
+                break;
+            }
+        }
+        (*interval_shrinker.interval).clone()
+    }
+    /// refines a copy of `self`'s isolating interval until its
+    /// denominator's log2 is at least `log2_denom`, then returns that
+    /// interval; if `self`'s exact value is reached at a lower precision
+    /// first, the returned interval is converted up to `log2_denom`
+    /// anyway, since an exact value can be represented at any precision
+    pub fn refine_to(&self, log2_denom: usize) -> DyadicFractionInterval {
+        let mut interval = self.refine_until(|interval| interval.log2_denom() >= log2_denom);
+        if interval.log2_denom() < log2_denom {
+            interval.convert_log2_denom(log2_denom);
+        }
+        interval
+    }
+    /// renders `self` as a LaTeX math expression (without surrounding `$`
+    /// delimiters): the minimal polynomial set to zero, together with the
+    /// isolating interval that picks out this particular root
+    pub fn to_latex(&self) -> String {
+        format!(
+            "{} = 0,\\ X \\in [{}, {}]",
+            self.minimal_polynomial().to_latex(),
+            ratio_to_latex(&self.interval().lower_bound()),
+            ratio_to_latex(&self.interval().upper_bound()),
+        )
+    }
+    /// every other real root of `self`'s minimal polynomial, i.e. every
+    /// other real number with the same minimal polynomial as `self`;
+    /// `self`'s minimal polynomial is irreducible, so isolating all of
+    /// its real roots and dropping the one equal to `self` is enough --
+    /// no separate root-finding for the conjugates themselves is needed
+    ///
+    /// complex conjugates (i.e. `conjugates()`) aren't available yet,
+    /// since this crate doesn't have a complex algebraic number type
+    pub fn real_conjugates(&self) -> Vec<Self> {
+        self.minimal_polynomial()
+            .isolate_real_roots(64, RootIsolationAlgorithm::Sturm)
+            .into_iter()
+            .map(|interval| Self::new_unchecked(self.data().minimal_polynomial.clone(), interval))
+            .filter(|candidate| candidate != self)
+            .collect()
+    }
+    /// `self`'s rank (0-indexed, ascending) among the real roots of its
+    /// own minimal polynomial
+    ///
+    /// combined with [`Self::minimal_polynomial`], this gives a stable
+    /// way to refer to one specific conjugate -- e.g. for serialization --
+    /// without needing to store an isolating interval; [`Self::from_poly_and_root_index`]
+    /// is the inverse
+    pub fn root_index(&self) -> usize {
+        self.minimal_polynomial()
+            .isolate_real_roots(64, RootIsolationAlgorithm::Sturm)
+            .into_iter()
+            .position(|interval| {
+                Self::new_unchecked(self.data().minimal_polynomial.clone(), interval) == *self
+            })
+            .expect("self is known to be a real root of its own minimal polynomial")
+    }
+    /// constructs the `index`th (0-indexed, ascending) real root of
+    /// `polynomial`, the inverse of [`Self::root_index`] combined with
+    /// [`Self::minimal_polynomial`]
+    ///
+    /// returns `None` if `polynomial` doesn't have that many distinct
+    /// real roots
+    pub fn from_poly_and_root_index(polynomial: &Polynomial<BigInt>, index: usize) -> Option<Self> {
+        real_roots(polynomial).into_iter().nth(index).map(|root| root.value)
+    }
+    /// returns a value equal to `self` whose minimal polynomial is shared
+    /// (via `context`) with every other value canonicalized through the
+    /// same [`Context`] that has an equal minimal polynomial
+    ///
+    /// useful for workloads that build the same algebraic number many
+    /// times over -- e.g. a stream of `sqrt(2)`s -- since after the first
+    /// one is canonicalized, every later equal one reuses its already-
+    /// interned minimal polynomial instead of storing its own copy
+    pub fn canonicalize_in(&self, context: &Context) -> Self {
+        let minimal_polynomial = context.intern(self.data().minimal_polynomial.clone());
+        Self::new_unchecked(minimal_polynomial, self.interval().clone())
+    }
+    /// tries to find an algebraic number close to `approx`, of degree at
+    /// most `max_degree` and with every coefficient of its minimal
+    /// polynomial fitting in `max_coeff_bits` bits; returns `None` if
+    /// `approx` isn't finite, `max_degree` is `0`, or no such algebraic
+    /// number is found
+    ///
+    /// uses [LLL lattice reduction] to search for an integer relation
+    /// among the powers `1, approx, approx^2, ..., approx^max_degree`,
+    /// the same idea [PSLQ] is built around, then checks every
+    /// short-enough relation it finds against the polynomial's actual
+    /// roots for one close enough to `approx` to plausibly be it;
+    /// `approx` being a plain `f64` puts a hard ceiling on how precisely
+    /// this can recognize anything, no matter how large `max_coeff_bits`
+    /// is
+    ///
+    /// [LLL lattice reduction]: https://en.wikipedia.org/wiki/Lenstra%E2%80%93Lenstra%E2%80%93Lov%C3%A1sz_lattice_basis_reduction_algorithm
+    /// [PSLQ]: https://en.wikipedia.org/wiki/Integer_relation_algorithm#PSLQ_algorithm
+    pub fn recognize(approx: f64, max_degree: usize, max_coeff_bits: u64) -> Option<Self> {
+        if !approx.is_finite() || max_degree == 0 {
+            return None;
+        }
+        let value = exact_ratio_from_finite_float(approx);
+        let count = max_degree + 1;
+        // `approx` only carries about 53 bits of useful precision (an
+        // `f64`'s mantissa plus its implicit leading bit), no matter how
+        // exactly its own rational value is represented here, so working
+        // at much higher precision than that would just let noise well
+        // below `approx`'s precision dominate the lattice instead of the
+        // relation actually being searched for
+        let precision_bits = 53;
+        let scale = Ratio::from(BigInt::one() << precision_bits);
+        let mut scaled_powers = Vec::with_capacity(count);
+        let mut power = Ratio::<BigInt>::one();
+        for _ in 0..count {
+            scaled_powers.push((&power * &scale).round().to_integer());
+            power *= &value;
+        }
+        let mut basis = crate::array2d::Array2DOwned::new(count, count + 1, BigInt::zero());
+        for (i, scaled_power) in scaled_powers.into_iter().enumerate() {
+            basis[(i, i)] = BigInt::one();
+            basis[(i, count)] = scaled_power;
+        }
+        let reduced = crate::lattice::lll_reduce(basis);
+        let mut candidates: Vec<(BigInt, Polynomial<BigInt>)> = Vec::new();
+        for column in 0..count {
+            let coefficients: Vec<BigInt> =
+                (0..count).map(|row| reduced[(column, row)].clone()).collect();
+            if coefficients.iter().all(Zero::is_zero)
+                || coefficients.iter().any(|c| c.bits() > max_coeff_bits)
+            {
+                continue;
+            }
+            let polynomial: Polynomial<BigInt> = coefficients.into_iter().collect();
+            if polynomial.degree().unwrap_or(0) == 0 {
+                continue;
+            }
+            candidates.push((reduced[(column, count)].abs(), polynomial));
+        }
+        candidates.sort_by(|(a, _), (b, _)| a.cmp(b));
+        let tolerance =
+            approx.abs().max(1.0) * 2f64.powi(-(max_coeff_bits.min(48) as i32) - 8);
+        for (_, polynomial) in candidates {
+            for factor in polynomial.factor().polynomial_factors {
+                for interval in factor
+                    .polynomial
+                    .isolate_real_roots(64, RootIsolationAlgorithm::Sturm)
+                {
+                    let (lower_bound, upper_bound) = interval.to_ratio_range();
+                    let midpoint = (lower_bound + upper_bound) / BigInt::from(2);
+                    if let Some(midpoint) = midpoint.to_f64() {
+                        if (midpoint - approx).abs() <= tolerance {
+                            return Some(Self::new_unchecked(factor.polynomial, interval));
+                        }
+                    }
+                }
+            }
+        }
+        None
+    }
     fn interval_shrinker(&mut self) -> IntervalShrinker {
         let RealAlgebraicNumberData {
             minimal_polynomial,
@@ -587,6 +1133,111 @@ impl RealAlgebraicNumber {
             }
         }
     }
+    /// compares `self` against `rhs` by evaluating the sign of `self`'s
+    /// minimal polynomial (via its Sturm sequence) at `rhs`, rather than
+    /// building a temporary [`RealAlgebraicNumber`] for `rhs` and running
+    /// a full resultant-based comparison against it
+    fn cmp_with_rational(&self, rhs: &Ratio<BigInt>) -> Ordering {
+        if let Some(value) = self.to_rational() {
+            return value.cmp(rhs);
+        }
+        if self.interval().lower_bound() > *rhs {
+            return Ordering::Greater;
+        }
+        if self.interval().upper_bound() < *rhs {
+            return Ordering::Less;
+        }
+        let primitive_sturm_sequence = self.minimal_polynomial().to_primitive_sturm_sequence();
+        let lower_bound_sign_changes = sign_changes_at(
+            &primitive_sturm_sequence,
+            ValueOrInfinity::Value(&self.interval().lower_bound()),
+        );
+        assert!(!lower_bound_sign_changes.is_root);
+        let rhs_sign_changes =
+            sign_changes_at(&primitive_sturm_sequence, ValueOrInfinity::Value(rhs));
+        if rhs_sign_changes.is_root {
+            // can only happen if `rhs` is an exact root of the minimal
+            // polynomial that lies inside the isolating interval, which
+            // (since the interval isolates exactly one root) means `rhs`
+            // is `self`'s exact value
+            Ordering::Equal
+        } else if lower_bound_sign_changes.sign_change_count == rhs_sign_changes.sign_change_count
+        {
+            Ordering::Greater
+        } else {
+            Ordering::Less
+        }
+    }
+    /// tries to compare `self` and `rhs` using only cheap invariants
+    /// (matching stored data, and non-overlapping isolating intervals),
+    /// returning `None` if those are inconclusive; used to avoid paying
+    /// for the expensive subtraction-based comparison in the common case
+    /// where it's not needed
+    fn quick_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        if self.data() == rhs.data() {
+            return Some(Ordering::Equal);
+        }
+        if self.interval().upper_bound() < rhs.interval().lower_bound() {
+            return Some(Ordering::Less);
+        }
+        if self.interval().lower_bound() > rhs.interval().upper_bound() {
+            return Some(Ordering::Greater);
+        }
+        None
+    }
+    /// cheaply checks whether `self` and `rhs` are definitely unequal by
+    /// comparing their minimal polynomials' degrees and, failing that,
+    /// their leading and trailing coefficients (which must have equal
+    /// magnitude for equal values, since a minimal polynomial is unique
+    /// up to an overall sign); returns `false` if it can't tell, in which
+    /// case the caller needs to fall back to an exact comparison
+    fn quick_ne(&self, rhs: &Self) -> bool {
+        self.degree() != rhs.degree()
+            || self.minimal_polynomial().coefficient(0).abs()
+                != rhs.minimal_polynomial().coefficient(0).abs()
+            || self.minimal_polynomial().highest_power_coefficient().abs()
+                != rhs.minimal_polynomial().highest_power_coefficient().abs()
+    }
+    /// returns the smaller of `self` and `other`, checking their isolating
+    /// intervals first so non-overlapping values don't need an exact
+    /// comparison; shadows [`Ord::min`] with a faster implementation
+    pub fn min(self, other: Self) -> Self {
+        if self.interval().upper_bound() <= other.interval().lower_bound() {
+            self
+        } else if other.interval().upper_bound() <= self.interval().lower_bound() {
+            other
+        } else if self <= other {
+            self
+        } else {
+            other
+        }
+    }
+    /// returns the larger of `self` and `other`, checking their isolating
+    /// intervals first so non-overlapping values don't need an exact
+    /// comparison; shadows [`Ord::max`] with a faster implementation
+    pub fn max(self, other: Self) -> Self {
+        if self.interval().lower_bound() >= other.interval().upper_bound() {
+            self
+        } else if other.interval().lower_bound() >= self.interval().upper_bound() {
+            other
+        } else if self >= other {
+            self
+        } else {
+            other
+        }
+    }
+    /// returns the smallest value in `values`, or `None` if it's empty
+    pub fn min_of(values: &[Self]) -> Option<Self> {
+        let mut iter = values.iter().cloned();
+        let first = iter.next()?;
+        Some(iter.fold(first, RealAlgebraicNumber::min))
+    }
+    /// returns the largest value in `values`, or `None` if it's empty
+    pub fn max_of(values: &[Self]) -> Option<Self> {
+        let mut iter = values.iter().cloned();
+        let first = iter.next()?;
+        Some(iter.fold(first, RealAlgebraicNumber::max))
+    }
     pub fn into_integer_trunc(self) -> BigInt {
         match self.cmp_with_zero() {
             Ordering::Equal => BigInt::zero(),
@@ -607,6 +1258,143 @@ impl RealAlgebraicNumber {
     pub fn trunc(&self) -> Self {
         self.to_integer_trunc().into()
     }
+    /// returns the nearest integer to `self`, rounding half-way cases
+    /// away from zero, matching [`Ratio::round`]
+    pub fn into_integer_round(self) -> BigInt {
+        if let Some(ratio) = self.to_rational() {
+            ratio.round().to_integer()
+        } else {
+            // irrational values are never exactly half-way between two
+            // integers, so rounding half-way cases doesn't matter here
+            (self + RealAlgebraicNumber::from(Ratio::new(BigInt::one(), BigInt::from(2))))
+                .into_integer_floor()
+        }
+    }
+    pub fn to_integer_round(&self) -> BigInt {
+        if let Some(ratio) = self.to_rational() {
+            ratio.round().to_integer()
+        } else {
+            self.clone().into_integer_round()
+        }
+    }
+    pub fn into_round(self) -> Self {
+        self.into_integer_round().into()
+    }
+    pub fn round(&self) -> Self {
+        self.to_integer_round().into()
+    }
+    /// formats `self` as a decimal expansion with exactly `digits` digits
+    /// after the decimal point, appending `\u{2026}` (an ellipsis) if the
+    /// shown digits don't represent `self` exactly; most algebraic
+    /// numbers can't be written as a finite decimal, so the ellipsis is
+    /// the common case
+    pub fn into_decimal_string(mut self, digits: usize) -> String {
+        if let Some(ratio) = self.to_rational() {
+            into_decimal_string_from_rational(ratio, digits)
+        } else {
+            let scale = BigInt::from(10).pow(digits as u32);
+            let mut interval_shrinker = self.interval_shrinker();
+            loop {
+                let lower_scaled = (interval_shrinker.interval.lower_bound() * &scale)
+                    .round()
+                    .to_integer();
+                let upper_scaled = (interval_shrinker.interval.upper_bound() * &scale)
+                    .round()
+                    .to_integer();
+                if lower_scaled == upper_scaled {
+                    let value = Ratio::new(lower_scaled, scale);
+                    return format!("{}\u{2026}", format_decimal(&value, digits));
+                }
+                interval_shrinker.shrink();
+            }
+        }
+    }
+    pub fn to_decimal_string(&self, digits: usize) -> String {
+        if let Some(ratio) = self.to_rational() {
+            into_decimal_string_from_rational(ratio, digits)
+        } else {
+            self.clone().into_decimal_string(digits)
+        }
+    }
+    /// attempts to express `self` as a [`RadicalExpression`] built from
+    /// integers, `+`, `-`, `*`, `/`, and `sqrt`, returning `None` if it
+    /// can't; rational numbers and roots of quadratics (degree at most 2)
+    /// are supported; cubics, quartics, and other solvable higher-degree
+    /// cases aren't implemented yet
+    pub fn to_radical_expression(&self) -> Option<RadicalExpression> {
+        if let Some(value) = self.to_rational() {
+            return Some(if value.is_integer() {
+                RadicalExpression::Integer(value.to_integer())
+            } else {
+                RadicalExpression::Div(
+                    Box::new(RadicalExpression::Integer(value.numer().clone())),
+                    Box::new(RadicalExpression::Integer(value.denom().clone())),
+                )
+            });
+        }
+        if self.degree() != 2 {
+            return None;
+        }
+        let minimal_polynomial = self.minimal_polynomial();
+        let a = minimal_polynomial.coefficient(2);
+        let b = minimal_polynomial.coefficient(1);
+        let c = minimal_polynomial.coefficient(0);
+        // real since `self` is real, so the discriminant can't be negative
+        let discriminant = &b * &b - BigInt::from(4) * &a * &c;
+        let (sqrt_coefficient, sqrt_radicand) = split_out_square_factors(discriminant.clone());
+        let mut neg_b = -&b;
+        let mut sqrt_coefficient = sqrt_coefficient;
+        let mut two_a = BigInt::from(2) * &a;
+        let divisor = GCD::gcd(&GCD::gcd(&neg_b, &sqrt_coefficient), &two_a);
+        if !divisor.is_one() {
+            neg_b /= &divisor;
+            sqrt_coefficient /= &divisor;
+            two_a /= &divisor;
+        }
+        if two_a.is_negative() {
+            neg_b = -neg_b;
+            sqrt_coefficient = -sqrt_coefficient;
+            two_a = -two_a;
+        }
+        let sqrt_term = if sqrt_coefficient.is_one() {
+            RadicalExpression::Sqrt(sqrt_radicand)
+        } else {
+            RadicalExpression::Mul(
+                Box::new(RadicalExpression::Integer(sqrt_coefficient)),
+                Box::new(RadicalExpression::Sqrt(sqrt_radicand)),
+            )
+        };
+        let build_root = |numerator_sign_positive: bool| {
+            let sqrt_operand = if numerator_sign_positive {
+                sqrt_term.clone()
+            } else {
+                RadicalExpression::Neg(Box::new(sqrt_term.clone()))
+            };
+            let numerator = if neg_b.is_zero() {
+                sqrt_operand
+            } else {
+                RadicalExpression::Add(
+                    Box::new(RadicalExpression::Integer(neg_b.clone())),
+                    Box::new(sqrt_operand),
+                )
+            };
+            if two_a.is_one() {
+                numerator
+            } else {
+                RadicalExpression::Div(
+                    Box::new(numerator),
+                    Box::new(RadicalExpression::Integer(two_a.clone())),
+                )
+            }
+        };
+        // `self` satisfies `2*a*self + b == +sqrt(discriminant)` or
+        // `== -sqrt(discriminant)`; checking the sign of that (rational)
+        // combination picks the right one without needing to compare `self`
+        // against a separately-computed root
+        let sign_indicator =
+            RealAlgebraicNumber::from(BigInt::from(2) * a) * self.clone() + RealAlgebraicNumber::from(b);
+        Some(build_root(sign_indicator.is_positive()))
+    }
     /// shrinks the interval till it doesn't contain zero
     #[must_use]
     fn remove_zero_from_interval(&mut self) -> Option<(Sign, IntervalShrinker)> {
@@ -648,7 +1436,11 @@ impl RealAlgebraicNumber {
             minimal_polynomial,
             interval,
         } = value.into_data();
-        let minimal_polynomial = minimal_polynomial.into_iter().rev().collect();
+        let minimal_polynomial: Polynomial<BigInt> = Arc::try_unwrap(minimal_polynomial)
+            .unwrap_or_else(|shared| (*shared).clone())
+            .into_iter()
+            .rev()
+            .collect();
         Some(RealAlgebraicNumber::new_unchecked(
             minimal_polynomial,
             interval.recip(),
@@ -658,6 +1450,23 @@ impl RealAlgebraicNumber {
         self.checked_recip()
             .expect("checked_recip called on zero value")
     }
+    /// a cheap zero check based only on `self`'s current isolating
+    /// interval: returns `false` immediately whenever that interval
+    /// already excludes zero, only falling back to the exact
+    /// [`Zero::is_zero`](num_traits::Zero::is_zero) check when it doesn't
+    pub fn is_zero_fast(&self) -> bool {
+        if self.interval().lower_bound_numer().is_positive()
+            || self.interval().upper_bound_numer().is_negative()
+        {
+            false
+        } else {
+            self.is_zero()
+        }
+    }
+    /// returns `None` instead of panicking when `rhs` is zero
+    pub fn checked_div(&self, rhs: &Self) -> Option<Self> {
+        ExactDiv::checked_exact_div(self, rhs)
+    }
     pub fn negative_one() -> Self {
         NEGATIVE_ONE.clone()
     }
@@ -667,7 +1476,35 @@ impl RealAlgebraicNumber {
     pub fn is_negative_one(&self) -> bool {
         self.minimal_polynomial() == NEGATIVE_ONE.minimal_polynomial()
     }
-    fn checked_pow_impl(base: Cow<Self>, exponent: Ratio<BigInt>) -> Option<Self> {
+    /// returns `Some(sign of the odd-denominator real root)` if
+    /// `policy` allows computing a real result for a negative base raised
+    /// to the non-integer rational power `exponent`, `Some(Sign::Positive)`
+    /// or `Some(Sign::Negative)` being the sign of that result, and `None`
+    /// if it doesn't (in which case `checked_pow_impl` should return `None`)
+    fn negative_base_non_integer_exponent_sign(
+        exponent: &Ratio<BigInt>,
+        policy: NegativeBasePowPolicy,
+    ) -> Option<Sign> {
+        match policy {
+            NegativeBasePowPolicy::Error | NegativeBasePowPolicy::ComplexPrincipalBranch => None,
+            NegativeBasePowPolicy::RealOddRoot => {
+                if exponent.denom().is_odd() {
+                    Some(if exponent.numer().is_odd() {
+                        Sign::Negative
+                    } else {
+                        Sign::Positive
+                    })
+                } else {
+                    None
+                }
+            }
+        }
+    }
+    fn checked_pow_impl(
+        base: Cow<Self>,
+        exponent: Ratio<BigInt>,
+        policy: NegativeBasePowPolicy,
+    ) -> Option<Self> {
         lazy_static! {
             static ref NEGATIVE_ONE_RATIO: Ratio<BigInt> = BigInt::from(-1).into();
         }
@@ -697,7 +1534,12 @@ impl RealAlgebraicNumber {
                     Self::one()
                 })
             } else {
-                None
+                Some(
+                    match Self::negative_base_non_integer_exponent_sign(&exponent, policy)? {
+                        Sign::Positive => Self::one(),
+                        Sign::Negative => Self::negative_one(),
+                    },
+                )
             }
         } else {
             let base_is_negative = base.is_negative();
@@ -709,7 +1551,7 @@ impl RealAlgebraicNumber {
                         Sign::Positive
                     }
                 } else {
-                    return None;
+                    Self::negative_base_non_integer_exponent_sign(&exponent, policy)?
                 }
             } else {
                 Sign::Positive
@@ -788,11 +1630,126 @@ impl RealAlgebraicNumber {
             )
         }
     }
+    /// same as [`Self::checked_pow`], but consumes `self` instead of
+    /// cloning it when possible
     pub fn checked_into_pow<E: IntoRationalExponent>(self, exponent: E) -> Option<Self> {
-        Self::checked_pow_impl(Cow::Owned(self), exponent.into_rational_exponent())
+        self.checked_into_pow_with_policy(exponent, NegativeBasePowPolicy::Error)
     }
+    /// returns `self` raised to `exponent`, or `None` if the result isn't
+    /// a real number (including `self` negative and `exponent` a
+    /// non-integer, since [`NegativeBasePowPolicy::Error`] is used); see
+    /// [`Self::checked_pow_with_policy`] for other conventions
     pub fn checked_pow<E: IntoRationalExponent>(&self, exponent: E) -> Option<Self> {
-        Self::checked_pow_impl(Cow::Borrowed(self), exponent.into_rational_exponent())
+        self.checked_pow_with_policy(exponent, NegativeBasePowPolicy::Error)
+    }
+    /// same as [`Self::checked_pow_with_policy`], but consumes `self`
+    /// instead of cloning it when possible
+    pub fn checked_into_pow_with_policy<E: IntoRationalExponent>(
+        self,
+        exponent: E,
+        policy: NegativeBasePowPolicy,
+    ) -> Option<Self> {
+        Self::checked_pow_impl(Cow::Owned(self), exponent.into_rational_exponent(), policy)
+    }
+    /// returns `self` raised to `exponent`, using `policy` to decide what
+    /// to do when `self` is negative and `exponent` is a non-integer
+    /// rational, where there's no single universally-correct convention
+    pub fn checked_pow_with_policy<E: IntoRationalExponent>(
+        &self,
+        exponent: E,
+        policy: NegativeBasePowPolicy,
+    ) -> Option<Self> {
+        Self::checked_pow_impl(Cow::Borrowed(self), exponent.into_rational_exponent(), policy)
+    }
+    /// the `n`th Chebyshev polynomial of the first kind, satisfying
+    /// `T_n(cos(theta)) == cos(n * theta)`
+    fn chebyshev_first_kind(n: u64) -> Polynomial<BigInt> {
+        let x = Polynomial::make_monomial(BigInt::one(), 1);
+        let mut previous = Polynomial::from(BigInt::one()); // T_0(x) == 1
+        let mut current = x.clone(); // T_1(x) == x
+        if n == 0 {
+            return previous;
+        }
+        let two = BigInt::from(2);
+        for _ in 1..n {
+            let next = (&current * &x) * &two - &previous;
+            previous = current;
+            current = next;
+        }
+        current
+    }
+    /// returns the exact value of `cos(pi * ratio)`, computed from the
+    /// factor of a shifted Chebyshev polynomial that has it as a root,
+    /// rather than by approximating `pi` numerically
+    ///
+    /// `cos(pi * p / q)` (for `p / q` reduced to lowest terms and folded
+    /// into `0 <= p <= q`) is one of the (at most) `q + 1` distinct roots
+    /// of `T_q(x) - (-1)^p`, since `T_q(cos(pi * p / q)) == cos(pi * p) ==
+    /// (-1)^p`; because `cos` is strictly decreasing on `[0, pi]`, those
+    /// roots appear in the same relative order as the angles they came
+    /// from, which is enough to pick out the right one without ever
+    /// approximating `cos(pi * p / q)` itself
+    pub fn cos_pi_ratio(ratio: &Ratio<BigInt>) -> Self {
+        let denom = ratio.denom().clone();
+        let double_denom = BigInt::from(2) * &denom;
+        let numer = ratio.numer().mod_floor(&double_denom);
+        // fold the angle (a multiple of pi) from `[0, 2)` half-turns down
+        // to `[0, 1]` half-turns using `cos(2*pi - x) == cos(x)`
+        let numer = if BigInt::from(2) * &numer > double_denom {
+            &double_denom - &numer
+        } else {
+            numer
+        };
+        let gcd = GCD::gcd(&numer, &denom);
+        let (p, q) = if gcd.is_zero() {
+            (BigUint::zero(), BigUint::one())
+        } else {
+            (
+                (numer / &gcd).to_biguint().expect("known to be non-negative"),
+                (denom / &gcd).to_biguint().expect("known to be non-negative"),
+            )
+        };
+        let q64 = q.to_u64().expect("denominator too big");
+        let p64 = p.to_u64().expect("numerator too big");
+        let sign = if p64.is_odd() {
+            -BigInt::one()
+        } else {
+            BigInt::one()
+        };
+        let candidate = Self::chebyshev_first_kind(q64) - sign;
+        // the distinct roots of `candidate` are `cos(pi * j / q)` for `j`
+        // in `0..=q` with `j` the same parity as `p`, in decreasing order
+        // of `j` as `x` increases (since `cos` is decreasing on `[0, pi]`)
+        let parity = p64 % 2;
+        let valid_j_count = (q64 - parity) / 2 + 1;
+        let rank_by_ascending_j = (p64 - parity) / 2;
+        let index_by_ascending_x = valid_j_count - 1 - rank_by_ascending_j;
+        let mut roots = candidate.isolate_real_roots(64, RootIsolationAlgorithm::Sturm);
+        assert_eq!(roots.len() as u64, valid_j_count, "unexpected root count");
+        let interval = roots.remove(index_by_ascending_x as usize);
+        struct FixedIntervalRootSelector {
+            interval: DyadicFractionInterval,
+        }
+        impl RootSelector for FixedIntervalRootSelector {
+            fn get_interval(&self) -> DyadicFractionInterval {
+                self.interval.clone()
+            }
+            fn shrink_interval(&mut self) {
+                unreachable!("interval already isolates exactly one root of `candidate`")
+            }
+        }
+        FixedIntervalRootSelector { interval }.select_root(candidate)
+    }
+    /// returns the exact value of `sin(pi * ratio)`, computed using
+    /// [`Self::cos_pi_ratio`] and the identity `sin(x) == cos(pi/2 - x)`
+    pub fn sin_pi_ratio(ratio: &Ratio<BigInt>) -> Self {
+        Self::cos_pi_ratio(&(Ratio::new(BigInt::one(), BigInt::from(2)) - ratio))
+    }
+    /// returns the exact value of `tan(pi * ratio)`, computed as
+    /// `sin(pi * ratio) / cos(pi * ratio)`; panics if `cos(pi * ratio)`
+    /// is zero, i.e. if `ratio - 1/2` is an integer
+    pub fn tan_pi_ratio(ratio: &Ratio<BigInt>) -> Self {
+        Self::sin_pi_ratio(ratio) / Self::cos_pi_ratio(ratio)
     }
     /// returns `Some(log2(self))` if self is a power of 2, otherwise `None`
     pub fn to_integer_log2(&self) -> Option<i64> {
@@ -862,7 +1819,7 @@ fn neg(value: Cow<RealAlgebraicNumber>) -> RealAlgebraicNumber {
         degree_is_odd: bool,
         negated_interval: DyadicFractionInterval,
     ) -> RealAlgebraicNumber {
-        let minimal_polynomial = iter
+        let minimal_polynomial: Polynomial<BigInt> = iter
             .enumerate()
             .map(|(index, coefficient)| {
                 if index.is_odd() == degree_is_odd {
@@ -886,7 +1843,13 @@ fn neg(value: Cow<RealAlgebraicNumber>) -> RealAlgebraicNumber {
                     minimal_polynomial,
                     interval,
                 },
-        }) => do_neg(minimal_polynomial.into_iter(), degree_is_odd, -interval),
+        }) => do_neg(
+            Arc::try_unwrap(minimal_polynomial)
+                .unwrap_or_else(|shared| (*shared).clone())
+                .into_iter(),
+            degree_is_odd,
+            -interval,
+        ),
     }
 }
 
@@ -1186,27 +2149,164 @@ impl<'a, 'b> Sub<&'a RealAlgebraicNumber> for &'b RealAlgebraicNumber {
     }
 }
 
+/// the reason parsing a [`RealAlgebraicNumber`] from a string failed
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
-pub struct RealAlgebraicNumberParseError {
-    private: (),
+pub enum RealAlgebraicNumberParseError {
+    /// the string was empty
+    Empty,
+    /// the string wasn't a valid integer, fraction, or decimal literal
+    InvalidLiteral,
+    /// a fraction's denominator was zero
+    ZeroDenominator,
 }
 
 impl fmt::Display for RealAlgebraicNumberParseError {
-    fn fmt(&self, _f: &mut fmt::Formatter) -> fmt::Result {
-        unimplemented!()
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            RealAlgebraicNumberParseError::Empty => {
+                "cannot parse RealAlgebraicNumber from empty string"
+            }
+            RealAlgebraicNumberParseError::InvalidLiteral => {
+                "invalid RealAlgebraicNumber literal, expected an integer (`123`), \
+                 a fraction (`22/7`), or a decimal literal (`3.14159`)"
+            }
+            RealAlgebraicNumberParseError::ZeroDenominator => {
+                "invalid RealAlgebraicNumber fraction: denominator is zero"
+            }
+        })
     }
 }
 
 impl Error for RealAlgebraicNumberParseError {}
 
-impl Num for RealAlgebraicNumber {
-    type FromStrRadixErr = RealAlgebraicNumberParseError;
-    fn from_str_radix(_str: &str, _radix: u32) -> Result<Self, Self::FromStrRadixErr> {
-        unimplemented!()
+impl std::str::FromStr for RealAlgebraicNumber {
+    type Err = RealAlgebraicNumberParseError;
+    /// parses an integer (`123`, `-45`), a fraction (`22/7`), or an exact
+    /// decimal literal (`3.14159`) into the corresponding exact
+    /// [`RealAlgebraicNumber`]
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        if text.is_empty() {
+            return Err(RealAlgebraicNumberParseError::Empty);
+        }
+        if let Some((numer_text, denom_text)) = text.split_once('/') {
+            let numer: BigInt = numer_text
+                .parse()
+                .map_err(|_| RealAlgebraicNumberParseError::InvalidLiteral)?;
+            let denom: BigInt = denom_text
+                .parse()
+                .map_err(|_| RealAlgebraicNumberParseError::InvalidLiteral)?;
+            if denom.is_zero() {
+                return Err(RealAlgebraicNumberParseError::ZeroDenominator);
+            }
+            return Ok(Ratio::new(numer, denom).into());
+        }
+        if let Some((integer_part, fractional_part)) = text.split_once('.') {
+            if fractional_part.is_empty() || !fractional_part.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(RealAlgebraicNumberParseError::InvalidLiteral);
+            }
+            let negative = integer_part.starts_with('-');
+            let digits: BigInt = format!(
+                "{}{}",
+                integer_part.trim_start_matches(['+', '-']),
+                fractional_part
+            )
+            .parse()
+            .map_err(|_| RealAlgebraicNumberParseError::InvalidLiteral)?;
+            let numer = if negative { -digits } else { digits };
+            let denom = BigInt::from(10).pow(fractional_part.len() as u32);
+            return Ok(Ratio::new(numer, denom).into());
+        }
+        let value: BigInt = text
+            .parse()
+            .map_err(|_| RealAlgebraicNumberParseError::InvalidLiteral)?;
+        Ok(value.into())
+    }
+}
+
+/// a symbolic radical expression built out of integers combined with
+/// `+`, `-`, `*`, `/`, and `sqrt`, as produced by
+/// [`RealAlgebraicNumber::to_radical_expression`]
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub enum RadicalExpression {
+    Integer(BigInt),
+    Sqrt(BigInt),
+    Neg(Box<RadicalExpression>),
+    Add(Box<RadicalExpression>, Box<RadicalExpression>),
+    Mul(Box<RadicalExpression>, Box<RadicalExpression>),
+    Div(Box<RadicalExpression>, Box<RadicalExpression>),
+}
+
+impl RadicalExpression {
+    /// writes `self` as an operand of a `*`, `/`, or unary `-`, adding
+    /// parentheses if `self` is an [`Add`](RadicalExpression::Add), since
+    /// that's the only variant with lower precedence than those operators
+    fn fmt_as_operand(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let RadicalExpression::Add(..) = self {
+            write!(f, "({})", self)
+        } else {
+            write!(f, "{}", self)
+        }
     }
 }
 
-impl Signed for RealAlgebraicNumber {
+impl fmt::Display for RadicalExpression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RadicalExpression::Integer(value) => write!(f, "{}", value),
+            RadicalExpression::Sqrt(value) => write!(f, "sqrt({})", value),
+            RadicalExpression::Neg(value) => {
+                write!(f, "-")?;
+                value.fmt_as_operand(f)
+            }
+            RadicalExpression::Add(lhs, rhs) => {
+                write!(f, "{}", lhs)?;
+                if let RadicalExpression::Neg(rhs) = &**rhs {
+                    write!(f, " - ")?;
+                    rhs.fmt_as_operand(f)
+                } else {
+                    write!(f, " + {}", rhs)
+                }
+            }
+            RadicalExpression::Mul(lhs, rhs) => {
+                lhs.fmt_as_operand(f)?;
+                write!(f, "*")?;
+                rhs.fmt_as_operand(f)
+            }
+            RadicalExpression::Div(lhs, rhs) => {
+                lhs.fmt_as_operand(f)?;
+                write!(f, "/")?;
+                rhs.fmt_as_operand(f)
+            }
+        }
+    }
+}
+
+/// splits positive `n` into `(outside, inside)` such that `n == outside *
+/// outside * inside` and `inside` is square-free, by pulling matched
+/// pairs of prime factors out of the radical
+fn split_out_square_factors(n: BigInt) -> (BigInt, BigInt) {
+    let mut outside = BigInt::one();
+    let mut inside = BigInt::one();
+    for (prime, exponent) in factorize(n) {
+        outside *= prime.clone().pow(exponent / 2);
+        if exponent % 2 != 0 {
+            inside *= prime;
+        }
+    }
+    (outside, inside)
+}
+
+impl Num for RealAlgebraicNumber {
+    type FromStrRadixErr = RealAlgebraicNumberParseError;
+    fn from_str_radix(_str: &str, _radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        unimplemented!()
+    }
+}
+
+impl Signed for RealAlgebraicNumber {
+    /// returns the absolute value of `self`; the sign check underlying
+    /// this only refines `self`'s isolating interval when that interval
+    /// straddles zero, see [`RealAlgebraicNumber::cmp_with_zero`]
     fn abs(&self) -> Self {
         if self.is_negative() {
             -self
@@ -1246,8 +2346,23 @@ impl Signed for RealAlgebraicNumber {
     }
 }
 
+impl hash::Hash for RealAlgebraicNumber {
+    /// hashes `self`'s minimal polynomial, which is canonical for a given
+    /// value regardless of which isolating interval `self` happens to use,
+    /// so equal numbers are guaranteed to hash equally
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.minimal_polynomial().hash(state);
+    }
+}
+
 impl PartialEq for RealAlgebraicNumber {
     fn eq(&self, rhs: &RealAlgebraicNumber) -> bool {
+        if let Some(ordering) = self.quick_cmp(rhs) {
+            return ordering == Ordering::Equal;
+        }
+        if self.quick_ne(rhs) {
+            return false;
+        }
         (self - rhs).is_zero()
     }
 }
@@ -1262,10 +2377,37 @@ impl PartialOrd for RealAlgebraicNumber {
 
 impl Ord for RealAlgebraicNumber {
     fn cmp(&self, rhs: &RealAlgebraicNumber) -> Ordering {
+        if let Some(ordering) = self.quick_cmp(rhs) {
+            return ordering;
+        }
         (self - rhs).cmp_with_zero()
     }
 }
 
+impl PartialEq<Ratio<BigInt>> for RealAlgebraicNumber {
+    fn eq(&self, rhs: &Ratio<BigInt>) -> bool {
+        self.cmp_with_rational(rhs) == Ordering::Equal
+    }
+}
+
+impl PartialOrd<Ratio<BigInt>> for RealAlgebraicNumber {
+    fn partial_cmp(&self, rhs: &Ratio<BigInt>) -> Option<Ordering> {
+        Some(self.cmp_with_rational(rhs))
+    }
+}
+
+impl PartialEq<BigInt> for RealAlgebraicNumber {
+    fn eq(&self, rhs: &BigInt) -> bool {
+        *self == Ratio::from(rhs.clone())
+    }
+}
+
+impl PartialOrd<BigInt> for RealAlgebraicNumber {
+    fn partial_cmp(&self, rhs: &BigInt) -> Option<Ordering> {
+        self.partial_cmp(&Ratio::from(rhs.clone()))
+    }
+}
+
 impl MulAssign for RealAlgebraicNumber {
     fn mul_assign(&mut self, mut rhs: RealAlgebraicNumber) {
         #![allow(clippy::suspicious_op_assign_impl)] // we need to use other operators
@@ -1603,6 +2745,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_to_latex() {
+        let real_algebraic_number = RealAlgebraicNumber::new_unchecked(
+            p(&[-2, 0, 1]),
+            DyadicFractionInterval::from_int_range(bi(1), bi(2), 0),
+        );
+        assert_eq!(
+            real_algebraic_number.to_latex(),
+            "-2 + 0X + 1X^{2} = 0,\\ X \\in [1, 2]"
+        );
+        let real_algebraic_number = RealAlgebraicNumber::new_unchecked(
+            p(&[-1, 2]),
+            DyadicFractionInterval::from_ratio_range(r(1, 4), r(3, 4), 4),
+        );
+        assert_eq!(
+            real_algebraic_number.to_latex(),
+            "-1 + 2X = 0,\\ X \\in [\\frac{1}{4}, \\frac{3}{4}]"
+        );
+    }
+
     #[test]
     fn test_neg() {
         fn test_case(
@@ -1617,7 +2779,7 @@ mod tests {
                 DyadicFractionInterval::from_int_range(bi(2), bi(3), 0),
             ),
             RealAlgebraicNumberData {
-                minimal_polynomial: p(&[-1, 2, 1]),
+                minimal_polynomial: Arc::new(p(&[-1, 2, 1])),
                 interval: DyadicFractionInterval::from_int_range(bi(-3), bi(-2), 0),
             },
         );
@@ -1668,6 +2830,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_signed() {
+        fn test_case<A: Into<RealAlgebraicNumber>, E: Into<RealAlgebraicNumber>>(
+            value: A,
+            expected_abs: E,
+            expected_signum: i128,
+        ) {
+            let value = value.into();
+            println!("value: {:?}", value);
+            assert_eq!(value.abs(), expected_abs.into());
+            assert_eq!(value.signum(), RealAlgebraicNumber::from(expected_signum));
+            assert_eq!(value.is_positive(), expected_signum > 0);
+            assert_eq!(value.is_negative(), expected_signum < 0);
+        }
+        test_case(0, 0, 0);
+        test_case(5, 5, 1);
+        test_case(-5, 5, -1);
+        test_case(r(-1, 3), r(1, 3), -1);
+        test_case(
+            make_sqrt(2, DyadicFractionInterval::from_int_range(bi(1), bi(2), 0)),
+            make_sqrt(2, DyadicFractionInterval::from_int_range(bi(1), bi(2), 0)),
+            1,
+        );
+        test_case(
+            -make_sqrt(2, DyadicFractionInterval::from_int_range(bi(1), bi(2), 0)),
+            make_sqrt(2, DyadicFractionInterval::from_int_range(bi(1), bi(2), 0)),
+            -1,
+        );
+    }
+
     #[test]
     fn test_add() {
         fn test_case<
@@ -1834,6 +3026,616 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_round() {
+        fn test_case<V: Into<RealAlgebraicNumber>, E: Into<BigInt>>(value: V, expected: E) {
+            let value = value.into();
+            println!("value: {:?}", value);
+            let expected = expected.into();
+            println!("expected: {}", expected);
+            let round = value.to_integer_round();
+            println!("round: {}", round);
+            assert!(expected == round);
+        }
+        test_case(1, 1);
+        test_case(r(6, 5), 1);
+        test_case(r(4, 5), 1);
+        // ties round away from zero
+        test_case(r(1, 2), 1);
+        test_case(r(-1, 2), -1);
+        test_case(r(3, 2), 2);
+        test_case(
+            make_sqrt(2, DyadicFractionInterval::from_int_range(bi(1), bi(2), 0)),
+            1,
+        );
+        test_case(
+            make_sqrt(
+                2_000_000,
+                DyadicFractionInterval::from_int_range(bi(1000), bi(2000), 0),
+            ),
+            1_414,
+        );
+        test_case(
+            make_sqrt(2, DyadicFractionInterval::from_int_range(bi(-2), bi(-1), 0)),
+            -1,
+        );
+    }
+
+    #[test]
+    fn test_decimal_string() {
+        // exact rational values don't get an ellipsis
+        assert_eq!(RealAlgebraicNumber::from(r(1, 4)).to_decimal_string(2), "0.25");
+        assert_eq!(RealAlgebraicNumber::from(5).to_decimal_string(3), "5.000");
+        // inexact rational values get an ellipsis and are rounded to the
+        // nearest representable value
+        assert_eq!(
+            RealAlgebraicNumber::from(r(1, 3)).to_decimal_string(3),
+            "0.333\u{2026}"
+        );
+        assert_eq!(
+            RealAlgebraicNumber::from(r(-1, 3)).to_decimal_string(3),
+            "-0.333\u{2026}"
+        );
+        // irrational values always get an ellipsis
+        let sqrt2 = make_sqrt(2, DyadicFractionInterval::from_int_range(bi(1), bi(2), 0));
+        assert_eq!(sqrt2.to_decimal_string(5), "1.41421\u{2026}");
+        assert_eq!(format!("{}", sqrt2), "1.4142135624\u{2026}");
+        assert_eq!(format!("{:.2}", sqrt2), "1.41\u{2026}");
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            "123".parse::<RealAlgebraicNumber>().unwrap(),
+            RealAlgebraicNumber::from(123)
+        );
+        assert_eq!(
+            "-123".parse::<RealAlgebraicNumber>().unwrap(),
+            RealAlgebraicNumber::from(-123)
+        );
+        assert_eq!(
+            "22/7".parse::<RealAlgebraicNumber>().unwrap(),
+            RealAlgebraicNumber::from(r(22, 7))
+        );
+        assert_eq!(
+            "-22/7".parse::<RealAlgebraicNumber>().unwrap(),
+            RealAlgebraicNumber::from(r(-22, 7))
+        );
+        assert_eq!(
+            "3.14159".parse::<RealAlgebraicNumber>().unwrap(),
+            RealAlgebraicNumber::from(r(314_159, 100_000))
+        );
+        assert_eq!(
+            "-3.5".parse::<RealAlgebraicNumber>().unwrap(),
+            RealAlgebraicNumber::from(r(-7, 2))
+        );
+        assert_eq!(
+            "".parse::<RealAlgebraicNumber>(),
+            Err(RealAlgebraicNumberParseError::Empty)
+        );
+        assert_eq!(
+            "abc".parse::<RealAlgebraicNumber>(),
+            Err(RealAlgebraicNumberParseError::InvalidLiteral)
+        );
+        assert_eq!(
+            "1/0".parse::<RealAlgebraicNumber>(),
+            Err(RealAlgebraicNumberParseError::ZeroDenominator)
+        );
+        assert_eq!(
+            "1.".parse::<RealAlgebraicNumber>(),
+            Err(RealAlgebraicNumberParseError::InvalidLiteral)
+        );
+    }
+
+    #[test]
+    fn test_from_float() {
+        assert_eq!(
+            RealAlgebraicNumber::try_from(0.0f64),
+            Ok(RealAlgebraicNumber::from(0))
+        );
+        assert_eq!(
+            RealAlgebraicNumber::try_from(-0.0f64),
+            Ok(RealAlgebraicNumber::from(0))
+        );
+        assert_eq!(
+            RealAlgebraicNumber::try_from(1.0f64),
+            Ok(RealAlgebraicNumber::from(1))
+        );
+        assert_eq!(
+            RealAlgebraicNumber::try_from(-2.5f64),
+            Ok(RealAlgebraicNumber::from(r(-5, 2)))
+        );
+        assert_eq!(
+            RealAlgebraicNumber::try_from(0.1f64),
+            Ok(RealAlgebraicNumber::from(r(
+                3_602_879_701_896_397,
+                36_028_797_018_963_968
+            )))
+        );
+        assert_eq!(
+            RealAlgebraicNumber::try_from(1.5f32),
+            Ok(RealAlgebraicNumber::from(r(3, 2)))
+        );
+        assert_eq!(
+            RealAlgebraicNumber::try_from(f64::NAN),
+            Err(FloatIsNotFiniteError)
+        );
+        assert_eq!(
+            RealAlgebraicNumber::try_from(f64::INFINITY),
+            Err(FloatIsNotFiniteError)
+        );
+        assert_eq!(
+            RealAlgebraicNumber::try_from(f64::NEG_INFINITY),
+            Err(FloatIsNotFiniteError)
+        );
+        assert_eq!(
+            RealAlgebraicNumber::try_from(f32::NAN),
+            Err(FloatIsNotFiniteError)
+        );
+    }
+
+    #[test]
+    fn test_recognize() {
+        assert_eq!(RealAlgebraicNumber::recognize(f64::NAN, 4, 16), None);
+        assert_eq!(RealAlgebraicNumber::recognize(1.5, 0, 16), None);
+        // an exact rational is always recognized at degree 1
+        assert_eq!(
+            RealAlgebraicNumber::recognize(0.5, 4, 16),
+            Some(RealAlgebraicNumber::from(r(1, 2)))
+        );
+        // sqrt(2), the root of x^2 - 2
+        assert_eq!(
+            RealAlgebraicNumber::recognize(std::f64::consts::SQRT_2, 4, 16),
+            Some(RealAlgebraicNumber::new_unchecked(
+                p(&[-2, 0, 1]),
+                DyadicFractionInterval::from_int_range(bi(1), bi(2), 0),
+            ))
+        );
+        // the golden ratio, the positive root of x^2 - x - 1
+        let golden_ratio_approx = (1.0 + 5f64.sqrt()) / 2.0;
+        assert_eq!(
+            RealAlgebraicNumber::recognize(golden_ratio_approx, 4, 16),
+            Some(RealAlgebraicNumber::new_unchecked(
+                p(&[-1, -1, 1]),
+                DyadicFractionInterval::from_int_range(bi(1), bi(2), 0),
+            ))
+        );
+        // a transcendental-looking value shouldn't match a low-degree,
+        // small-coefficient algebraic number
+        assert_eq!(
+            RealAlgebraicNumber::recognize(std::f64::consts::PI, 3, 10),
+            None
+        );
+    }
+
+    #[test]
+    fn test_cos_pi_ratio() {
+        assert_eq!(
+            RealAlgebraicNumber::cos_pi_ratio(&ri(0)),
+            RealAlgebraicNumber::one()
+        );
+        assert_eq!(
+            RealAlgebraicNumber::cos_pi_ratio(&ri(1)),
+            RealAlgebraicNumber::from(-1)
+        );
+        assert_eq!(
+            RealAlgebraicNumber::cos_pi_ratio(&ri(2)),
+            RealAlgebraicNumber::one()
+        );
+        assert_eq!(
+            RealAlgebraicNumber::cos_pi_ratio(&r(1, 2)),
+            RealAlgebraicNumber::zero()
+        );
+        assert_eq!(
+            RealAlgebraicNumber::cos_pi_ratio(&r(1, 3)),
+            RealAlgebraicNumber::from(r(1, 2))
+        );
+        assert_eq!(
+            RealAlgebraicNumber::cos_pi_ratio(&r(2, 3)),
+            -RealAlgebraicNumber::from(r(1, 2))
+        );
+        let sqrt2_over_2 = RealAlgebraicNumber::from(2)
+            .checked_pow((1i32, 2i32))
+            .unwrap()
+            / RealAlgebraicNumber::from(2);
+        assert_eq!(RealAlgebraicNumber::cos_pi_ratio(&r(1, 4)), sqrt2_over_2);
+        assert_eq!(
+            RealAlgebraicNumber::cos_pi_ratio(&r(3, 4)),
+            -sqrt2_over_2
+        );
+    }
+
+    #[test]
+    fn test_sin_pi_ratio() {
+        assert_eq!(
+            RealAlgebraicNumber::sin_pi_ratio(&ri(0)),
+            RealAlgebraicNumber::zero()
+        );
+        assert_eq!(
+            RealAlgebraicNumber::sin_pi_ratio(&r(1, 2)),
+            RealAlgebraicNumber::one()
+        );
+        assert_eq!(
+            RealAlgebraicNumber::sin_pi_ratio(&r(3, 2)),
+            RealAlgebraicNumber::from(-1)
+        );
+        assert_eq!(
+            RealAlgebraicNumber::sin_pi_ratio(&r(1, 6)),
+            RealAlgebraicNumber::from(r(1, 2))
+        );
+    }
+
+    #[test]
+    fn test_tan_pi_ratio() {
+        assert_eq!(
+            RealAlgebraicNumber::tan_pi_ratio(&ri(0)),
+            RealAlgebraicNumber::zero()
+        );
+        assert_eq!(
+            RealAlgebraicNumber::tan_pi_ratio(&r(1, 4)),
+            RealAlgebraicNumber::one()
+        );
+        assert_eq!(
+            RealAlgebraicNumber::tan_pi_ratio(&r(3, 4)),
+            RealAlgebraicNumber::from(-1)
+        );
+    }
+
+    #[test]
+    fn test_canonicalize_in() {
+        let context = Context::new();
+        let sqrt2_a = make_sqrt(2, DyadicFractionInterval::from_int_range(bi(1), bi(2), 0));
+        let sqrt2_b = make_sqrt(2, DyadicFractionInterval::from_int_range(bi(1), bi(2), 0));
+        assert!(!Arc::ptr_eq(
+            &sqrt2_a.data().minimal_polynomial,
+            &sqrt2_b.data().minimal_polynomial
+        ));
+        let sqrt2_a = sqrt2_a.canonicalize_in(&context);
+        let sqrt2_b = sqrt2_b.canonicalize_in(&context);
+        assert_eq!(sqrt2_a, sqrt2_b);
+        assert!(Arc::ptr_eq(
+            &sqrt2_a.data().minimal_polynomial,
+            &sqrt2_b.data().minimal_polynomial
+        ));
+        // canonicalizing doesn't change the value
+        let sqrt3 = make_sqrt(3, DyadicFractionInterval::from_int_range(bi(1), bi(2), 0))
+            .canonicalize_in(&context);
+        assert_eq!(sqrt3, make_sqrt(3, DyadicFractionInterval::from_int_range(bi(1), bi(2), 0)));
+        assert!(!Arc::ptr_eq(
+            &sqrt2_a.data().minimal_polynomial,
+            &sqrt3.data().minimal_polynomial
+        ));
+    }
+
+    #[test]
+    fn test_cmp_with_rational() {
+        let sqrt2 = make_sqrt(2, DyadicFractionInterval::from_int_range(bi(1), bi(2), 0));
+        assert!(sqrt2 > r(1, 1));
+        assert!(sqrt2 < r(2, 1));
+        assert!(sqrt2 > r(14142, 10000));
+        assert!(sqrt2 < r(14143, 10000));
+        assert_eq!(sqrt2.partial_cmp(&r(3, 2)), Some(Ordering::Less));
+        let rational = RealAlgebraicNumber::from(r(3, 2));
+        assert!(rational == r(3, 2));
+        assert!(rational < r(2, 1));
+        assert!(rational > r(1, 1));
+    }
+
+    #[test]
+    fn test_cmp_with_integer() {
+        let sqrt2 = make_sqrt(2, DyadicFractionInterval::from_int_range(bi(1), bi(2), 0));
+        assert!(sqrt2 > bi(1));
+        assert!(sqrt2 < bi(2));
+        assert_ne!(sqrt2, bi(1));
+        let three = RealAlgebraicNumber::from(3);
+        assert!(three == bi(3));
+        assert!(three < bi(4));
+        assert!(three > bi(2));
+    }
+
+    #[test]
+    fn test_quick_cmp_disjoint_intervals() {
+        // both starting intervals contain their respective roots (sqrt(2)
+        // is about 1.4142, sqrt(3) is about 1.7320) but don't overlap each
+        // other, so comparing them shouldn't need to build the difference
+        let sqrt2 = make_sqrt(2, DyadicFractionInterval::new(bi(22), bi(23), 4));
+        let sqrt3 = make_sqrt(3, DyadicFractionInterval::new(bi(27), bi(28), 4));
+        assert_eq!(sqrt2.quick_cmp(&sqrt3), Some(Ordering::Less));
+        assert!(sqrt2 < sqrt3);
+        assert_ne!(sqrt2, sqrt3);
+    }
+
+    #[test]
+    fn test_quick_ne_different_degree() {
+        let sqrt2 = make_sqrt(2, DyadicFractionInterval::from_int_range(bi(1), bi(2), 0));
+        let one = RealAlgebraicNumber::from(1);
+        assert!(sqrt2.quick_ne(&one));
+        assert_ne!(sqrt2, one);
+    }
+
+    #[test]
+    fn test_try_new() {
+        let sqrt2 = RealAlgebraicNumber::try_new(
+            p(&[-2, 0, 1]),
+            DyadicFractionInterval::from_int_range(bi(1), bi(2), 0),
+        )
+        .unwrap();
+        assert_eq!(sqrt2, make_sqrt(2, DyadicFractionInterval::from_int_range(bi(1), bi(2), 0)));
+        // the interval lands exactly on a rational root
+        assert_eq!(
+            RealAlgebraicNumber::try_new(p(&[-4, 0, 1]), DyadicFractionInterval::from_int_range(bi(2), bi(3), 0))
+                .unwrap(),
+            RealAlgebraicNumber::from(2)
+        );
+        // factoring should pick out the one factor with a root in the interval
+        // p(x) = (x - 2) * (x^2 - 2); use [1, 3/2] so only sqrt2 is inside
+        let combined = p(&[4, -2, -2, 1]);
+        let tight_interval = DyadicFractionInterval::new(bi(2), bi(3), 1);
+        assert_eq!(
+            RealAlgebraicNumber::try_new(combined, tight_interval.clone()).unwrap(),
+            make_sqrt(2, tight_interval)
+        );
+    }
+
+    #[test]
+    fn test_try_new_zero_polynomial() {
+        assert_eq!(
+            RealAlgebraicNumber::try_new(
+                Polynomial::<BigInt>::zero(),
+                DyadicFractionInterval::from_int_range(bi(1), bi(2), 0)
+            ),
+            Err(RootIsolationError::ZeroPolynomial)
+        );
+    }
+
+    #[test]
+    fn test_try_new_no_root_in_interval() {
+        assert_eq!(
+            RealAlgebraicNumber::try_new(
+                p(&[-2, 0, 1]),
+                DyadicFractionInterval::from_int_range(bi(2), bi(3), 0)
+            ),
+            Err(RootIsolationError::NoRootInInterval)
+        );
+    }
+
+    #[test]
+    fn test_try_new_ambiguous_root() {
+        // x^2 - 4 has roots -2 and 2, both inside [-3, 3]
+        assert_eq!(
+            RealAlgebraicNumber::try_new(
+                p(&[-4, 0, 1]),
+                DyadicFractionInterval::from_int_range(bi(-3), bi(3), 0)
+            ),
+            Err(RootIsolationError::AmbiguousRoot)
+        );
+    }
+
+    #[test]
+    fn test_try_new_ambiguous_root_with_boundary_root() {
+        // (x - 2) * (x^2 - 6) has a root exactly at the lower bound (2)
+        // and another root strictly inside the interval (sqrt(6) ~= 2.449),
+        // so the interval doesn't isolate a single root even though one
+        // of the roots lands exactly on the boundary
+        assert_eq!(
+            RealAlgebraicNumber::try_new(
+                p(&[12, -6, -2, 1]),
+                DyadicFractionInterval::from_int_range(bi(2), bi(3), 0)
+            ),
+            Err(RootIsolationError::AmbiguousRoot)
+        );
+    }
+
+    #[test]
+    fn test_real_roots() {
+        // (x - 2) * (x^2 - 2)^2, so 2 has multiplicity 1 and +-sqrt(2) has multiplicity 2
+        let polynomial = p(&[4, -2, -2, 1]) * p(&[-2, 0, 1]);
+        let roots = real_roots(&polynomial);
+        let sqrt2 = make_sqrt(2, DyadicFractionInterval::from_int_range(bi(1), bi(2), 0));
+        assert_eq!(roots.len(), 3);
+        assert_eq!(roots[0].value, -&sqrt2);
+        assert_eq!(roots[0].multiplicity, 2);
+        assert_eq!(roots[1].value, sqrt2);
+        assert_eq!(roots[1].multiplicity, 2);
+        assert_eq!(roots[2].value, RealAlgebraicNumber::from(2));
+        assert_eq!(roots[2].multiplicity, 1);
+    }
+
+    #[test]
+    fn test_real_roots_zero_polynomial() {
+        assert_eq!(real_roots(&Polynomial::<BigInt>::zero()), Vec::new());
+    }
+
+    #[test]
+    fn test_real_roots_no_real_roots() {
+        // x^2 + 1 has no real roots
+        assert_eq!(real_roots(&p(&[1, 0, 1])), Vec::new());
+    }
+
+    #[test]
+    fn test_refine_to() {
+        let sqrt2 = make_sqrt(2, DyadicFractionInterval::from_int_range(bi(1), bi(2), 0));
+        let interval = sqrt2.refine_to(10);
+        assert!(interval.log2_denom() >= 10);
+        assert!(interval.lower_bound() <= r(14142, 10000));
+        assert!(interval.upper_bound() >= r(14142, 10000));
+        assert!(interval.lower_bound() < interval.upper_bound());
+    }
+
+    #[test]
+    fn test_refine_to_exact_value() {
+        let five = RealAlgebraicNumber::from(5);
+        let interval = five.refine_to(20);
+        assert!(interval.log2_denom() >= 20);
+        assert_eq!(interval.lower_bound(), ri(5));
+        assert_eq!(interval.upper_bound(), ri(5));
+    }
+
+    #[test]
+    fn test_refine_until() {
+        let sqrt2 = make_sqrt(2, DyadicFractionInterval::from_int_range(bi(1), bi(2), 0));
+        let interval =
+            sqrt2.refine_until(|interval| interval.upper_bound() - interval.lower_bound() < r(1, 100));
+        assert!(interval.upper_bound() - interval.lower_bound() < r(1, 100));
+        assert!(interval.lower_bound() <= r(14142, 10000));
+        assert!(interval.upper_bound() >= r(14142, 10000));
+    }
+
+    #[test]
+    fn test_refine_until_stops_when_exact() {
+        let five = RealAlgebraicNumber::from(5);
+        // this predicate can never be satisfied, so refine_until must stop
+        // once the interval collapses to the exact value instead of looping
+        // forever
+        let interval = five.refine_until(|_| false);
+        assert_eq!(interval.lower_bound(), ri(5));
+        assert_eq!(interval.upper_bound(), ri(5));
+    }
+
+    #[test]
+    fn test_to_radical_expression() {
+        fn to_string(value: &RealAlgebraicNumber) -> String {
+            value.to_radical_expression().unwrap().to_string()
+        }
+        assert_eq!(to_string(&RealAlgebraicNumber::from(5)), "5");
+        assert_eq!(to_string(&RealAlgebraicNumber::from(-5)), "-5");
+        assert_eq!(to_string(&RealAlgebraicNumber::from(r(1, 3))), "1/3");
+        // sqrt(2), the root of x^2 - 2 in [1, 2]
+        let sqrt2 = RealAlgebraicNumber::new_unchecked(
+            p(&[-2, 0, 1]),
+            DyadicFractionInterval::from_int_range(bi(1), bi(2), 0),
+        );
+        assert_eq!(to_string(&sqrt2), "sqrt(2)");
+        assert_eq!(to_string(&-sqrt2.clone()), "-sqrt(2)");
+        // golden ratio, the positive root of x^2 - x - 1
+        let golden_ratio = RealAlgebraicNumber::new_unchecked(
+            p(&[-1, -1, 1]),
+            DyadicFractionInterval::from_int_range(bi(1), bi(2), 0),
+        );
+        assert_eq!(to_string(&golden_ratio), "(1 + sqrt(5))/2");
+        // the other root of x^2 - x - 1
+        let golden_ratio_conjugate = RealAlgebraicNumber::new_unchecked(
+            p(&[-1, -1, 1]),
+            DyadicFractionInterval::from_int_range(bi(-1), bi(0), 0),
+        );
+        assert_eq!(to_string(&golden_ratio_conjugate), "(1 - sqrt(5))/2");
+        // the root of x^2 - 2x - 1 in [2, 3], which simplifies to 1 + sqrt(2)
+        let one_plus_sqrt2 = RealAlgebraicNumber::new_unchecked(
+            p(&[-1, -2, 1]),
+            DyadicFractionInterval::from_int_range(bi(2), bi(3), 0),
+        );
+        assert_eq!(to_string(&one_plus_sqrt2), "1 + sqrt(2)");
+        // cubics aren't implemented yet
+        assert_eq!(
+            RealAlgebraicNumber::new_unchecked(
+                p(&[-2, 0, 0, 1]),
+                DyadicFractionInterval::from_int_range(bi(1), bi(2), 0),
+            )
+            .to_radical_expression(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_real_conjugates() {
+        // rational numbers have no conjugates
+        assert_eq!(
+            RealAlgebraicNumber::from(5).real_conjugates(),
+            Vec::<RealAlgebraicNumber>::new()
+        );
+        // sqrt(2)'s only other real conjugate is -sqrt(2)
+        let sqrt2 = make_sqrt(2, DyadicFractionInterval::from_int_range(bi(1), bi(2), 0));
+        assert_eq!(sqrt2.real_conjugates(), vec![-sqrt2.clone()]);
+        // -sqrt(2) likewise conjugates to sqrt(2)
+        let neg_sqrt2 = -sqrt2.clone();
+        assert_eq!(neg_sqrt2.real_conjugates(), vec![sqrt2]);
+    }
+
+    #[test]
+    fn test_root_index() {
+        assert_eq!(RealAlgebraicNumber::from(5).root_index(), 0);
+        let sqrt2 = make_sqrt(2, DyadicFractionInterval::from_int_range(bi(1), bi(2), 0));
+        let neg_sqrt2 = -sqrt2.clone();
+        assert_eq!(neg_sqrt2.root_index(), 0);
+        assert_eq!(sqrt2.root_index(), 1);
+    }
+
+    #[test]
+    fn test_from_poly_and_root_index() {
+        let sqrt2 = make_sqrt(2, DyadicFractionInterval::from_int_range(bi(1), bi(2), 0));
+        let neg_sqrt2 = -sqrt2.clone();
+        let poly = p(&[-2, 0, 1]);
+        assert_eq!(
+            RealAlgebraicNumber::from_poly_and_root_index(&poly, 0),
+            Some(neg_sqrt2)
+        );
+        assert_eq!(
+            RealAlgebraicNumber::from_poly_and_root_index(&poly, 1),
+            Some(sqrt2)
+        );
+        assert_eq!(RealAlgebraicNumber::from_poly_and_root_index(&poly, 2), None);
+    }
+
+    #[test]
+    fn test_root_index_from_poly_and_root_index_round_trip() {
+        let sqrt2 = make_sqrt(2, DyadicFractionInterval::from_int_range(bi(1), bi(2), 0));
+        assert_eq!(
+            RealAlgebraicNumber::from_poly_and_root_index(
+                sqrt2.minimal_polynomial(),
+                sqrt2.root_index()
+            ),
+            Some(sqrt2)
+        );
+    }
+
+    #[test]
+    fn test_naive_height() {
+        assert_eq!(RealAlgebraicNumber::from(5).naive_height(), bi(5));
+        let sqrt2 = make_sqrt(2, DyadicFractionInterval::from_int_range(bi(1), bi(2), 0));
+        assert_eq!(sqrt2.naive_height(), bi(2));
+    }
+
+    #[test]
+    fn test_mahler_measure() {
+        let sqrt2 = make_sqrt(2, DyadicFractionInterval::from_int_range(bi(1), bi(2), 0));
+        let measure = sqrt2.mahler_measure();
+        assert_eq!(*measure.lower_bound_numer(), bi(1));
+        assert_eq!(*measure.upper_bound_numer(), bi(3));
+        assert_eq!(measure.log2_denom(), 0);
+        // the true Mahler measure of X^2 - 2, |sqrt(2)| * |-sqrt(2)| == 2,
+        // must lie within the rigorous enclosure
+        assert!(measure.lower_bound() <= ri(2));
+        assert!(measure.upper_bound() >= ri(2));
+    }
+
+    #[test]
+    fn test_logarithmic_height() {
+        let sqrt2 = make_sqrt(2, DyadicFractionInterval::from_int_range(bi(1), bi(2), 0));
+        let height = sqrt2.logarithmic_height();
+        // the true logarithmic height, log2(2) / 2 == 1/2, must lie
+        // within the rigorous enclosure
+        assert!(height.lower_bound() <= r(1, 2));
+        assert!(height.upper_bound() >= r(1, 2));
+        // the integer 1 has Mahler measure 1, so its logarithmic height is 0
+        let one_height = RealAlgebraicNumber::from(1).logarithmic_height();
+        assert!(one_height.lower_bound() <= ri(0));
+        assert!(one_height.upper_bound() >= ri(0));
+    }
+
+    #[test]
+    fn test_trace_norm() {
+        // rational numbers are their own trace and norm
+        assert_eq!(RealAlgebraicNumber::from(5).trace(), ri(5));
+        assert_eq!(RealAlgebraicNumber::from(5).norm(), ri(5));
+        assert_eq!(RealAlgebraicNumber::from(r(-3, 4)).trace(), r(-3, 4));
+        assert_eq!(RealAlgebraicNumber::from(r(-3, 4)).norm(), r(-3, 4));
+        // sqrt(2) has minimal polynomial X^2 - 2, so its conjugates are
+        // +/- sqrt(2), which sum to 0 and multiply to -2
+        let sqrt2 = make_sqrt(2, DyadicFractionInterval::from_int_range(bi(1), bi(2), 0));
+        assert_eq!(sqrt2.trace(), ri(0));
+        assert_eq!(sqrt2.norm(), ri(-2));
+    }
+
     #[test]
     fn test_mul() {
         fn test_case<
@@ -1938,6 +3740,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_checked_div_and_is_zero_fast() {
+        let sqrt2 = make_sqrt(2, DyadicFractionInterval::from_int_range(bi(1), bi(2), 0));
+        assert_eq!(
+            sqrt2.checked_div(&RealAlgebraicNumber::from(2)),
+            Some(sqrt2.clone() * RealAlgebraicNumber::from(r(1, 2)))
+        );
+        assert_eq!(sqrt2.checked_div(&RealAlgebraicNumber::zero()), None);
+        assert_eq!(
+            RealAlgebraicNumber::from(0).checked_div(&sqrt2),
+            Some(RealAlgebraicNumber::from(0))
+        );
+
+        assert!(!sqrt2.is_zero_fast());
+        assert!(RealAlgebraicNumber::zero().is_zero_fast());
+        // an interval that straddles zero but whose value isn't zero still
+        // falls back to the exact check correctly
+        let non_zero_straddling = RealAlgebraicNumber::new_unchecked(
+            p(&[-1, 1]),
+            DyadicFractionInterval::from_int_range(bi(-1), bi(2), 0),
+        );
+        assert!(!non_zero_straddling.is_zero_fast());
+    }
+
     #[test]
     fn test_pow() {
         fn test_case<B: Into<RealAlgebraicNumber>, E: Into<Ratio<BigInt>>>(
@@ -2021,6 +3847,51 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_pow_with_policy() {
+        fn test_case<B: Into<RealAlgebraicNumber>, E: Into<Ratio<BigInt>>>(
+            base: B,
+            exponent: E,
+            policy: NegativeBasePowPolicy,
+            expected: Option<RealAlgebraicNumber>,
+        ) {
+            let base = base.into();
+            let exponent = exponent.into();
+            let result = base.checked_into_pow_with_policy(exponent, policy);
+            assert!(result == expected);
+        }
+        // default policy is unchanged from before this API existed
+        test_case(-8, r(1, 3), NegativeBasePowPolicy::Error, None);
+        test_case(-8, r(1, 3), NegativeBasePowPolicy::ComplexPrincipalBranch, None);
+        // odd denominator: real cube root of -8 is -2
+        test_case(
+            -8,
+            r(1, 3),
+            NegativeBasePowPolicy::RealOddRoot,
+            Some((-2).into()),
+        );
+        // even numerator flips the sign back to positive
+        test_case(
+            -8,
+            r(2, 3),
+            NegativeBasePowPolicy::RealOddRoot,
+            Some(4.into()),
+        );
+        // even denominator: no real root exists
+        test_case(-8, r(1, 2), NegativeBasePowPolicy::RealOddRoot, None);
+        // the base == -1 special case goes through the same policy
+        test_case(-1, r(1, 3), NegativeBasePowPolicy::Error, None);
+        test_case(
+            -1,
+            r(1, 3),
+            NegativeBasePowPolicy::RealOddRoot,
+            Some((-1).into()),
+        );
+        // integer exponents never consult the policy
+        test_case(-2, ri(3), NegativeBasePowPolicy::Error, Some((-8).into()));
+        assert!(RealAlgebraicNumber::from(-8).checked_pow(r(1, 3)).is_none());
+    }
+
     #[test]
     fn test_integer_floor_ceil_log2() {
         fn test_case<V: Into<RealAlgebraicNumber>>(
@@ -2117,4 +3988,52 @@ mod tests {
             Some(-1),
         );
     }
+
+    #[test]
+    fn test_hash_consistent_with_eq() {
+        fn hash_of(value: &RealAlgebraicNumber) -> u64 {
+            use std::hash::{Hash, Hasher};
+            let mut hasher = std::collections::hash_map::DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+        let golden_ratio_a = RealAlgebraicNumber::new_unchecked(
+            p(&[-1, -1, 1]),
+            DyadicFractionInterval::from_int_range(bi(1), bi(2), 0),
+        );
+        let golden_ratio_b = RealAlgebraicNumber::new_unchecked(
+            p(&[-1, -1, 1]),
+            DyadicFractionInterval::from_int_range(bi(1), bi(1000), 0),
+        );
+        assert_eq!(golden_ratio_a, golden_ratio_b);
+        assert_eq!(hash_of(&golden_ratio_a), hash_of(&golden_ratio_b));
+
+        let sqrt2 = make_sqrt(2, DyadicFractionInterval::from_int_range(bi(1), bi(2), 0));
+        assert_ne!(golden_ratio_a, sqrt2);
+        assert_ne!(hash_of(&golden_ratio_a), hash_of(&sqrt2));
+    }
+
+    #[test]
+    fn test_min_max() {
+        let sqrt2 = make_sqrt(2, DyadicFractionInterval::from_int_range(bi(1), bi(2), 0));
+        let one = RealAlgebraicNumber::from(1);
+        let two = RealAlgebraicNumber::from(2);
+        assert_eq!(one.clone().min(sqrt2.clone()), one);
+        assert_eq!(sqrt2.clone().min(one.clone()), one);
+        assert_eq!(one.clone().max(sqrt2.clone()), sqrt2);
+        assert_eq!(sqrt2.clone().max(one.clone()), sqrt2);
+        assert_eq!(sqrt2.clone().min(two.clone()), sqrt2);
+        assert_eq!(sqrt2.clone().max(two.clone()), two);
+        assert_eq!(sqrt2.clone().min(sqrt2.clone()), sqrt2);
+        assert_eq!(sqrt2.clone().max(sqrt2.clone()), sqrt2);
+        assert_eq!(RealAlgebraicNumber::min_of(&[]), None);
+        assert_eq!(
+            RealAlgebraicNumber::min_of(&[two.clone(), sqrt2.clone(), one.clone()]),
+            Some(one.clone())
+        );
+        assert_eq!(
+            RealAlgebraicNumber::max_of(&[two.clone(), sqrt2.clone(), one.clone()]),
+            Some(two)
+        );
+    }
 }
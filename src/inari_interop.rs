@@ -0,0 +1,105 @@
+// SPDX-License-Identifier: LGPL-2.1-or-later
+// See Notices.txt for copyright information
+
+#![cfg(feature = "inari")]
+
+//! conversions between [`DyadicFractionInterval`] and `inari`'s hardware
+//! `f64` interval type, for handing enclosures back and forth with
+//! `inari`-based pipelines; since `inari::Interval`'s endpoints are plain
+//! `f64`s, conversions in that direction necessarily widen the interval
+//! (rounding the lower bound down and the upper bound up) rather than
+//! being exact
+
+use crate::interval_arithmetic::DyadicFractionInterval;
+use inari::Interval;
+use num_rational::Ratio;
+use num_traits::ToPrimitive;
+
+/// rounds `value` down to an `f64` that is `<= value`, unlike
+/// [`ToPrimitive::to_f64`], which rounds to nearest and so can round up
+fn ratio_to_f64_rounding_down(value: &Ratio<num_bigint::BigInt>) -> f64 {
+    let mut result = value.to_f64().expect("finite");
+    while Ratio::from_float(result).expect("finite") > *value {
+        result = f64_next_down(result);
+    }
+    result
+}
+
+/// rounds `value` up to an `f64` that is `>= value`, unlike
+/// [`ToPrimitive::to_f64`], which rounds to nearest and so can round down
+fn ratio_to_f64_rounding_up(value: &Ratio<num_bigint::BigInt>) -> f64 {
+    let mut result = value.to_f64().expect("finite");
+    while Ratio::from_float(result).expect("finite") < *value {
+        result = f64_next_up(result);
+    }
+    result
+}
+
+/// the next representable `f64` towards positive infinity
+fn f64_next_up(value: f64) -> f64 {
+    if value.is_nan() || value == f64::INFINITY {
+        return value;
+    }
+    let bits = value.to_bits();
+    let next_bits = if value == 0.0 {
+        1
+    } else if value > 0.0 {
+        bits + 1
+    } else {
+        bits - 1
+    };
+    f64::from_bits(next_bits)
+}
+
+/// the next representable `f64` towards negative infinity
+fn f64_next_down(value: f64) -> f64 {
+    -f64_next_up(-value)
+}
+
+impl DyadicFractionInterval {
+    /// an `inari::Interval` that is guaranteed to enclose `self`; since
+    /// `inari::Interval`'s endpoints are `f64`s, the result is generally
+    /// wider than `self`
+    pub fn to_inari_interval(&self) -> Interval {
+        Interval::with_infsup(
+            ratio_to_f64_rounding_down(&self.lower_bound()),
+            ratio_to_f64_rounding_up(&self.upper_bound()),
+        )
+        .expect("bounds are finite and ordered")
+    }
+    /// builds an interval enclosing `value` at `log2_denom`;
+    /// `inari::Interval`'s `f64` endpoints are always exact fractions, so
+    /// the bounds are exact when `log2_denom` is fine enough to hold them,
+    /// and are rounded outward otherwise
+    pub fn from_inari_interval(value: Interval, log2_denom: usize) -> Self {
+        Self::from_ratio_range(
+            Ratio::from_float(value.inf()).expect("finite"),
+            Ratio::from_float(value.sup()).expect("finite"),
+            log2_denom,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_bigint::BigInt;
+    use num_traits::One;
+
+    #[test]
+    fn test_to_inari_interval_is_conservative() {
+        let interval =
+            DyadicFractionInterval::from_ratio(Ratio::new(BigInt::one(), BigInt::from(3)), 32);
+        let inari_interval = interval.to_inari_interval();
+        assert!(Ratio::from_float(inari_interval.inf()).unwrap() <= interval.lower_bound());
+        assert!(Ratio::from_float(inari_interval.sup()).unwrap() >= interval.upper_bound());
+    }
+
+    #[test]
+    fn test_from_inari_interval_round_trip() {
+        let inari_interval = Interval::with_infsup(-1.5, 2.25).unwrap();
+        let interval = DyadicFractionInterval::from_inari_interval(inari_interval, 8);
+        assert_eq!(interval.lower_bound(), Ratio::new(BigInt::from(-3), BigInt::from(2)));
+        assert_eq!(interval.upper_bound(), Ratio::new(BigInt::from(9), BigInt::from(4)));
+    }
+}
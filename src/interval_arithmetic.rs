@@ -4,7 +4,7 @@
 use crate::{
     traits::{
         AlwaysExactDiv, AlwaysExactDivAssign, CeilLog2, ExactDiv, ExactDivAssign, FloorLog2,
-        IntervalUnion, IntervalUnionAssign,
+        IntervalUnion, IntervalUnionAssign, TrailingZeros,
     },
     util::DebugAsDisplay,
 };
@@ -14,8 +14,15 @@ use num_rational::Ratio;
 use num_traits::{FromPrimitive, One, Pow, Signed, ToPrimitive, Zero};
 use std::{
     borrow::Cow,
-    fmt, mem,
-    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    iter::{Product, Sum},
+    mem,
+    ops::{
+        Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Shl, ShlAssign, Shr, ShrAssign, Sub,
+        SubAssign,
+    },
     sync::{Arc, RwLock},
 };
 
@@ -39,6 +46,39 @@ fn convert_log2_denom_ceil(numer: &mut BigInt, old_log2_denom: usize, new_log2_d
     }
 }
 
+fn convert_log2_denom_nearest(numer: &mut BigInt, old_log2_denom: usize, new_log2_denom: usize) {
+    if new_log2_denom >= old_log2_denom {
+        *numer <<= new_log2_denom - old_log2_denom;
+    } else {
+        let shift = old_log2_denom - new_log2_denom;
+        let half = BigInt::one() << (shift - 1);
+        let mut numer_value = mem::take(numer);
+        // ties round away from zero
+        numer_value = if numer_value.is_negative() {
+            -((-numer_value + &half) >> shift)
+        } else {
+            (numer_value + &half) >> shift
+        };
+        *numer = numer_value;
+    }
+}
+
+/// controls how a lossy conversion picks a representable value when the
+/// exact one isn't representable
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// round down
+    Floor,
+    /// round up
+    Ceil,
+    /// round to the nearest representable value, ties away from zero
+    Nearest,
+    /// round the lower bound down and the upper bound up, so the result
+    /// always encloses the input; this is what the unparameterized
+    /// conversions on [`DyadicFractionInterval`] do
+    Outward,
+}
+
 struct ConstantCache {
     cache: RwLock<Arc<Vec<Arc<DyadicFractionInterval>>>>,
 }
@@ -120,8 +160,53 @@ impl ConstantCache {
     }
 }
 
+/// the sign of a [`DyadicFractionInterval`], as determined by [`DyadicFractionInterval::sign`]
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+pub enum IntervalSign {
+    /// every value in the interval is negative
+    NegativeDefinite,
+    /// every value in the interval is positive
+    PositiveDefinite,
+    /// the interval is the single point zero
+    ZeroDefinite,
+    /// the interval spans zero without being exactly zero
+    ContainsZero,
+}
+
+/// controls how the `*_with_policy` methods on [`DyadicFractionInterval`]
+/// pick the `log2_denom` to use for the result of an operation, so a
+/// chain of operations can keep its precision under control without the
+/// caller calling [`DyadicFractionInterval::convert_log2_denom`] by hand
+/// after every step
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PrecisionPolicy {
+    /// always use exactly this `log2_denom`
+    Fixed(usize),
+    /// use `extra_bits` more than the finer of the two operands'
+    /// `log2_denom`s, so precision grows a little with every operation
+    /// rather than being eroded by repeated outward rounding
+    GrowBy(usize),
+    /// use the finer of the two operands' `log2_denom`s, but never more
+    /// than `max_bits`, so a long chain of [`GrowBy`](Self::GrowBy)-like
+    /// growth can't make `log2_denom` unboundedly large
+    Budgeted { max_bits: usize },
+}
+
+impl PrecisionPolicy {
+    /// the `log2_denom` an operation between operands with the given
+    /// `log2_denom`s should produce its result at, under this policy
+    fn result_log2_denom(&self, lhs_log2_denom: usize, rhs_log2_denom: usize) -> usize {
+        let natural_log2_denom = lhs_log2_denom.max(rhs_log2_denom);
+        match *self {
+            PrecisionPolicy::Fixed(log2_denom) => log2_denom,
+            PrecisionPolicy::GrowBy(extra_bits) => natural_log2_denom + extra_bits,
+            PrecisionPolicy::Budgeted { max_bits } => natural_log2_denom.min(max_bits),
+        }
+    }
+}
+
 /// inclusive interval of the form `[a / 2^n, b / 2^n]` where `a` and `b` are integers and `n` is an unsigned integer.
-#[derive(Clone, Default, Hash)]
+#[derive(Clone, Default)]
 pub struct DyadicFractionInterval {
     lower_bound_numer: BigInt,
     upper_bound_numer: BigInt,
@@ -153,11 +238,32 @@ impl DyadicFractionInterval {
         }
     }
     pub fn from_ratio(ratio: Ratio<BigInt>, log2_denom: usize) -> Self {
+        Self::from_ratio_rounding(ratio, log2_denom, RoundingMode::Outward)
+    }
+    /// like [`Self::from_ratio`], but lets the caller pick how `ratio` is
+    /// rounded; `RoundingMode::Floor`/`Ceil`/`Nearest` produce a
+    /// single-point interval using only one rounding operation, instead
+    /// of `Outward`'s pair of floor-then-ceil calls, for callers that
+    /// only need a one-sided approximation of `ratio`
+    pub fn from_ratio_rounding(ratio: Ratio<BigInt>, log2_denom: usize, rounding: RoundingMode) -> Self {
         let (mut numer, denom) = ratio.into();
         numer <<= log2_denom;
         let ratio = Ratio::new(numer, denom);
-        let lower_bound_numer = ratio.floor().to_integer();
-        let upper_bound_numer = ratio.ceil().to_integer();
+        let (lower_bound_numer, upper_bound_numer) = match rounding {
+            RoundingMode::Outward => (ratio.floor().to_integer(), ratio.ceil().to_integer()),
+            RoundingMode::Floor => {
+                let value = ratio.floor().to_integer();
+                (value.clone(), value)
+            }
+            RoundingMode::Ceil => {
+                let value = ratio.ceil().to_integer();
+                (value.clone(), value)
+            }
+            RoundingMode::Nearest => {
+                let value = ratio.round().to_integer();
+                (value.clone(), value)
+            }
+        };
         Self {
             lower_bound_numer,
             upper_bound_numer,
@@ -190,6 +296,27 @@ impl DyadicFractionInterval {
     pub fn negative_one(log2_denom: usize) -> Self {
         Self::from_int(-BigInt::one(), log2_denom)
     }
+    /// the empty interval, i.e. the enclosure of no reals at all, e.g. the
+    /// result of intersecting two disjoint intervals; `+`, `-`, `/`,
+    /// [`Self::square`], [`Self::sqrt`] and [`Self::abs`] all propagate an
+    /// empty operand through to an empty result rather than treating the
+    /// underlying inverted bounds (`lower_bound_numer >
+    /// upper_bound_numer`) as meaningful. `*` doesn't check for emptiness,
+    /// since some internal reduction algorithms (e.g. [`Self::into_exp`])
+    /// briefly multiply through intentionally-inverted intermediate bounds
+    /// that aren't actually empty
+    pub fn empty(log2_denom: usize) -> Self {
+        Self {
+            lower_bound_numer: BigInt::one(),
+            upper_bound_numer: BigInt::zero(),
+            log2_denom,
+        }
+    }
+    /// `true` if `self` encloses no reals at all, i.e. `self`'s bounds are
+    /// inverted (`lower_bound_numer > upper_bound_numer`)
+    pub fn is_empty(&self) -> bool {
+        self.lower_bound_numer > self.upper_bound_numer
+    }
     pub fn set_zero(&mut self) {
         self.lower_bound_numer.set_zero();
         self.upper_bound_numer.set_zero();
@@ -242,6 +369,128 @@ impl DyadicFractionInterval {
     pub fn upper_bound_numer(&self) -> &BigInt {
         &self.upper_bound_numer
     }
+    /// `self.upper_bound() - self.lower_bound()`, computed exactly
+    pub fn width(&self) -> Ratio<BigInt> {
+        Ratio::new(
+            &self.upper_bound_numer - &self.lower_bound_numer,
+            BigInt::one() << self.log2_denom,
+        )
+    }
+    /// half of [`Self::width`]
+    pub fn radius(&self) -> Ratio<BigInt> {
+        Ratio::new(
+            &self.upper_bound_numer - &self.lower_bound_numer,
+            BigInt::one() << (self.log2_denom + 1),
+        )
+    }
+    /// the midpoint of the interval, computed exactly as a dyadic fraction
+    /// with `log2_denom` one greater than `self`'s
+    pub fn midpoint(&self) -> Self {
+        Self::from_dyadic_fraction(
+            &self.lower_bound_numer + &self.upper_bound_numer,
+            self.log2_denom + 1,
+        )
+    }
+    /// splits `self` into two halves at [`Self::midpoint`], each with
+    /// `log2_denom` one greater than `self`'s; the halves share their
+    /// midpoint bound rather than leaving a gap or overlap there
+    pub fn bisect(&self) -> (Self, Self) {
+        let log2_denom = self.log2_denom + 1;
+        let midpoint_numer = &self.lower_bound_numer + &self.upper_bound_numer;
+        let lower_numer = &self.lower_bound_numer << 1;
+        let upper_numer = &self.upper_bound_numer << 1;
+        (
+            Self::new(lower_numer, midpoint_numer.clone(), log2_denom),
+            Self::new(midpoint_numer, upper_numer, log2_denom),
+        )
+    }
+    /// splits `self` into two pieces at `split_point`, for isolation
+    /// loops that want an uneven split instead of always bisecting at the
+    /// midpoint; `split_point` is rounded outward to `self`'s own
+    /// `log2_denom`, so the two returned pieces always cover all of
+    /// `self` (they may overlap by up to one unit in the last place at
+    /// the seam, but never leave a gap)
+    pub fn split_at(&self, split_point: &Ratio<BigInt>) -> (Self, Self) {
+        let scaled = split_point * (BigInt::one() << self.log2_denom);
+        let lower_split_numer = scaled.floor().to_integer();
+        let upper_split_numer = scaled.ceil().to_integer();
+        (
+            Self::new(
+                self.lower_bound_numer.clone(),
+                upper_split_numer,
+                self.log2_denom,
+            ),
+            Self::new(
+                lower_split_numer,
+                self.upper_bound_numer.clone(),
+                self.log2_denom,
+            ),
+        )
+    }
+    /// splits `self` into a dyadic midpoint, rounded to `log2_denom`, and
+    /// a radius bound that still encloses `self` around that rounded
+    /// midpoint; this is the standard hand-off format expected by
+    /// ball-arithmetic and plotting code, which represent an enclosure as
+    /// `center \u{b1} error` rather than `[lower, upper]`
+    pub fn to_midpoint_and_error(&self, log2_denom: usize) -> (Ratio<BigInt>, Ratio<BigInt>) {
+        let mut midpoint_numer = &self.lower_bound_numer + &self.upper_bound_numer;
+        convert_log2_denom_nearest(&mut midpoint_numer, self.log2_denom + 1, log2_denom);
+        let midpoint = Ratio::new(midpoint_numer, BigInt::one() << log2_denom);
+        let lower_error = (&midpoint - self.lower_bound()).abs();
+        let upper_error = (self.upper_bound() - &midpoint).abs();
+        let error = lower_error.max(upper_error);
+        (midpoint, error)
+    }
+    /// `true` if this interval's width is at most `2.pow(-bits)`, i.e. it
+    /// pins down at least `bits` bits of precision
+    pub fn is_tighter_than(&self, bits: usize) -> bool {
+        self.width() <= Ratio::new(BigInt::one(), BigInt::one() << bits)
+    }
+    /// the largest `k` such that `self`'s radius is at most `2.pow(-k)`,
+    /// or `None` if the radius is zero or greater than `1` and can't be
+    /// expressed that way; used by [`Self::to_decimal_string`] and the precision-aware
+    /// `Display` impl
+    fn error_exponent(&self) -> Option<usize> {
+        let radius = self.radius();
+        if radius.is_zero() {
+            return None;
+        }
+        let denom_log2 = radius
+            .denom()
+            .floor_log2()
+            .expect("radius's denominator is a positive power of two");
+        let numer_log2 = radius
+            .numer()
+            .ceil_log2()
+            .expect("radius's numerator is positive");
+        if numer_log2 > denom_log2 {
+            None
+        } else {
+            Some(denom_log2 - numer_log2)
+        }
+    }
+    /// formats `self` as a decimal enclosure with `digits` digits after
+    /// the decimal point, e.g. `1.41421356237309514547 \u{b1} 2^-64`
+    pub fn to_decimal_string(&self, digits: usize) -> String {
+        format!("{:.*}", digits, self)
+    }
+    /// a definite ordering between `self` and `rhs` if they don't overlap
+    /// (or are both the same single point), `None` otherwise
+    pub fn interval_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        let self_lower = self.lower_bound();
+        let self_upper = self.upper_bound();
+        let rhs_lower = rhs.lower_bound();
+        let rhs_upper = rhs.upper_bound();
+        if self_upper < rhs_lower {
+            Some(Ordering::Less)
+        } else if rhs_upper < self_lower {
+            Some(Ordering::Greater)
+        } else if self_lower == self_upper && self_lower == rhs_lower && rhs_lower == rhs_upper {
+            Some(Ordering::Equal)
+        } else {
+            None
+        }
+    }
     /// convert to a tuple `(self.lower_bound_numer, self.upper_bound_numer, self.log2_denom)`
     pub fn destructure(self) -> (BigInt, BigInt, usize) {
         (
@@ -273,17 +522,119 @@ impl DyadicFractionInterval {
         self.upper_bound_numer.set_zero();
     }
     pub fn convert_log2_denom(&mut self, log2_denom: usize) {
-        convert_log2_denom_floor(&mut self.lower_bound_numer, self.log2_denom, log2_denom);
-        convert_log2_denom_ceil(&mut self.upper_bound_numer, self.log2_denom, log2_denom);
-        self.log2_denom = log2_denom;
+        self.convert_log2_denom_rounding(log2_denom, RoundingMode::Outward);
     }
     pub fn into_converted_log2_denom(mut self, log2_denom: usize) -> Self {
         self.convert_log2_denom(log2_denom);
         self
     }
+    /// outward-rounds `self` to a `log2_denom` lowered by the number of
+    /// bits by which the wider numerator exceeds `max_numer_bits` (but
+    /// never below zero); rounding up can carry a numerator one bit past
+    /// the budget, and at `log2_denom` zero numerators still wider than
+    /// the budget are kept as they are; long chains of
+    /// computation otherwise leave the numerators growing without bound,
+    /// slowing down every later operation with precision far beyond what
+    /// anyone asked for
+    pub fn compress_to_bits(&self, max_numer_bits: usize) -> Self {
+        let numer_bits = self.lower_bound_numer.bits().max(self.upper_bound_numer.bits());
+        let max_numer_bits = max_numer_bits as u64;
+        if numer_bits <= max_numer_bits {
+            return self.clone();
+        }
+        let shift = (numer_bits - max_numer_bits) as usize;
+        let log2_denom = self.log2_denom.saturating_sub(shift);
+        self.to_converted_log2_denom(log2_denom)
+    }
+    /// like [`Self::convert_log2_denom`], but lets the caller pick how
+    /// each bound rounds instead of always rounding outward; useful when
+    /// only one of the two bounds is needed afterward, since e.g.
+    /// `RoundingMode::Floor` only ever rounds down, avoiding the wasted
+    /// work of also computing the other bound's ceiling
+    pub fn convert_log2_denom_rounding(&mut self, log2_denom: usize, rounding: RoundingMode) {
+        if self.is_empty() {
+            // an empty interval has no scale to convert; only the
+            // reported `log2_denom` needs to track the request
+            self.log2_denom = log2_denom;
+            return;
+        }
+        let (lower_rounding, upper_rounding) = match rounding {
+            RoundingMode::Outward => (RoundingMode::Floor, RoundingMode::Ceil),
+            rounding => (rounding, rounding),
+        };
+        for (numer, rounding) in [
+            (&mut self.lower_bound_numer, lower_rounding),
+            (&mut self.upper_bound_numer, upper_rounding),
+        ] {
+            match rounding {
+                RoundingMode::Floor => {
+                    convert_log2_denom_floor(numer, self.log2_denom, log2_denom)
+                }
+                RoundingMode::Ceil => convert_log2_denom_ceil(numer, self.log2_denom, log2_denom),
+                RoundingMode::Nearest => {
+                    convert_log2_denom_nearest(numer, self.log2_denom, log2_denom)
+                }
+                RoundingMode::Outward => unreachable!("Outward is resolved above"),
+            }
+        }
+        self.log2_denom = log2_denom;
+    }
+    pub fn into_converted_log2_denom_rounding(mut self, log2_denom: usize, rounding: RoundingMode) -> Self {
+        self.convert_log2_denom_rounding(log2_denom, rounding);
+        self
+    }
+    pub fn to_converted_log2_denom_rounding(&self, log2_denom: usize, rounding: RoundingMode) -> Self {
+        self.clone()
+            .into_converted_log2_denom_rounding(log2_denom, rounding)
+    }
     pub fn to_converted_log2_denom(&self, log2_denom: usize) -> Self {
         self.clone().into_converted_log2_denom(log2_denom)
     }
+    /// strips any power-of-two factor common to both numerators and
+    /// reduces `log2_denom` accordingly, so e.g. `[2 / 2^2, 4 / 2^2]`
+    /// becomes `[1 / 2^1, 2 / 2^1]`; long chains of operations can
+    /// accumulate numerators far bigger than the value's actual
+    /// precision needs, so it's worth calling this periodically to keep
+    /// them from growing without bound
+    pub fn normalize(&mut self) {
+        let (lower_bound_numer, upper_bound_numer, log2_denom) = self.normalized_form();
+        self.lower_bound_numer = lower_bound_numer;
+        self.upper_bound_numer = upper_bound_numer;
+        self.log2_denom = log2_denom;
+    }
+    pub fn into_normalized(mut self) -> Self {
+        self.normalize();
+        self
+    }
+    pub fn normalized(&self) -> Self {
+        self.clone().into_normalized()
+    }
+    /// `self + rhs`, with the result's `log2_denom` chosen by `policy`
+    /// instead of just using the finer of `self` and `rhs`'s `log2_denom`s
+    pub fn add_with_policy(&self, rhs: &Self, policy: &PrecisionPolicy) -> Self {
+        let log2_denom = policy.result_log2_denom(self.log2_denom, rhs.log2_denom);
+        self.to_converted_log2_denom(log2_denom) + rhs.to_converted_log2_denom(log2_denom)
+    }
+    /// `self - rhs`, with the result's `log2_denom` chosen by `policy`
+    /// instead of just using the finer of `self` and `rhs`'s `log2_denom`s
+    pub fn sub_with_policy(&self, rhs: &Self, policy: &PrecisionPolicy) -> Self {
+        let log2_denom = policy.result_log2_denom(self.log2_denom, rhs.log2_denom);
+        self.to_converted_log2_denom(log2_denom) - rhs.to_converted_log2_denom(log2_denom)
+    }
+    /// `self * rhs`, with the result's `log2_denom` chosen by `policy`
+    /// instead of just using the finer of `self` and `rhs`'s `log2_denom`s
+    pub fn mul_with_policy(&self, rhs: &Self, policy: &PrecisionPolicy) -> Self {
+        let log2_denom = policy.result_log2_denom(self.log2_denom, rhs.log2_denom);
+        self.to_converted_log2_denom(log2_denom) * rhs.to_converted_log2_denom(log2_denom)
+    }
+    /// `self / rhs`, with the result's `log2_denom` chosen by `policy`
+    /// instead of just using the finer of `self` and `rhs`'s `log2_denom`s;
+    /// see [`Self::checked_div`] for when this returns `None`
+    pub fn checked_div_with_policy(&self, rhs: &Self, policy: &PrecisionPolicy) -> Option<Self> {
+        let log2_denom = policy.result_log2_denom(self.log2_denom, rhs.log2_denom);
+        self.to_converted_log2_denom(log2_denom)
+            .checked_div(&rhs.to_converted_log2_denom(log2_denom))
+    }
     fn do_op_assign<Op: Fn(&mut BigInt, &mut BigInt, &BigInt, &BigInt, usize) -> R, R>(
         &mut self,
         rhs: Cow<DyadicFractionInterval>,
@@ -325,6 +676,13 @@ impl DyadicFractionInterval {
         }
     }
     fn do_add_assign(&mut self, rhs: Cow<DyadicFractionInterval>) {
+        if self.is_empty() {
+            return;
+        }
+        if rhs.is_empty() {
+            *self = Self::empty(self.log2_denom.max(rhs.log2_denom));
+            return;
+        }
         self.do_op_assign(
             rhs,
             |lhs_lower_bound_numer,
@@ -338,6 +696,13 @@ impl DyadicFractionInterval {
         );
     }
     fn do_sub_assign(&mut self, rhs: Cow<DyadicFractionInterval>) {
+        if self.is_empty() {
+            return;
+        }
+        if rhs.is_empty() {
+            *self = Self::empty(self.log2_denom.max(rhs.log2_denom));
+            return;
+        }
         self.do_op_assign(
             rhs,
             |lhs_lower_bound_numer,
@@ -351,7 +716,31 @@ impl DyadicFractionInterval {
             },
         );
     }
+    /// adds the exact rational `rhs` to `self`, rounding outward only once
+    /// (rather than rounding `rhs` to an interval first and adding that,
+    /// which can needlessly double the added width)
+    fn do_add_assign_ratio(&mut self, rhs: &Ratio<BigInt>) {
+        if self.is_empty() {
+            return;
+        }
+        let scaled_numer = rhs.numer().clone() << self.log2_denom;
+        self.lower_bound_numer += scaled_numer.div_floor(rhs.denom());
+        self.upper_bound_numer += scaled_numer.div_ceil(rhs.denom());
+    }
+    /// subtracts the exact rational `rhs` from `self`, rounding outward
+    /// only once, mirroring [`Self::do_add_assign_ratio`]
+    fn do_sub_assign_ratio(&mut self, rhs: &Ratio<BigInt>) {
+        if self.is_empty() {
+            return;
+        }
+        let scaled_numer = rhs.numer().clone() << self.log2_denom;
+        self.lower_bound_numer -= scaled_numer.div_ceil(rhs.denom());
+        self.upper_bound_numer -= scaled_numer.div_floor(rhs.denom());
+    }
     fn do_mul_assign_int(&mut self, rhs: &BigInt) {
+        if self.is_empty() {
+            return;
+        }
         if rhs.is_negative() {
             mem::swap(&mut self.lower_bound_numer, &mut self.upper_bound_numer);
         }
@@ -359,12 +748,60 @@ impl DyadicFractionInterval {
         self.upper_bound_numer.mul_assign(rhs);
     }
     fn do_mul_assign_ratio(&mut self, rhs: &Ratio<BigInt>) {
+        if self.is_empty() {
+            return;
+        }
         if rhs.is_negative() {
             mem::swap(&mut self.lower_bound_numer, &mut self.upper_bound_numer);
         }
         self.lower_bound_numer = (rhs * &self.lower_bound_numer).floor().to_integer();
         self.upper_bound_numer = (rhs * &self.upper_bound_numer).ceil().to_integer();
     }
+    /// the product's exact `(min, max)` corner bounds, trying all four
+    /// pairings of bounds; used when the operands' signs aren't both
+    /// known (including the case of inverted, i.e. not [`Self::is_empty`]
+    /// aware, bounds), since then no sign-based shortcut applies
+    fn mul_corner_bounds(
+        lhs_lower_bound_numer: &BigInt,
+        lhs_upper_bound_numer: &BigInt,
+        rhs_lower_bound_numer: &BigInt,
+        rhs_upper_bound_numer: &BigInt,
+    ) -> (BigInt, BigInt) {
+        let mut bounds = [
+            Some(lhs_lower_bound_numer * rhs_lower_bound_numer),
+            Some(lhs_lower_bound_numer * rhs_upper_bound_numer),
+            Some(lhs_upper_bound_numer * rhs_lower_bound_numer),
+            Some(lhs_upper_bound_numer * rhs_upper_bound_numer),
+        ];
+        let mut lower_bound = None;
+        for bound in &mut bounds {
+            match (&mut lower_bound, bound) {
+                (_, None) => {}
+                (None, bound) => lower_bound = bound.take(),
+                (Some(lower_bound), Some(bound)) => {
+                    if *bound < *lower_bound {
+                        mem::swap(lower_bound, bound)
+                    }
+                }
+            }
+        }
+        let mut upper_bound = None;
+        for bound in &mut bounds {
+            match (&mut upper_bound, bound) {
+                (_, None) => {}
+                (None, bound) => upper_bound = bound.take(),
+                (Some(upper_bound), Some(bound)) => {
+                    if *bound > *upper_bound {
+                        mem::swap(upper_bound, bound)
+                    }
+                }
+            }
+        }
+        (
+            lower_bound.expect("known to exist"),
+            upper_bound.expect("known to exist"),
+        )
+    }
     fn do_mul_assign(&mut self, rhs: Cow<DyadicFractionInterval>) {
         self.do_op_assign(
             rhs,
@@ -373,42 +810,92 @@ impl DyadicFractionInterval {
              rhs_lower_bound_numer,
              rhs_upper_bound_numer,
              log2_denom| {
-                let mut bounds = [
-                    Some(&*lhs_lower_bound_numer * rhs_lower_bound_numer),
-                    Some(&*lhs_lower_bound_numer * rhs_upper_bound_numer),
-                    Some(&*lhs_upper_bound_numer * rhs_lower_bound_numer),
-                    Some(&*lhs_upper_bound_numer * rhs_upper_bound_numer),
-                ];
-                let mut lower_bound = None;
-                for bound in &mut bounds {
-                    match (&mut lower_bound, bound) {
-                        (_, None) => {}
-                        (None, bound) => lower_bound = bound.take(),
-                        (Some(lower_bound), Some(bound)) => {
-                            if *bound < *lower_bound {
-                                mem::swap(lower_bound, bound)
-                            }
-                        }
+                let lhs_valid = *lhs_lower_bound_numer <= *lhs_upper_bound_numer;
+                let rhs_valid = *rhs_lower_bound_numer <= *rhs_upper_bound_numer;
+                let lhs_nonneg = !lhs_lower_bound_numer.is_negative();
+                let lhs_nonpos = !lhs_upper_bound_numer.is_positive();
+                let rhs_nonneg = !rhs_lower_bound_numer.is_negative();
+                let rhs_nonpos = !rhs_upper_bound_numer.is_positive();
+                let lhs_mixed = !lhs_nonneg && !lhs_nonpos;
+                let rhs_mixed = !rhs_nonneg && !rhs_nonpos;
+                // sign-based fast path: whenever both operands' signs are
+                // known, the product's extremes are always two of the
+                // corners rather than requiring all four, since the
+                // other two corners can't be more extreme; this only
+                // applies when both operands have valid (non-inverted)
+                // bounds, so the exhaustive fallback below still handles
+                // reduction algorithms (e.g. `into_exp`) that briefly
+                // multiply through intentionally-inverted bounds
+                let (lower_bound, upper_bound) = if lhs_valid && rhs_valid && !lhs_mixed && !rhs_mixed
+                {
+                    if lhs_nonneg && rhs_nonneg {
+                        (
+                            &*lhs_lower_bound_numer * rhs_lower_bound_numer,
+                            &*lhs_upper_bound_numer * rhs_upper_bound_numer,
+                        )
+                    } else if lhs_nonneg {
+                        (
+                            &*lhs_upper_bound_numer * rhs_lower_bound_numer,
+                            &*lhs_lower_bound_numer * rhs_upper_bound_numer,
+                        )
+                    } else if rhs_nonneg {
+                        (
+                            &*lhs_lower_bound_numer * rhs_upper_bound_numer,
+                            &*lhs_upper_bound_numer * rhs_lower_bound_numer,
+                        )
+                    } else {
+                        (
+                            &*lhs_upper_bound_numer * rhs_upper_bound_numer,
+                            &*lhs_lower_bound_numer * rhs_lower_bound_numer,
+                        )
                     }
-                }
-                let mut upper_bound = None;
-                for bound in &mut bounds {
-                    match (&mut upper_bound, bound) {
-                        (_, None) => {}
-                        (None, bound) => upper_bound = bound.take(),
-                        (Some(upper_bound), Some(bound)) => {
-                            if *bound > *upper_bound {
-                                mem::swap(upper_bound, bound)
-                            }
-                        }
+                } else if lhs_valid && rhs_valid && !lhs_mixed {
+                    // rhs straddles zero, lhs's sign is known
+                    if lhs_nonneg {
+                        (
+                            &*lhs_upper_bound_numer * rhs_lower_bound_numer,
+                            &*lhs_upper_bound_numer * rhs_upper_bound_numer,
+                        )
+                    } else {
+                        (
+                            &*lhs_lower_bound_numer * rhs_upper_bound_numer,
+                            &*lhs_lower_bound_numer * rhs_lower_bound_numer,
+                        )
                     }
-                }
-                *lhs_lower_bound_numer = lower_bound.expect("known to exist") >> log2_denom;
-                *lhs_upper_bound_numer = -(-upper_bound.expect("known to exist") >> log2_denom);
+                } else if lhs_valid && rhs_valid && !rhs_mixed {
+                    // lhs straddles zero, rhs's sign is known
+                    if rhs_nonneg {
+                        (
+                            &*lhs_lower_bound_numer * rhs_upper_bound_numer,
+                            &*lhs_upper_bound_numer * rhs_upper_bound_numer,
+                        )
+                    } else {
+                        (
+                            &*lhs_upper_bound_numer * rhs_lower_bound_numer,
+                            &*lhs_lower_bound_numer * rhs_lower_bound_numer,
+                        )
+                    }
+                } else {
+                    Self::mul_corner_bounds(
+                        lhs_lower_bound_numer,
+                        lhs_upper_bound_numer,
+                        rhs_lower_bound_numer,
+                        rhs_upper_bound_numer,
+                    )
+                };
+                *lhs_lower_bound_numer = lower_bound >> log2_denom;
+                *lhs_upper_bound_numer = -(-upper_bound >> log2_denom);
             },
         );
     }
     fn do_checked_div_assign(&mut self, rhs: Cow<DyadicFractionInterval>) -> Result<(), ()> {
+        if self.is_empty() {
+            return Ok(());
+        }
+        if rhs.is_empty() {
+            *self = Self::empty(self.log2_denom.max(rhs.log2_denom));
+            return Ok(());
+        }
         if let Some(recip) = rhs.checked_recip() {
             *self *= recip;
             Ok(())
@@ -417,9 +904,19 @@ impl DyadicFractionInterval {
         }
     }
     fn do_div_assign(&mut self, rhs: Cow<DyadicFractionInterval>) {
+        if self.is_empty() {
+            return;
+        }
+        if rhs.is_empty() {
+            *self = Self::empty(self.log2_denom.max(rhs.log2_denom));
+            return;
+        }
         *self *= rhs.recip();
     }
     pub fn checked_recip(&self) -> Option<Self> {
+        if self.is_empty() {
+            return Some(Self::empty(self.log2_denom));
+        }
         if self.contains_zero() {
             None
         } else {
@@ -436,7 +933,15 @@ impl DyadicFractionInterval {
     pub fn recip(&self) -> Self {
         self.checked_recip().expect("division by zero")
     }
+    /// like `self / rhs`, but returns `None` instead of panicking when
+    /// `rhs` contains zero
+    pub fn checked_div(&self, rhs: &Self) -> Option<Self> {
+        self.clone().checked_exact_div(rhs.clone())
+    }
     pub fn into_square(mut self) -> Self {
+        if self.is_empty() {
+            return self;
+        }
         let contains_zero = self.contains_zero();
         let lower_bound_numer_is_negative = self.lower_bound_numer.is_negative();
         let upper_bound_numer_is_negative = self.upper_bound_numer.is_negative();
@@ -468,6 +973,9 @@ impl DyadicFractionInterval {
         self.clone().into_square()
     }
     fn do_sqrt(radicand: Cow<Self>) -> Self {
+        if radicand.is_empty() {
+            return Self::empty(radicand.log2_denom);
+        }
         let log2_denom = radicand.log2_denom;
         let (scaled_lower_bound_numer, scaled_upper_bound_numer) = match radicand {
             Cow::Borrowed(radicand) => (
@@ -501,10 +1009,133 @@ impl DyadicFractionInterval {
     pub fn sqrt(&self) -> Self {
         Self::do_sqrt(Cow::Borrowed(self))
     }
+    /// a tight enclosure of `sqrt(self.pow(2) + other.pow(2))`; squaring
+    /// each operand first (rather than e.g. converting both to a shared,
+    /// artificially finer `log2_denom` before squaring) keeps the
+    /// intermediate `log2_denom`s the same size as the inputs'
+    pub fn hypot(&self, other: &Self) -> Self {
+        (self.square() + other.square()).sqrt()
+    }
     pub fn contains_zero(&self) -> bool {
         !self.lower_bound_numer.is_positive() && !self.upper_bound_numer.is_negative()
     }
+    pub fn sign(&self) -> IntervalSign {
+        if self.lower_bound_numer.is_positive() {
+            IntervalSign::PositiveDefinite
+        } else if self.upper_bound_numer.is_negative() {
+            IntervalSign::NegativeDefinite
+        } else if self.lower_bound_numer.is_zero() && self.upper_bound_numer.is_zero() {
+            IntervalSign::ZeroDefinite
+        } else {
+            IntervalSign::ContainsZero
+        }
+    }
+    pub fn is_positive_definite(&self) -> bool {
+        self.sign() == IntervalSign::PositiveDefinite
+    }
+    pub fn is_negative_definite(&self) -> bool {
+        self.sign() == IntervalSign::NegativeDefinite
+    }
+    /// the floor of the interval's value if every point in the interval
+    /// has the same floor, otherwise the integer interval enclosing the
+    /// possible floors
+    pub fn floor_int(&self) -> Result<BigInt, Self> {
+        let lower = self.lower_bound().floor().to_integer();
+        let upper = self.upper_bound().floor().to_integer();
+        if lower == upper {
+            Ok(lower)
+        } else {
+            Err(Self::new(lower, upper, 0))
+        }
+    }
+    /// like [`Self::floor_int`] but rounding up instead of down
+    pub fn ceil_int(&self) -> Result<BigInt, Self> {
+        let lower = self.lower_bound().ceil().to_integer();
+        let upper = self.upper_bound().ceil().to_integer();
+        if lower == upper {
+            Ok(lower)
+        } else {
+            Err(Self::new(lower, upper, 0))
+        }
+    }
+    /// like [`Self::floor_int`] but rounding towards zero instead of down
+    pub fn trunc_int(&self) -> Result<BigInt, Self> {
+        let lower = self.lower_bound().trunc().to_integer();
+        let upper = self.upper_bound().trunc().to_integer();
+        if lower == upper {
+            Ok(lower)
+        } else {
+            Err(Self::new(lower, upper, 0))
+        }
+    }
+    /// like [`Self::floor_int`] but rounding to the nearest integer,
+    /// rounding half-way cases away from zero
+    pub fn round_int(&self) -> Result<BigInt, Self> {
+        let lower = self.lower_bound().round().to_integer();
+        let upper = self.upper_bound().round().to_integer();
+        if lower == upper {
+            Ok(lower)
+        } else {
+            Err(Self::new(lower, upper, 0))
+        }
+    }
+    pub fn contains_ratio(&self, value: &Ratio<BigInt>) -> bool {
+        self.lower_bound() <= *value && *value <= self.upper_bound()
+    }
+    pub fn contains_int(&self, value: &BigInt) -> bool {
+        self.contains_ratio(&Ratio::from_integer(value.clone()))
+    }
+    pub fn contains_interval(&self, rhs: &Self) -> bool {
+        if rhs.is_empty() {
+            return true;
+        }
+        if self.is_empty() {
+            return false;
+        }
+        self.lower_bound() <= rhs.lower_bound() && rhs.upper_bound() <= self.upper_bound()
+    }
+    pub fn is_subset_of(&self, rhs: &Self) -> bool {
+        rhs.contains_interval(self)
+    }
     fn do_interval_union_assign(&mut self, rhs: Cow<Self>) {
+        if self.is_empty() {
+            *self = rhs.into_owned();
+            return;
+        }
+        if rhs.is_empty() {
+            return;
+        }
+        self.do_op_assign(
+            rhs,
+            |lhs_lower_bound_numer,
+             lhs_upper_bound_numer,
+             rhs_lower_bound_numer,
+             rhs_upper_bound_numer,
+             _log2_denom| {
+                if *lhs_lower_bound_numer > *rhs_lower_bound_numer {
+                    lhs_lower_bound_numer.clone_from(rhs_lower_bound_numer);
+                }
+                if *lhs_upper_bound_numer < *rhs_upper_bound_numer {
+                    lhs_upper_bound_numer.clone_from(rhs_upper_bound_numer);
+                }
+            },
+        );
+    }
+    /// the convex hull of `self` and `rhs`, i.e. the smallest interval
+    /// containing both; equivalent to [`IntervalUnion::interval_union`]
+    /// under a more descriptive name for callers that don't already import
+    /// that trait
+    pub fn convex_hull(&self, rhs: &Self) -> Self {
+        self.interval_union(rhs)
+    }
+    fn do_interval_min_assign(&mut self, rhs: Cow<Self>) {
+        if self.is_empty() {
+            return;
+        }
+        if rhs.is_empty() {
+            *self = Self::empty(self.log2_denom.max(rhs.log2_denom));
+            return;
+        }
         self.do_op_assign(
             rhs,
             |lhs_lower_bound_numer,
@@ -515,12 +1146,99 @@ impl DyadicFractionInterval {
                 if *lhs_lower_bound_numer > *rhs_lower_bound_numer {
                     lhs_lower_bound_numer.clone_from(rhs_lower_bound_numer);
                 }
+                if *lhs_upper_bound_numer > *rhs_upper_bound_numer {
+                    lhs_upper_bound_numer.clone_from(rhs_upper_bound_numer);
+                }
+            },
+        );
+    }
+    pub fn interval_min_assign(&mut self, rhs: &Self) {
+        self.do_interval_min_assign(Cow::Borrowed(rhs));
+    }
+    pub fn into_interval_min(mut self, rhs: &Self) -> Self {
+        self.interval_min_assign(rhs);
+        self
+    }
+    /// the tightest enclosure of the pointwise minimum of `self` and
+    /// `rhs`, i.e. of `{ x.min(y) : x \u{2208} self, y \u{2208} rhs }`;
+    /// empty if either operand is
+    pub fn interval_min(&self, rhs: &Self) -> Self {
+        self.clone().into_interval_min(rhs)
+    }
+    fn do_interval_max_assign(&mut self, rhs: Cow<Self>) {
+        if self.is_empty() {
+            return;
+        }
+        if rhs.is_empty() {
+            *self = Self::empty(self.log2_denom.max(rhs.log2_denom));
+            return;
+        }
+        self.do_op_assign(
+            rhs,
+            |lhs_lower_bound_numer,
+             lhs_upper_bound_numer,
+             rhs_lower_bound_numer,
+             rhs_upper_bound_numer,
+             _log2_denom| {
+                if *lhs_lower_bound_numer < *rhs_lower_bound_numer {
+                    lhs_lower_bound_numer.clone_from(rhs_lower_bound_numer);
+                }
                 if *lhs_upper_bound_numer < *rhs_upper_bound_numer {
                     lhs_upper_bound_numer.clone_from(rhs_upper_bound_numer);
                 }
             },
         );
     }
+    pub fn interval_max_assign(&mut self, rhs: &Self) {
+        self.do_interval_max_assign(Cow::Borrowed(rhs));
+    }
+    pub fn into_interval_max(mut self, rhs: &Self) -> Self {
+        self.interval_max_assign(rhs);
+        self
+    }
+    /// the tightest enclosure of the pointwise maximum of `self` and
+    /// `rhs`, i.e. of `{ x.max(y) : x \u{2208} self, y \u{2208} rhs }`;
+    /// empty if either operand is
+    pub fn interval_max(&self, rhs: &Self) -> Self {
+        self.clone().into_interval_max(rhs)
+    }
+    /// the tightest enclosure of clamping `self` into `[min, max]`, i.e.
+    /// of `{ x.max(lo).min(hi) : x \u{2208} self, lo \u{2208} min, hi
+    /// \u{2208} max }`
+    pub fn clamp(&self, min: &Self, max: &Self) -> Self {
+        self.interval_max(min).into_interval_min(max)
+    }
+    /// sets `self` to the intersection of `self` and `rhs`, returning
+    /// `true` if the intersection is non-empty; if the intersection is
+    /// empty, `self` is left containing the (also empty, i.e.
+    /// `lower_bound_numer > upper_bound_numer`) overlap of the two ranges
+    fn do_checked_intersection_assign(&mut self, rhs: Cow<Self>) -> bool {
+        self.do_op_assign(
+            rhs,
+            |lhs_lower_bound_numer,
+             lhs_upper_bound_numer,
+             rhs_lower_bound_numer,
+             rhs_upper_bound_numer,
+             _log2_denom| {
+                if *lhs_lower_bound_numer < *rhs_lower_bound_numer {
+                    lhs_lower_bound_numer.clone_from(rhs_lower_bound_numer);
+                }
+                if *lhs_upper_bound_numer > *rhs_upper_bound_numer {
+                    lhs_upper_bound_numer.clone_from(rhs_upper_bound_numer);
+                }
+                *lhs_lower_bound_numer <= *lhs_upper_bound_numer
+            },
+        )
+    }
+    /// the intersection of `self` and `rhs`, or `None` if they don't overlap
+    pub fn intersection(&self, rhs: &Self) -> Option<Self> {
+        let mut retval = self.clone();
+        if retval.do_checked_intersection_assign(Cow::Borrowed(rhs)) {
+            Some(retval)
+        } else {
+            None
+        }
+    }
     pub fn into_arithmetic_geometric_mean(self, rhs: Self) -> Self {
         assert!(!self.lower_bound_numer.is_negative());
         assert!(!rhs.lower_bound_numer.is_negative());
@@ -552,17 +1270,25 @@ impl DyadicFractionInterval {
             Self::from_int(2i32.into(), log2_denom).into_sqrt()
         })
     }
-    #[allow(dead_code)] // FIXME: remove when implemented
-    pub(crate) fn pi(log2_denom: usize) -> Self {
+    /// pi computed via Machin's formula `pi = 16 * atan(1/5) - 4 * atan(1/239)`,
+    /// with each `atan` evaluated by [`Self::atan_core`]'s Taylor series
+    pub fn pi(log2_denom: usize) -> Self {
         lazy_static! {
             static ref CACHE: ConstantCache = ConstantCache::new();
         }
         let compute = |log2_denom: usize| -> Self {
-            let log2_denom = log2_denom + 32 + log2_denom / 1000;
-            let _ = log2_denom;
-            unimplemented!(
-                "finish implementing algorithm to compute pi using arithmetic_geometric_mean"
+            let working_log2_denom = log2_denom + 32 + log2_denom / 1000;
+            let one_fifth =
+                Self::from_ratio(Ratio::new(BigInt::one(), BigInt::from(5)), working_log2_denom);
+            let one_over_239 = Self::from_ratio(
+                Ratio::new(BigInt::one(), BigInt::from(239)),
+                working_log2_denom,
             );
+            let mut retval = Self::atan_core(&one_fifth, working_log2_denom) * 16i32
+                - Self::atan_core(&one_over_239, working_log2_denom) * 4i32;
+            retval.upper_bound_numer += 1;
+            retval.lower_bound_numer -= 1;
+            retval.into_converted_log2_denom(log2_denom)
         };
         CACHE.get(log2_denom, compute)
     }
@@ -682,6 +1408,10 @@ impl DyadicFractionInterval {
         );
         retval.into_converted_log2_denom(self.log2_denom)
     }
+    /// alias for [`Self::log`], for callers expecting the more conventional name
+    pub fn ln(&self) -> Self {
+        self.log()
+    }
     pub fn into_exp(mut self) -> Self {
         let original_log2_denom = self.log2_denom;
         self.convert_log2_denom(original_log2_denom + 10);
@@ -760,7 +1490,107 @@ impl DyadicFractionInterval {
     pub fn exp(&self) -> Self {
         self.clone().into_exp()
     }
-    /// use instead of .eq() since .eq() wouldn't have well defined results in all cases
+    /// computes `atan(x)` at `working_log2_denom`, reducing the argument
+    /// with the tangent half-angle identity `atan(x) == 2 * atan(x / (1 +
+    /// sqrt(1 + x^2)))` until `|x| <= 1/2`, then summing the alternating
+    /// Taylor series `atan(x) == x - x^3/3 + x^5/5 - ...`, which converges
+    /// quickly once `x` is that small
+    fn atan_core(x: &Self, working_log2_denom: usize) -> Self {
+        let mut x = x.to_converted_log2_denom(working_log2_denom);
+        let half = Ratio::new(BigInt::one(), BigInt::from(2));
+        let mut halvings = 0usize;
+        while x.abs().upper_bound() > half {
+            let denom =
+                Self::one(working_log2_denom) + (Self::one(working_log2_denom) + x.square()).sqrt();
+            x = x.checked_div(&denom).expect("denominator is always at least 1");
+            halvings += 1;
+        }
+        let x_squared = x.square();
+        let mut power = x;
+        let mut retval = Self::zero(working_log2_denom);
+        for i in 0..working_log2_denom {
+            let term = &power / (2 * i + 1);
+            if i % 2 == 1 {
+                retval -= &term;
+            } else {
+                retval += &term;
+            }
+            power *= &x_squared;
+            if term.upper_bound_numer.is_zero() {
+                break;
+            }
+        }
+        retval.upper_bound_numer += 1;
+        retval.lower_bound_numer -= 1;
+        retval << halvings
+    }
+    pub fn atan(&self) -> Self {
+        let original_log2_denom = self.log2_denom;
+        let working_log2_denom = original_log2_denom + 16;
+        Self::atan_core(self, working_log2_denom).into_converted_log2_denom(original_log2_denom)
+    }
+    /// computes `(sin(self), cos(self))` together, reducing `self` modulo
+    /// `2 * pi` and then summing the Taylor series for both at once, since
+    /// they share the same `x^2` power ladder
+    fn sin_cos(&self) -> (Self, Self) {
+        let original_log2_denom = self.log2_denom;
+        let working_log2_denom = original_log2_denom
+            + 32
+            + (original_log2_denom + 1)
+                .floor_log2()
+                .expect("known to not fail")
+                * 2;
+        let mut x = self.to_converted_log2_denom(working_log2_denom);
+        let two_pi = Self::pi(working_log2_denom) << 1;
+        // subtract the same multiple of 2*pi from both bounds (rather than
+        // flooring each bound against 2*pi independently) so that a value
+        // landing right next to a period boundary can't cause the two
+        // bounds to floor to different periods and blow up the width
+        let period_count = (&x.lower_bound_numer + &x.upper_bound_numer).div_floor(
+            &(&two_pi.lower_bound_numer + &two_pi.upper_bound_numer),
+        );
+        x -= two_pi * period_count;
+        let x_squared = x.square();
+        let mut sin_term = x;
+        let mut sin_retval = sin_term.clone();
+        let mut cos_term = Self::one(working_log2_denom);
+        let mut cos_retval = cos_term.clone();
+        for i in 1..working_log2_denom {
+            cos_term *= &x_squared;
+            cos_term /= (2 * i - 1) * (2 * i);
+            sin_term *= &x_squared;
+            sin_term /= (2 * i) * (2 * i + 1);
+            if i % 2 == 1 {
+                cos_retval -= &cos_term;
+                sin_retval -= &sin_term;
+            } else {
+                cos_retval += &cos_term;
+                sin_retval += &sin_term;
+            }
+            if cos_term.upper_bound_numer.is_zero() && sin_term.upper_bound_numer.is_zero() {
+                break;
+            }
+        }
+        sin_retval.upper_bound_numer += 1;
+        sin_retval.lower_bound_numer -= 1;
+        cos_retval.upper_bound_numer += 1;
+        cos_retval.lower_bound_numer -= 1;
+        (
+            sin_retval.into_converted_log2_denom(original_log2_denom),
+            cos_retval.into_converted_log2_denom(original_log2_denom),
+        )
+    }
+    pub fn sin(&self) -> Self {
+        self.sin_cos().0
+    }
+    pub fn cos(&self) -> Self {
+        self.sin_cos().1
+    }
+    /// `true` if `self` and `rhs` have exactly the same fields; unlike
+    /// [`PartialEq`], this doesn't treat differently-scaled
+    /// representations of the same set (e.g. `[1 / 2^1, 1 / 2^1]` and
+    /// `[2 / 2^2, 2 / 2^2]`) as equal, which is useful in tests that check
+    /// the exact bounds a computation produced
     pub fn is_same(&self, rhs: &Self) -> bool {
         let Self {
             lower_bound_numer,
@@ -771,7 +1601,45 @@ impl DyadicFractionInterval {
             && *upper_bound_numer == rhs.upper_bound_numer
             && *log2_denom == rhs.log2_denom
     }
+    /// `self`'s bounds and `log2_denom`, with any power-of-two factor
+    /// common to both numerators divided out (without reducing
+    /// `log2_denom` below zero); two intervals compare equal under
+    /// [`PartialEq`] exactly when their normalized forms match, since
+    /// this is the unique canonical representation of the set of reals
+    /// the interval encloses
+    fn normalized_form(&self) -> (BigInt, BigInt, usize) {
+        if self.is_empty() {
+            // all empty intervals enclose the same (empty) set of reals
+            // regardless of `log2_denom`, so they must normalize to a
+            // single shared representation to compare/hash equal
+            return (BigInt::one(), BigInt::zero(), 0);
+        }
+        let shift = match (
+            TrailingZeros::trailing_zeros(&self.lower_bound_numer),
+            TrailingZeros::trailing_zeros(&self.upper_bound_numer),
+        ) {
+            (Some(a), Some(b)) => a.min(b),
+            (Some(a), None) | (None, Some(a)) => a,
+            (None, None) => self.log2_denom,
+        }
+        .min(self.log2_denom);
+        (
+            &self.lower_bound_numer >> shift,
+            &self.upper_bound_numer >> shift,
+            self.log2_denom - shift,
+        )
+    }
+    /// `true` if `self` and `rhs` enclose exactly the same set of reals,
+    /// even if they use different `log2_denom`s, e.g. `[1 / 2^1, 1 / 2^1]`
+    /// and `[2 / 2^2, 2 / 2^2]` are `same_set` even though they aren't
+    /// [`is_same`](Self::is_same)
+    pub fn same_set(&self, rhs: &Self) -> bool {
+        self.lower_bound() == rhs.lower_bound() && self.upper_bound() == rhs.upper_bound()
+    }
     pub fn abs_assign(&mut self) {
+        if self.is_empty() {
+            return;
+        }
         let contains_zero = self.contains_zero();
         if self.lower_bound_numer.is_negative() {
             self.lower_bound_numer = -mem::take(&mut self.lower_bound_numer);
@@ -851,8 +1719,54 @@ impl fmt::Debug for DyadicFractionInterval {
     }
 }
 
+/// formats `value` as a fixed-point decimal with exactly `digits` digits
+/// after the decimal point, rounding to the nearest representable value
+pub(crate) fn format_decimal(value: &Ratio<BigInt>, digits: usize) -> String {
+    let scaled = (value * Ratio::from_integer(BigInt::from(10).pow(digits as u32)))
+        .round()
+        .to_integer();
+    let negative = scaled.is_negative();
+    let magnitude = scaled.abs().to_str_radix(10);
+    let magnitude = if magnitude.len() <= digits {
+        format!("{:0>width$}", magnitude, width = digits + 1)
+    } else {
+        magnitude
+    };
+    let (int_part, frac_part) = magnitude.split_at(magnitude.len() - digits);
+    let mut retval = String::new();
+    if negative {
+        retval.push('-');
+    }
+    retval.push_str(int_part);
+    if digits > 0 {
+        retval.push('.');
+        retval.push_str(frac_part);
+    }
+    retval
+}
+
 impl fmt::Display for DyadicFractionInterval {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(digits) = f.precision() {
+            let midpoint = Ratio::new(
+                &self.lower_bound_numer + &self.upper_bound_numer,
+                BigInt::one() << (self.log2_denom + 1),
+            );
+            let decimal = format_decimal(&midpoint, digits);
+            if self.lower_bound_numer == self.upper_bound_numer {
+                return write!(f, "{}", decimal);
+            }
+            // the printed center is `midpoint` rounded to `digits` places,
+            // so the stated error also covers that rounding
+            let scale = Ratio::from_integer(BigInt::from(10).pow(digits as u32));
+            let rounded = (&midpoint * &scale).round() / scale;
+            let error = self.radius() + (&midpoint - &rounded).abs();
+            let enclosure =
+                Self::from_ratio_range(&rounded - &error, &rounded + &error, self.log2_denom);
+            if let Some(error_exponent) = enclosure.error_exponent() {
+                return write!(f, "{} \u{b1} 2^-{}", decimal, error_exponent);
+            }
+        }
         write!(
             f,
             "[{} / 2^{}, {} / 2^{}]",
@@ -861,6 +1775,25 @@ impl fmt::Display for DyadicFractionInterval {
     }
 }
 
+impl PartialEq for DyadicFractionInterval {
+    /// structural equality on normalized form: `self` and `rhs` compare
+    /// equal exactly when [`Self::normalized_form`] matches, so `[1 /
+    /// 2^1, 1 / 2^1]` and `[2 / 2^2, 2 / 2^2]` are equal even though they
+    /// don't have [`is_same`](Self::is_same) fields; use this (rather
+    /// than [`is_same`](Self::is_same)) to store intervals in maps/sets
+    fn eq(&self, rhs: &Self) -> bool {
+        self.normalized_form() == rhs.normalized_form()
+    }
+}
+
+impl Eq for DyadicFractionInterval {}
+
+impl Hash for DyadicFractionInterval {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.normalized_form().hash(state);
+    }
+}
+
 impl Neg for DyadicFractionInterval {
     type Output = Self;
     fn neg(self) -> Self {
@@ -1020,13 +1953,13 @@ impl AddAssign<&'_ BigInt> for DyadicFractionInterval {
 
 impl AddAssign<Ratio<BigInt>> for DyadicFractionInterval {
     fn add_assign(&mut self, rhs: Ratio<BigInt>) {
-        self.add_assign(DyadicFractionInterval::from_ratio(rhs, self.log2_denom))
+        self.do_add_assign_ratio(&rhs)
     }
 }
 
 impl AddAssign<&'_ Ratio<BigInt>> for DyadicFractionInterval {
     fn add_assign(&mut self, rhs: &Ratio<BigInt>) {
-        self.add_assign(rhs.clone())
+        self.do_add_assign_ratio(rhs)
     }
 }
 
@@ -1066,13 +1999,13 @@ impl SubAssign<&'_ BigInt> for DyadicFractionInterval {
 
 impl SubAssign<Ratio<BigInt>> for DyadicFractionInterval {
     fn sub_assign(&mut self, rhs: Ratio<BigInt>) {
-        self.sub_assign(DyadicFractionInterval::from_ratio(rhs, self.log2_denom))
+        self.do_sub_assign_ratio(&rhs)
     }
 }
 
 impl SubAssign<&'_ Ratio<BigInt>> for DyadicFractionInterval {
     fn sub_assign(&mut self, rhs: &Ratio<BigInt>) {
-        self.sub_assign(rhs.clone())
+        self.do_sub_assign_ratio(rhs)
     }
 }
 
@@ -1279,25 +2212,432 @@ impl<E: Integer> Pow<E> for DyadicFractionInterval {
             if bounds_swapped {
                 mem::swap(&mut retval_lower_bound_numer, &mut retval_upper_bound_numer);
             }
-            if lower_bound_numer_is_negative {
-                retval_lower_bound_numer = -retval_lower_bound_numer;
+            if lower_bound_numer_is_negative {
+                retval_lower_bound_numer = -retval_lower_bound_numer;
+            }
+            if upper_bound_numer_is_negative {
+                retval_upper_bound_numer = -retval_upper_bound_numer;
+            }
+            DyadicFractionInterval {
+                lower_bound_numer: retval_lower_bound_numer,
+                upper_bound_numer: retval_upper_bound_numer,
+                log2_denom,
+            }
+        }
+    }
+}
+
+impl<E: Integer> Pow<E> for &'_ DyadicFractionInterval {
+    type Output = DyadicFractionInterval;
+    fn pow(self, exponent: E) -> DyadicFractionInterval {
+        self.clone().pow(exponent)
+    }
+}
+
+impl Sum<DyadicFractionInterval> for DyadicFractionInterval {
+    /// sums `iter`, ending up with `log2_denom` equal to the max of all
+    /// the summands' `log2_denom`s, since [`AddAssign`] already picks the
+    /// finer of its two operands' `log2_denom`s at every step
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(DyadicFractionInterval::zero(0), |mut acc, item| {
+            acc += item;
+            acc
+        })
+    }
+}
+
+impl<'a> Sum<&'a DyadicFractionInterval> for DyadicFractionInterval {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(DyadicFractionInterval::zero(0), |mut acc, item| {
+            acc += item;
+            acc
+        })
+    }
+}
+
+/// multiplies `items` together using balanced (pairwise) reduction rather
+/// than a left fold, so a product of `n` intervals only accumulates
+/// `O(log n)` rounds of [`DyadicFractionInterval`]'s outward rounding
+/// instead of `O(n)`, limiting how much the width can blow up
+fn balanced_product(mut items: Vec<DyadicFractionInterval>) -> DyadicFractionInterval {
+    if items.is_empty() {
+        return DyadicFractionInterval::one(0);
+    }
+    while items.len() > 1 {
+        let mut reduced = Vec::with_capacity((items.len() + 1) / 2);
+        let mut items_iter = items.into_iter();
+        while let Some(lhs) = items_iter.next() {
+            reduced.push(match items_iter.next() {
+                Some(rhs) => lhs * rhs,
+                None => lhs,
+            });
+        }
+        items = reduced;
+    }
+    items.pop().expect("known to be non-empty")
+}
+
+impl Product<DyadicFractionInterval> for DyadicFractionInterval {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        balanced_product(iter.collect())
+    }
+}
+
+impl<'a> Product<&'a DyadicFractionInterval> for DyadicFractionInterval {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        balanced_product(iter.cloned().collect())
+    }
+}
+
+impl ShlAssign<usize> for DyadicFractionInterval {
+    /// multiplies `self` by `2.pow(rhs)` exactly, by scaling up both
+    /// numerators; `log2_denom` is left unchanged
+    fn shl_assign(&mut self, rhs: usize) {
+        self.lower_bound_numer <<= rhs;
+        self.upper_bound_numer <<= rhs;
+    }
+}
+
+impl ShlAssign<&'_ usize> for DyadicFractionInterval {
+    fn shl_assign(&mut self, rhs: &usize) {
+        *self <<= *rhs;
+    }
+}
+
+forward_op_to_op_assign!(ShlAssign, shl_assign, Shl, shl, usize);
+
+impl ShrAssign<usize> for DyadicFractionInterval {
+    /// divides `self` by `2.pow(rhs)` exactly, by increasing `log2_denom`;
+    /// the numerators are left unchanged
+    fn shr_assign(&mut self, rhs: usize) {
+        self.log2_denom += rhs;
+    }
+}
+
+impl ShrAssign<&'_ usize> for DyadicFractionInterval {
+    fn shr_assign(&mut self, rhs: &usize) {
+        *self >>= *rhs;
+    }
+}
+
+forward_op_to_op_assign!(ShrAssign, shr_assign, Shr, shr, usize);
+
+impl PartialEq<Ratio<BigInt>> for DyadicFractionInterval {
+    fn eq(&self, rhs: &Ratio<BigInt>) -> bool {
+        self.partial_cmp(rhs) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd<Ratio<BigInt>> for DyadicFractionInterval {
+    fn partial_cmp(&self, rhs: &Ratio<BigInt>) -> Option<Ordering> {
+        let lower = self.lower_bound();
+        let upper = self.upper_bound();
+        if upper < *rhs {
+            Some(Ordering::Less)
+        } else if *rhs < lower {
+            Some(Ordering::Greater)
+        } else if lower == upper && lower == *rhs {
+            Some(Ordering::Equal)
+        } else {
+            None
+        }
+    }
+}
+
+impl PartialEq<BigInt> for DyadicFractionInterval {
+    fn eq(&self, rhs: &BigInt) -> bool {
+        self == &Ratio::from_integer(rhs.clone())
+    }
+}
+
+impl PartialOrd<BigInt> for DyadicFractionInterval {
+    fn partial_cmp(&self, rhs: &BigInt) -> Option<Ordering> {
+        self.partial_cmp(&Ratio::from_integer(rhs.clone()))
+    }
+}
+
+/// shifts `value` right by `shift` bits, rounding to the nearest integer
+/// (ties away from zero); returns the rounded value along with a
+/// conservative bound on the rounding error introduced, in units of the
+/// shifted-down result
+fn round_shift_right_nearest(value: BigInt, shift: usize) -> (BigInt, BigInt) {
+    if shift == 0 {
+        return (value, BigInt::zero());
+    }
+    let half = BigInt::one() << (shift - 1);
+    let rounded = if value.is_negative() {
+        -((-value + &half) >> shift)
+    } else {
+        (value + &half) >> shift
+    };
+    // rounding to nearest is off by at most half a unit of the result
+    (rounded, BigInt::one())
+}
+
+/// shifts the non-negative `value` right by `shift` bits, rounding up
+fn ceil_shift_right(value: BigInt, shift: usize) -> BigInt {
+    if shift == 0 {
+        return value;
+    }
+    (value + (BigInt::one() << shift) - BigInt::one()) >> shift
+}
+
+/// a ball enclosure `center \u{b1} radius`, with both `center` and
+/// `radius` dyadic fractions sharing one `log2_denom`; unlike
+/// [`DyadicFractionInterval`], whose corner-based multiplication makes
+/// `log2_denom` grow with every multiply, [`DyadicBall::mul_rounding`]
+/// rounds the product back down to a caller-chosen `log2_denom`, folding
+/// the rounding error into the radius instead of growing the
+/// denominator, which is much cheaper for the long multiplication chains
+/// in high-precision refinement loops, at the cost of a slightly wider
+/// enclosure
+#[derive(Clone, Debug)]
+pub struct DyadicBall {
+    center_numer: BigInt,
+    radius_numer: BigInt,
+    log2_denom: usize,
+}
+
+impl DyadicBall {
+    /// `radius_numer` must be non-negative
+    pub fn new(center_numer: BigInt, radius_numer: BigInt, log2_denom: usize) -> Self {
+        assert!(
+            !radius_numer.is_negative(),
+            "radius_numer must be non-negative"
+        );
+        Self {
+            center_numer,
+            radius_numer,
+            log2_denom,
+        }
+    }
+    pub fn center_numer(&self) -> &BigInt {
+        &self.center_numer
+    }
+    pub fn radius_numer(&self) -> &BigInt {
+        &self.radius_numer
+    }
+    pub fn log2_denom(&self) -> usize {
+        self.log2_denom
+    }
+    pub fn center(&self) -> Ratio<BigInt> {
+        Ratio::new(self.center_numer.clone(), BigInt::one() << self.log2_denom)
+    }
+    pub fn radius(&self) -> Ratio<BigInt> {
+        Ratio::new(self.radius_numer.clone(), BigInt::one() << self.log2_denom)
+    }
+    /// the smallest [`DyadicFractionInterval`] enclosing `self`
+    pub fn to_interval(&self) -> DyadicFractionInterval {
+        DyadicFractionInterval::new(
+            &self.center_numer - &self.radius_numer,
+            &self.center_numer + &self.radius_numer,
+            self.log2_denom,
+        )
+    }
+    /// a ball enclosing `interval`, with its center rounded to the
+    /// nearest multiple of `2.pow(-log2_denom)` and its radius widened to
+    /// cover both `interval` and that rounding, using
+    /// [`DyadicFractionInterval::to_midpoint_and_error`]
+    pub fn from_interval(interval: &DyadicFractionInterval, log2_denom: usize) -> Self {
+        let (center, radius) = interval.to_midpoint_and_error(log2_denom);
+        let center_numer =
+            DyadicFractionInterval::from_ratio_rounding(center, log2_denom, RoundingMode::Nearest)
+                .upper_bound_numer()
+                .clone();
+        let radius_numer =
+            DyadicFractionInterval::from_ratio_rounding(radius, log2_denom, RoundingMode::Ceil)
+                .upper_bound_numer()
+                .clone();
+        Self::new(center_numer, radius_numer, log2_denom)
+    }
+    /// increases `log2_denom` to exactly `log2_denom`, which is always
+    /// exact since it just scales both numerators up; reducing
+    /// `log2_denom` requires rounding, so go through
+    /// [`Self::to_interval`]/[`Self::from_interval`] instead
+    pub fn convert_log2_denom(&mut self, log2_denom: usize) {
+        assert!(
+            log2_denom >= self.log2_denom,
+            "DyadicBall::convert_log2_denom can only increase log2_denom"
+        );
+        let shift = log2_denom - self.log2_denom;
+        self.center_numer <<= shift;
+        self.radius_numer <<= shift;
+        self.log2_denom = log2_denom;
+    }
+    pub fn into_converted_log2_denom(mut self, log2_denom: usize) -> Self {
+        self.convert_log2_denom(log2_denom);
+        self
+    }
+    fn do_add_assign(&mut self, rhs: Cow<Self>) {
+        let log2_denom = self.log2_denom.max(rhs.log2_denom);
+        self.convert_log2_denom(log2_denom);
+        let rhs = rhs.into_owned().into_converted_log2_denom(log2_denom);
+        self.center_numer += rhs.center_numer;
+        self.radius_numer += rhs.radius_numer;
+    }
+    fn do_sub_assign(&mut self, rhs: Cow<Self>) {
+        let log2_denom = self.log2_denom.max(rhs.log2_denom);
+        self.convert_log2_denom(log2_denom);
+        let rhs = rhs.into_owned().into_converted_log2_denom(log2_denom);
+        self.center_numer -= rhs.center_numer;
+        self.radius_numer += rhs.radius_numer;
+    }
+    /// like `*`, but lets the caller pick the result's `log2_denom`
+    /// instead of always keeping the wider of the two operands'; folds
+    /// both the ball-multiplication error term (`|c1| r2 + |c2| r1 + r1
+    /// r2`) and the rounding needed to fit the product into `log2_denom`
+    /// bits into the result's radius
+    pub fn mul_rounding(&self, rhs: &Self, log2_denom: usize) -> Self {
+        let combined_log2_denom = self.log2_denom + rhs.log2_denom;
+        assert!(
+            log2_denom <= combined_log2_denom,
+            "log2_denom is finer than the exact product"
+        );
+        let shift = combined_log2_denom - log2_denom;
+        let center_product = &self.center_numer * &rhs.center_numer;
+        let error_bound = self.center_numer.abs() * &rhs.radius_numer
+            + rhs.center_numer.abs() * &self.radius_numer
+            + &self.radius_numer * &rhs.radius_numer;
+        let (center_numer, rounding_error) = round_shift_right_nearest(center_product, shift);
+        let radius_numer = ceil_shift_right(error_bound, shift) + rounding_error;
+        Self {
+            center_numer,
+            radius_numer,
+            log2_denom,
+        }
+    }
+    /// multiplies `self` and `rhs`, keeping `log2_denom` at the wider of
+    /// the two operands' rather than growing it; see [`Self::mul_rounding`]
+    fn do_mul_assign(&mut self, rhs: &Self) {
+        *self = self.mul_rounding(rhs, self.log2_denom.max(rhs.log2_denom));
+    }
+}
+
+impl Neg for DyadicBall {
+    type Output = DyadicBall;
+    fn neg(self) -> DyadicBall {
+        -&self
+    }
+}
+
+impl Neg for &'_ DyadicBall {
+    type Output = DyadicBall;
+    fn neg(self) -> DyadicBall {
+        DyadicBall {
+            center_numer: -&self.center_numer,
+            radius_numer: self.radius_numer.clone(),
+            log2_denom: self.log2_denom,
+        }
+    }
+}
+
+impl AddAssign<DyadicBall> for DyadicBall {
+    fn add_assign(&mut self, rhs: DyadicBall) {
+        self.do_add_assign(Cow::Owned(rhs));
+    }
+}
+
+impl AddAssign<&'_ DyadicBall> for DyadicBall {
+    fn add_assign(&mut self, rhs: &DyadicBall) {
+        self.do_add_assign(Cow::Borrowed(rhs));
+    }
+}
+
+impl SubAssign<DyadicBall> for DyadicBall {
+    fn sub_assign(&mut self, rhs: DyadicBall) {
+        self.do_sub_assign(Cow::Owned(rhs));
+    }
+}
+
+impl SubAssign<&'_ DyadicBall> for DyadicBall {
+    fn sub_assign(&mut self, rhs: &DyadicBall) {
+        self.do_sub_assign(Cow::Borrowed(rhs));
+    }
+}
+
+impl MulAssign<DyadicBall> for DyadicBall {
+    fn mul_assign(&mut self, rhs: DyadicBall) {
+        self.do_mul_assign(&rhs);
+    }
+}
+
+impl MulAssign<&'_ DyadicBall> for DyadicBall {
+    fn mul_assign(&mut self, rhs: &DyadicBall) {
+        self.do_mul_assign(rhs);
+    }
+}
+
+macro_rules! forward_dyadic_ball_op_to_op_assign {
+    ($op_assign_trait:ident, $op_assign:ident, $op_trait:ident, $op:ident) => {
+        impl $op_trait<DyadicBall> for DyadicBall {
+            type Output = DyadicBall;
+            fn $op(mut self, rhs: DyadicBall) -> DyadicBall {
+                self.$op_assign(rhs);
+                self
+            }
+        }
+
+        impl $op_trait<&'_ DyadicBall> for DyadicBall {
+            type Output = DyadicBall;
+            fn $op(mut self, rhs: &DyadicBall) -> DyadicBall {
+                self.$op_assign(rhs);
+                self
             }
-            if upper_bound_numer_is_negative {
-                retval_upper_bound_numer = -retval_upper_bound_numer;
+        }
+
+        impl $op_trait<DyadicBall> for &'_ DyadicBall {
+            type Output = DyadicBall;
+            fn $op(self, rhs: DyadicBall) -> DyadicBall {
+                self.clone().$op(rhs)
             }
-            DyadicFractionInterval {
-                lower_bound_numer: retval_lower_bound_numer,
-                upper_bound_numer: retval_upper_bound_numer,
-                log2_denom,
+        }
+
+        impl<'a, 'b> $op_trait<&'a DyadicBall> for &'b DyadicBall {
+            type Output = DyadicBall;
+            fn $op(self, rhs: &DyadicBall) -> DyadicBall {
+                self.clone().$op(rhs)
             }
         }
+    };
+}
+
+forward_dyadic_ball_op_to_op_assign!(AddAssign, add_assign, Add, add);
+forward_dyadic_ball_op_to_op_assign!(SubAssign, sub_assign, Sub, sub);
+forward_dyadic_ball_op_to_op_assign!(MulAssign, mul_assign, Mul, mul);
+
+impl PartialEq for DyadicBall {
+    /// structural equality on normalized form: `self` and `rhs` compare
+    /// equal exactly when their [`Self::to_interval`] results do, so
+    /// balls with different `log2_denom`s but the same enclosed interval
+    /// still compare equal
+    fn eq(&self, rhs: &Self) -> bool {
+        self.to_interval() == rhs.to_interval()
     }
 }
 
-impl<E: Integer> Pow<E> for &'_ DyadicFractionInterval {
-    type Output = DyadicFractionInterval;
-    fn pow(self, exponent: E) -> DyadicFractionInterval {
-        self.clone().pow(exponent)
+impl Eq for DyadicBall {}
+
+/// an axis-aligned rectangle in the complex plane, formed as the product
+/// of a real-part and an imaginary-part [`DyadicFractionInterval`]; used
+/// to isolate a single (possibly non-real) complex root
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct ComplexDyadicInterval {
+    real_part: DyadicFractionInterval,
+    imaginary_part: DyadicFractionInterval,
+}
+
+impl ComplexDyadicInterval {
+    pub fn new(real_part: DyadicFractionInterval, imaginary_part: DyadicFractionInterval) -> Self {
+        Self {
+            real_part,
+            imaginary_part,
+        }
+    }
+    pub fn real_part(&self) -> &DyadicFractionInterval {
+        &self.real_part
+    }
+    pub fn imaginary_part(&self) -> &DyadicFractionInterval {
+        &self.imaginary_part
     }
 }
 
@@ -1396,6 +2736,78 @@ mod tests {
         assert_same!(DFI::from_ratio(r(1, 8), 8), DFI::new(bi(32), bi(32), 8));
     }
 
+    #[test]
+    fn test_from_ratio_rounding() {
+        assert_same!(
+            DFI::from_ratio_rounding(r(2, 3), 8, RoundingMode::Floor),
+            DFI::new(bi(170), bi(170), 8)
+        );
+        assert_same!(
+            DFI::from_ratio_rounding(r(2, 3), 8, RoundingMode::Ceil),
+            DFI::new(bi(171), bi(171), 8)
+        );
+        assert_same!(
+            DFI::from_ratio_rounding(r(2, 3), 8, RoundingMode::Nearest),
+            DFI::new(bi(171), bi(171), 8)
+        );
+        assert_same!(
+            DFI::from_ratio_rounding(r(2, 3), 8, RoundingMode::Outward),
+            DFI::from_ratio(r(2, 3), 8)
+        );
+        assert_same!(
+            DFI::from_ratio_rounding(r(1, 8), 8, RoundingMode::Nearest),
+            DFI::new(bi(32), bi(32), 8)
+        );
+    }
+
+    #[test]
+    fn test_convert_log2_denom_rounding() {
+        let value = DFI::new(bi(5), bi(5), 0);
+        assert_same!(
+            value.to_converted_log2_denom_rounding(0, RoundingMode::Floor),
+            DFI::new(bi(5), bi(5), 0)
+        );
+        let value = DFI::from_ratio(r(5, 3), 8);
+        assert_same!(
+            value.to_converted_log2_denom_rounding(2, RoundingMode::Floor),
+            DFI::new(bi(6), bi(6), 2)
+        );
+        assert_same!(
+            value.to_converted_log2_denom_rounding(2, RoundingMode::Ceil),
+            DFI::new(bi(7), bi(7), 2)
+        );
+        assert_same!(
+            value.to_converted_log2_denom_rounding(2, RoundingMode::Nearest),
+            DFI::new(bi(7), bi(7), 2)
+        );
+        assert_same!(
+            value.to_converted_log2_denom_rounding(2, RoundingMode::Outward),
+            value.to_converted_log2_denom(2)
+        );
+    }
+
+    #[test]
+    fn test_compress_to_bits() {
+        // already within budget: left unchanged
+        let value = DFI::new(bi(3), bi(5), 8);
+        assert_same!(value.compress_to_bits(8), value);
+
+        // over budget: log2_denom drops by exactly the excess bit count,
+        // rounding outward so the compressed interval still contains the
+        // original
+        let value = DFI::new(bi(0x1_2345), bi(0x1_2399), 16);
+        let compressed = value.compress_to_bits(8);
+        assert_eq!(compressed.log2_denom(), 7);
+        assert!(compressed.contains_interval(&value));
+        assert!(compressed.lower_bound_numer().bits() <= 8);
+        assert!(compressed.upper_bound_numer().bits() <= 8);
+
+        // never reduces log2_denom below zero, even for a huge budget
+        // overrun
+        let value = DFI::new(bi(3), bi(5), 2);
+        assert_same!(value.compress_to_bits(0), value.to_converted_log2_denom(0));
+    }
+
     #[test]
     fn test_convert_log2_denom() {
         assert_same!(
@@ -1456,6 +2868,60 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_normalize() {
+        assert_same!(
+            DFI::new(bi(2), bi(4), 2).into_normalized(),
+            DFI::new(bi(1), bi(2), 1)
+        );
+        assert_same!(
+            DFI::new(bi(-8), bi(-8), 3).into_normalized(),
+            DFI::new(bi(-1), bi(-1), 0)
+        );
+        // already-normalized intervals are left unchanged
+        assert_same!(
+            DFI::new(bi(1), bi(3), 4).into_normalized(),
+            DFI::new(bi(1), bi(3), 4)
+        );
+        assert_same!(
+            DFI::new(bi(0), bi(0), 16).into_normalized(),
+            DFI::new(bi(0), bi(0), 0)
+        );
+        let mut value = DFI::new(bi(6), bi(6), 4);
+        value.normalize();
+        assert_same!(value.clone(), DFI::new(bi(3), bi(3), 3));
+        assert_same!(value.normalized(), DFI::new(bi(3), bi(3), 3));
+    }
+
+    #[test]
+    fn test_precision_policy() {
+        let lhs = DFI::new(bi(1), bi(2), 4);
+        let rhs = DFI::new(bi(3), bi(4), 8);
+        assert_same!(
+            lhs.add_with_policy(&rhs, &PrecisionPolicy::Fixed(2)),
+            &lhs.to_converted_log2_denom(2) + &rhs.to_converted_log2_denom(2)
+        );
+        assert_same!(
+            lhs.add_with_policy(&rhs, &PrecisionPolicy::GrowBy(4)),
+            &lhs.to_converted_log2_denom(12) + &rhs.to_converted_log2_denom(12)
+        );
+        assert_same!(
+            lhs.sub_with_policy(&rhs, &PrecisionPolicy::Budgeted { max_bits: 6 }),
+            &lhs.to_converted_log2_denom(6) - &rhs.to_converted_log2_denom(6)
+        );
+        assert_same!(
+            lhs.mul_with_policy(&rhs, &PrecisionPolicy::Budgeted { max_bits: 100 }),
+            &lhs.to_converted_log2_denom(8) * &rhs.to_converted_log2_denom(8)
+        );
+        assert_same!(
+            lhs.checked_div_with_policy(&rhs, &PrecisionPolicy::Fixed(16))
+                .unwrap(),
+            lhs.to_converted_log2_denom(16)
+                .checked_div(&rhs.to_converted_log2_denom(16))
+                .unwrap()
+        );
+    }
+
     #[test]
     fn test_square() {
         assert_same!(
@@ -1532,6 +2998,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_hypot() {
+        assert_same!(
+            DFI::new(bi(3), bi(3), 0).hypot(&DFI::new(bi(4), bi(4), 0)),
+            DFI::new(bi(5), bi(5), 0)
+        );
+        assert_same!(
+            DFI::new(bi(0), bi(0), 8).hypot(&DFI::new(bi(0), bi(0), 8)),
+            DFI::new(bi(0), bi(0), 8)
+        );
+        assert_same!(
+            DFI::new(bi(256), bi(256), 8).hypot(&DFI::new(bi(256), bi(256), 8)),
+            DFI::new(bi(362), bi(363), 8)
+        );
+        // differing `log2_denom`s must not overflow or panic
+        assert_same!(
+            DFI::new(bi(3), bi(3), 0).hypot(&DFI::new(bi(1024), bi(1024), 8)),
+            DFI::new(bi(1280), bi(1280), 8)
+        );
+    }
+
     #[test]
     fn test_arithmetic_geometric_mean() {
         assert_same!(
@@ -1572,6 +3059,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_eq_hash_same_set() {
+        use std::collections::hash_map::DefaultHasher;
+        fn hash_of(value: &DFI) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+        let half_at_1 = DFI::new(bi(1), bi(1), 1);
+        let half_at_2 = DFI::new(bi(2), bi(2), 2);
+        assert_eq!(half_at_1, half_at_2);
+        assert_eq!(hash_of(&half_at_1), hash_of(&half_at_2));
+        assert!(half_at_1.same_set(&half_at_2));
+        assert!(!half_at_1.is_same(&half_at_2));
+        let third = DFI::new(bi(1), bi(1), 3);
+        assert_ne!(half_at_1, third);
+        assert!(!half_at_1.same_set(&third));
+        // a nonzero-width interval also normalizes correctly
+        let range_at_2 = DFI::new(bi(2), bi(4), 2);
+        let range_at_1 = DFI::new(bi(1), bi(2), 1);
+        assert_eq!(range_at_2, range_at_1);
+        assert!(range_at_2.same_set(&range_at_1));
+    }
+
     #[test]
     fn test_display() {
         assert_eq!(
@@ -1580,6 +3091,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_decimal_display() {
+        assert_eq!(format!("{:.4}", DFI::from_ratio(r(1, 8), 16)), "0.1250");
+        assert_eq!(format!("{:.2}", DFI::from_ratio(r(-5, 4), 16)), "-1.25");
+        let interval = DFI::from_ratio_range(r(1, 1), r(5, 4), 4);
+        assert_eq!(format!("{:.2}", interval), "1.13 \u{b1} 2^-2");
+        assert_eq!(interval.to_decimal_string(2), format!("{:.2}", interval));
+        // without a requested precision, falls back to the bracket form
+        assert_eq!(
+            &format!("{}", DFI::new(bi(-123), bi(456), 789)),
+            "[-123 / 2^789, 456 / 2^789]",
+        );
+    }
+
     #[test]
     fn test_interval_union() {
         fn test_case(lhs: DFI, rhs: DFI, expected: DFI) {
@@ -1658,6 +3183,312 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_convex_hull() {
+        assert!(DFI::new(bi(3), bi(5), 0)
+            .convex_hull(&DFI::new(bi(17), bi(97), 0))
+            .is_same(&DFI::new(bi(3), bi(97), 0)));
+        assert!(DFI::new(bi(3), bi(5), 1)
+            .convex_hull(&DFI::new(bi(17), bi(97), 0))
+            .is_same(&DFI::new(bi(3), bi(194), 1)));
+    }
+
+    #[test]
+    fn test_interval_min_max() {
+        let a = DFI::new(bi(3), bi(97), 0); // [3, 97]
+        let b = DFI::new(bi(17), bi(60), 0); // [17, 60]
+        assert!(a.interval_min(&b).is_same(&DFI::new(bi(3), bi(60), 0)));
+        assert!(b.interval_min(&a).is_same(&DFI::new(bi(3), bi(60), 0)));
+        assert!(a.interval_max(&b).is_same(&DFI::new(bi(17), bi(97), 0)));
+        assert!(b.interval_max(&a).is_same(&DFI::new(bi(17), bi(97), 0)));
+
+        // differing log2_denom get reconciled before combining
+        let c = DFI::new(bi(17), bi(60), 1); // [8.5, 30]
+        assert!(a.interval_min(&c).is_same(&DFI::new(bi(6), bi(60), 1)));
+        assert!(a.interval_max(&c).is_same(&DFI::new(bi(17), bi(194), 1)));
+
+        // disjoint ranges still enclose every pointwise min/max exactly
+        let d = DFI::new(bi(-5), bi(-1), 0);
+        assert!(a.interval_min(&d).is_same(&DFI::new(bi(-5), bi(-1), 0)));
+        assert!(a.interval_max(&d).is_same(&DFI::new(bi(3), bi(97), 0)));
+
+        // an empty operand makes the result empty
+        let empty = DFI::empty(0);
+        assert!(a.interval_min(&empty).is_empty());
+        assert!(empty.interval_max(&a).is_empty());
+    }
+
+    #[test]
+    fn test_clamp() {
+        let lo = DFI::from_int(bi(0), 0);
+        let hi = DFI::from_int(bi(10), 0);
+        assert!(DFI::new(bi(3), bi(5), 0)
+            .clamp(&lo, &hi)
+            .is_same(&DFI::new(bi(3), bi(5), 0)));
+        assert!(DFI::new(bi(-8), bi(-3), 0)
+            .clamp(&lo, &hi)
+            .is_same(&DFI::new(bi(0), bi(0), 0)));
+        assert!(DFI::new(bi(15), bi(20), 0)
+            .clamp(&lo, &hi)
+            .is_same(&DFI::new(bi(10), bi(10), 0)));
+        // straddling either edge clamps only the part that's out of range
+        assert!(DFI::new(bi(-3), bi(5), 0)
+            .clamp(&lo, &hi)
+            .is_same(&DFI::new(bi(0), bi(5), 0)));
+    }
+
+    #[test]
+    fn test_intersection() {
+        assert!(DFI::new(bi(3), bi(97), 0)
+            .intersection(&DFI::new(bi(17), bi(120), 0))
+            .unwrap()
+            .is_same(&DFI::new(bi(17), bi(97), 0)));
+        // differing log2_denom get reconciled before intersecting
+        assert!(DFI::new(bi(3), bi(97), 1)
+            .intersection(&DFI::new(bi(17), bi(60), 0))
+            .unwrap()
+            .is_same(&DFI::new(bi(34), bi(97), 1)));
+        assert!(DFI::new(bi(3), bi(5), 0)
+            .intersection(&DFI::new(bi(17), bi(97), 0))
+            .is_none());
+        // touching at a single point is a non-empty intersection
+        assert!(DFI::new(bi(3), bi(5), 0)
+            .intersection(&DFI::new(bi(5), bi(97), 0))
+            .unwrap()
+            .is_same(&DFI::new(bi(5), bi(5), 0)));
+    }
+
+    #[test]
+    fn test_empty() {
+        let empty = DFI::empty(4);
+        let non_empty = DFI::new(bi(3), bi(97), 4);
+        assert!(empty.is_empty());
+        assert!(!non_empty.is_empty());
+
+        // arithmetic propagates emptiness instead of producing nonsense
+        assert!((&empty + &non_empty).is_empty());
+        assert!((&non_empty + &empty).is_empty());
+        assert!((&empty - &non_empty).is_empty());
+        assert!(empty.square().is_empty());
+        assert!(empty.sqrt().is_empty());
+        assert!(empty.abs().is_empty());
+        assert!((-empty.clone()).is_empty());
+        assert!(non_empty.checked_div(&empty).unwrap().is_empty());
+        assert!(empty.checked_div(&non_empty).unwrap().is_empty());
+
+        // two empty intervals are equal regardless of log2_denom
+        assert_eq!(DFI::empty(1), DFI::empty(9));
+        assert_ne!(DFI::empty(4), non_empty);
+
+        // an interval created via a disjoint intersection is empty and
+        // propagates the same way
+        let disjoint_intersection = DFI::new(bi(3), bi(5), 0)
+            .intersection(&DFI::new(bi(17), bi(97), 0));
+        assert!(disjoint_intersection.is_none());
+
+        // any interval contains the empty interval; only the empty
+        // interval contains itself
+        assert!(non_empty.contains_interval(&empty));
+        assert!(!empty.contains_interval(&non_empty));
+        assert!(empty.contains_interval(&empty));
+
+        // union with an empty interval is the identity
+        assert!(empty
+            .clone()
+            .interval_union(&non_empty)
+            .is_same(&non_empty));
+        assert!(non_empty
+            .clone()
+            .interval_union(&empty)
+            .is_same(&non_empty));
+    }
+
+    #[test]
+    fn test_contains_ratio() {
+        let interval = DFI::new(bi(3), bi(97), 1); // [1.5, 48.5]
+        assert!(interval.contains_ratio(&Ratio::new(bi(3), bi(2))));
+        assert!(interval.contains_ratio(&Ratio::new(bi(97), bi(2))));
+        assert!(interval.contains_ratio(&Ratio::new(bi(20), bi(1))));
+        assert!(!interval.contains_ratio(&Ratio::new(bi(1), bi(1))));
+        assert!(!interval.contains_ratio(&Ratio::new(bi(49), bi(1))));
+    }
+
+    #[test]
+    fn test_contains_int() {
+        let interval = DFI::new(bi(3), bi(97), 1); // [1.5, 48.5]
+        assert!(interval.contains_int(&bi(2)));
+        assert!(interval.contains_int(&bi(48)));
+        assert!(!interval.contains_int(&bi(1)));
+        assert!(!interval.contains_int(&bi(49)));
+    }
+
+    #[test]
+    fn test_contains_interval_and_is_subset_of() {
+        let outer = DFI::new(bi(3), bi(97), 0);
+        let inner = DFI::new(bi(34), bi(97), 1); // [17, 48.5]
+        let disjoint = DFI::new(bi(200), bi(300), 0);
+        assert!(outer.contains_interval(&inner));
+        assert!(inner.is_subset_of(&outer));
+        assert!(!inner.contains_interval(&outer));
+        assert!(!outer.is_subset_of(&inner));
+        assert!(!outer.contains_interval(&disjoint));
+        assert!(outer.contains_interval(&outer));
+        assert!(outer.is_subset_of(&outer));
+    }
+
+    #[test]
+    fn test_width_midpoint_radius() {
+        let interval = DFI::new(bi(3), bi(97), 1); // [1.5, 48.5]
+        assert_eq!(interval.width(), Ratio::new(bi(47), bi(1)));
+        assert_eq!(interval.radius(), Ratio::new(bi(47), bi(2)));
+        assert!(interval
+            .midpoint()
+            .is_same(&DFI::from_dyadic_fraction(bi(100), 2)));
+        assert_eq!(interval.midpoint().lower_bound(), Ratio::new(bi(25), bi(1)));
+    }
+
+    #[test]
+    fn test_bisect() {
+        let interval = DFI::new(bi(3), bi(97), 1); // [1.5, 48.5]
+        let (lower, upper) = interval.bisect();
+        assert!(lower.is_same(&DFI::new(bi(6), bi(100), 2)));
+        assert!(upper.is_same(&DFI::new(bi(100), bi(194), 2)));
+        assert_eq!(lower.upper_bound(), upper.lower_bound());
+        assert_eq!(lower.upper_bound(), interval.midpoint().lower_bound());
+    }
+
+    #[test]
+    fn test_split_at() {
+        let interval = DFI::new(bi(0), bi(80), 2); // [0, 20]
+        let (lower, upper) = interval.split_at(&r(1, 3));
+        assert!(lower.contains_interval(&DFI::from_int(bi(0), 2)));
+        assert!(upper.contains_interval(&DFI::from_int(bi(20), 2)));
+        // the seam covers the exact split point from both sides, so no
+        // gap is ever left between the two pieces
+        assert!(lower.upper_bound() >= r(1, 3));
+        assert!(upper.lower_bound() <= r(1, 3));
+        assert_eq!(interval.lower_bound(), lower.lower_bound());
+        assert_eq!(interval.upper_bound(), upper.upper_bound());
+    }
+
+    #[test]
+    fn test_to_midpoint_and_error() {
+        let interval = DFI::new(bi(3), bi(97), 1); // [1.5, 48.5]
+        let (midpoint, error) = interval.to_midpoint_and_error(2);
+        assert_eq!(midpoint, Ratio::new(bi(25), bi(1)));
+        assert_eq!(error, Ratio::new(bi(47), bi(2)));
+
+        // a midpoint rounded to a coarser precision still yields an error
+        // bound that encloses the whole interval
+        let (midpoint, error) = interval.to_midpoint_and_error(0);
+        assert!(&midpoint - &error <= interval.lower_bound());
+        assert!(&midpoint + &error >= interval.upper_bound());
+    }
+
+    #[test]
+    fn test_is_tighter_than() {
+        let interval = DFI::new(bi(3), bi(5), 8); // width 2/256 == 1/128
+        assert!(interval.is_tighter_than(0));
+        assert!(interval.is_tighter_than(7));
+        assert!(!interval.is_tighter_than(8));
+        let point = DFI::from_int(bi(1), 0);
+        assert!(point.is_tighter_than(1000));
+    }
+
+    #[test]
+    fn test_interval_cmp() {
+        let low = DFI::new(bi(3), bi(5), 0);
+        let high = DFI::new(bi(17), bi(97), 0);
+        let overlapping = DFI::new(bi(4), bi(20), 0);
+        assert_eq!(low.interval_cmp(&high), Some(Ordering::Less));
+        assert_eq!(high.interval_cmp(&low), Some(Ordering::Greater));
+        assert_eq!(low.interval_cmp(&overlapping), None);
+        assert_eq!(overlapping.interval_cmp(&low), None);
+        let point = DFI::from_int(bi(5), 0);
+        assert_eq!(point.interval_cmp(&point), Some(Ordering::Equal));
+    }
+
+    #[test]
+    fn test_partial_ord_ratio_and_int() {
+        let interval = DFI::new(bi(3), bi(97), 1); // [1.5, 48.5]
+        assert_eq!(
+            interval.partial_cmp(&Ratio::new(bi(1), bi(1))),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(
+            interval.partial_cmp(&Ratio::new(bi(49), bi(1))),
+            Some(Ordering::Less)
+        );
+        assert_eq!(interval.partial_cmp(&Ratio::new(bi(20), bi(1))), None);
+        assert_eq!(interval.partial_cmp(&bi(1)), Some(Ordering::Greater));
+        assert_eq!(interval.partial_cmp(&bi(49)), Some(Ordering::Less));
+        assert_eq!(interval.partial_cmp(&bi(20)), None);
+        let point = DFI::from_int(bi(5), 0);
+        assert!(point == bi(5));
+        assert!(point != bi(6));
+    }
+
+    #[test]
+    fn test_sign() {
+        let positive = DFI::new(bi(3), bi(5), 0);
+        assert_eq!(positive.sign(), IntervalSign::PositiveDefinite);
+        assert!(positive.is_positive_definite());
+        assert!(!positive.is_negative_definite());
+
+        let negative = DFI::new(bi(-5), bi(-3), 0);
+        assert_eq!(negative.sign(), IntervalSign::NegativeDefinite);
+        assert!(negative.is_negative_definite());
+        assert!(!negative.is_positive_definite());
+
+        let zero = DFI::from_int(bi(0), 4);
+        assert_eq!(zero.sign(), IntervalSign::ZeroDefinite);
+        assert!(!zero.is_positive_definite());
+        assert!(!zero.is_negative_definite());
+
+        let straddling = DFI::new(bi(-3), bi(5), 0);
+        assert_eq!(straddling.sign(), IntervalSign::ContainsZero);
+        assert!(!straddling.is_positive_definite());
+        assert!(!straddling.is_negative_definite());
+    }
+
+    #[test]
+    fn test_floor_ceil_trunc_round_int() {
+        fn assert_err_is_same(result: Result<BigInt, DFI>, expected: DFI) {
+            match result {
+                Err(actual) => assert!(actual.is_same(&expected)),
+                Ok(value) => panic!("expected Err({:?}), got Ok({:?})", expected, value),
+            }
+        }
+
+        // consistent interval: [1.5, 1.75]
+        let consistent = DFI::new(bi(6), bi(7), 2);
+        assert_eq!(consistent.floor_int().unwrap(), bi(1));
+        assert_eq!(consistent.ceil_int().unwrap(), bi(2));
+        assert_eq!(consistent.trunc_int().unwrap(), bi(1));
+        assert_eq!(consistent.round_int().unwrap(), bi(2));
+
+        // ambiguous interval straddling an integer: [1.5, 2.5]
+        let straddling = DFI::new(bi(3), bi(5), 1);
+        assert_err_is_same(straddling.floor_int(), DFI::new(bi(1), bi(2), 0));
+        assert_err_is_same(straddling.ceil_int(), DFI::new(bi(2), bi(3), 0));
+        assert_err_is_same(straddling.trunc_int(), DFI::new(bi(1), bi(2), 0));
+        assert_err_is_same(straddling.round_int(), DFI::new(bi(2), bi(3), 0));
+
+        // consistent negative interval: [-1.75, -1.5]
+        let negative = DFI::new(bi(-7), bi(-6), 2);
+        assert_eq!(negative.floor_int().unwrap(), bi(-2));
+        assert_eq!(negative.ceil_int().unwrap(), bi(-1));
+        assert_eq!(negative.trunc_int().unwrap(), bi(-1));
+        assert_eq!(negative.round_int().unwrap(), bi(-2));
+
+        // exact integer
+        let exact = DFI::from_int(bi(5), 3);
+        assert_eq!(exact.floor_int().unwrap(), bi(5));
+        assert_eq!(exact.ceil_int().unwrap(), bi(5));
+        assert_eq!(exact.trunc_int().unwrap(), bi(5));
+        assert_eq!(exact.round_int().unwrap(), bi(5));
+    }
+
     #[test]
     fn test_add() {
         fn test_case(lhs: DFI, rhs: DFI, expected: DFI) {
@@ -1768,6 +3599,12 @@ mod tests {
             r(-7, 5),
             DFI::new(bi(-356), bi(-353), 8),
         );
+        // an exact rational addend must not introduce any extra width
+        test_case(
+            DFI::new(bi(3), bi(5), 8),
+            r(1, 4),
+            DFI::new(bi(67), bi(69), 8),
+        );
     }
 
     #[test]
@@ -1880,6 +3717,12 @@ mod tests {
             r(-7, 5),
             DFI::new(bi(361), bi(364), 8),
         );
+        // an exact rational subtrahend must not introduce any extra width
+        test_case(
+            DFI::new(bi(3), bi(5), 8),
+            r(1, 4),
+            DFI::new(bi(-61), bi(-59), 8),
+        );
     }
 
     #[test]
@@ -2005,6 +3848,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_sum() {
+        let items = vec![
+            DFI::new(bi(1), bi(2), 0),
+            DFI::new(bi(3), bi(4), 4),
+            DFI::new(bi(-1), bi(1), 8),
+        ];
+        let expected = DFI::new(bi(1), bi(2), 0)
+            + DFI::new(bi(3), bi(4), 4)
+            + DFI::new(bi(-1), bi(1), 8);
+        assert_same!(items.iter().sum::<DFI>(), expected.clone());
+        assert_same!(items.into_iter().sum::<DFI>(), expected);
+        assert_same!(std::iter::empty::<DFI>().sum::<DFI>(), DFI::new(bi(0), bi(0), 0));
+    }
+
+    #[test]
+    fn test_product() {
+        let items = vec![
+            DFI::new(bi(3), bi(3), 0),
+            DFI::new(bi(4), bi(4), 0),
+            DFI::new(bi(5), bi(5), 0),
+        ];
+        assert_same!(
+            items.iter().product::<DFI>(),
+            DFI::new(bi(60), bi(60), 0)
+        );
+        assert_same!(
+            items.into_iter().product::<DFI>(),
+            DFI::new(bi(60), bi(60), 0)
+        );
+        assert_same!(
+            std::iter::empty::<DFI>().product::<DFI>(),
+            DFI::new(bi(1), bi(1), 0)
+        );
+    }
+
     #[test]
     fn test_mul_int() {
         fn test_case(lhs: DFI, rhs: BigInt, expected: DFI) {
@@ -2191,6 +4070,65 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_checked_div() {
+        let lhs = DFI::new(bi(3), bi(5), 8);
+        let rhs = DFI::new(bi(17), bi(97), 8);
+        assert_eq!(
+            lhs.checked_div(&rhs).map(SameWrapper),
+            lhs.clone()
+                .checked_exact_div(rhs.clone())
+                .map(SameWrapper)
+        );
+        let zero_containing = DFI::new(bi(-17), bi(97), 8);
+        assert!(lhs.checked_div(&zero_containing).is_none());
+    }
+
+    #[test]
+    fn test_shl() {
+        fn test_case(lhs: DFI, rhs: usize, expected: DFI) {
+            test_op_helper(
+                SameWrapper(lhs),
+                rhs,
+                &SameWrapper(expected),
+                |SameWrapper(a), b| a.shl_assign(b),
+                |SameWrapper(a), b| a.shl_assign(b),
+                |SameWrapper(a), b| SameWrapper(a.shl(b)),
+                |SameWrapper(a), b| SameWrapper(a.shl(b)),
+                |SameWrapper(a), b| SameWrapper(a.shl(b)),
+                |SameWrapper(a), b| SameWrapper(a.shl(b)),
+            );
+        }
+        test_case(DFI::new(bi(3), bi(5), 8), 0, DFI::new(bi(3), bi(5), 8));
+        test_case(DFI::new(bi(3), bi(5), 8), 2, DFI::new(bi(12), bi(20), 8));
+        test_case(DFI::new(bi(-3), bi(5), 4), 3, DFI::new(bi(-24), bi(40), 4));
+    }
+
+    #[test]
+    fn test_shr() {
+        fn test_case(lhs: DFI, rhs: usize, expected: DFI) {
+            test_op_helper(
+                SameWrapper(lhs),
+                rhs,
+                &SameWrapper(expected),
+                |SameWrapper(a), b| a.shr_assign(b),
+                |SameWrapper(a), b| a.shr_assign(b),
+                |SameWrapper(a), b| SameWrapper(a.shr(b)),
+                |SameWrapper(a), b| SameWrapper(a.shr(b)),
+                |SameWrapper(a), b| SameWrapper(a.shr(b)),
+                |SameWrapper(a), b| SameWrapper(a.shr(b)),
+            );
+        }
+        test_case(DFI::new(bi(3), bi(5), 8), 0, DFI::new(bi(3), bi(5), 8));
+        test_case(DFI::new(bi(3), bi(5), 8), 2, DFI::new(bi(3), bi(5), 10));
+        test_case(DFI::new(bi(-3), bi(5), 4), 3, DFI::new(bi(-3), bi(5), 7));
+        // Shl followed by Shr by the same amount represents the same value,
+        // just at a different (both still exact) log2_denom
+        let value = DFI::new(bi(7), bi(19), 3);
+        let round_tripped = (value.clone() << 5) >> 5;
+        assert!(round_tripped.is_same(&value.into_converted_log2_denom(8)));
+    }
+
     #[test]
     fn test_div_int() {
         fn test_case(lhs: DFI, rhs: BigInt, expected: DFI) {
@@ -2526,6 +4464,12 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_ln() {
+        let input = DFI::from_ratio_range(r(4, 5), r(123, 45), 64);
+        assert_same!(input.ln(), input.log());
+    }
+
     #[test]
     fn test_exp() {
         assert_same!(
@@ -2631,4 +4575,87 @@ mod tests {
             );
         }
     }
+
+    /// checks that `result` contains `expected` and is tighter than
+    /// `2^-(log2_denom - slack_bits)`, used by the transcendental function
+    /// tests since their exact rigorous bounds don't line up bit-for-bit
+    /// with a `f64`-derived reference the way the rational-only tests above do
+    fn check_transcendental_result(result: &DFI, expected: f64, log2_denom: usize, slack_bits: usize) {
+        dbg!(&result, expected);
+        assert!(result.contains_ratio(&Ratio::<BigInt>::from_float(expected).unwrap()));
+        assert!(result.is_tighter_than(log2_denom - slack_bits));
+    }
+
+    #[test]
+    fn test_pi() {
+        check_transcendental_result(&DFI::pi(32), std::f64::consts::PI, 32, 8);
+    }
+
+    #[test]
+    fn test_atan() {
+        fn do_test(x: f64, log2_denom: usize) {
+            println!("x = {}", x);
+            let input = DFI::from_ratio(Ratio::<BigInt>::from_float(x).unwrap(), log2_denom);
+            check_transcendental_result(&input.atan(), x.atan(), log2_denom, 8);
+        }
+        for &x in &[
+            0.0, 0.125, 0.25, 0.5, 0.75, 1.0, 1.5, 2.0, 5.0, 123.456, -0.5, -1.0, -5.0, -123.456,
+        ] {
+            do_test(x, 32);
+        }
+    }
+
+    #[test]
+    fn test_sin_cos() {
+        fn do_test(x: f64, log2_denom: usize) {
+            println!("x = {}", x);
+            let input = DFI::from_ratio(Ratio::<BigInt>::from_float(x).unwrap(), log2_denom);
+            check_transcendental_result(&input.sin(), x.sin(), log2_denom, 8);
+            check_transcendental_result(&input.cos(), x.cos(), log2_denom, 8);
+        }
+        for &x in &[
+            0.0,
+            0.5,
+            1.0,
+            std::f64::consts::FRAC_PI_4,
+            std::f64::consts::FRAC_PI_2,
+            std::f64::consts::PI,
+            2.0 * std::f64::consts::PI,
+            -1.0,
+            -std::f64::consts::PI,
+            10.0,
+        ] {
+            do_test(x, 32);
+        }
+    }
+
+    #[test]
+    fn test_dyadic_ball_conversions() {
+        let interval = DFI::new(bi(3), bi(97), 1); // [1.5, 48.5]
+        let ball = DyadicBall::from_interval(&interval, 4);
+        assert!(ball.to_interval().contains_interval(&interval));
+        assert_eq!(ball.center(), Ratio::new(bi(25), bi(1)));
+    }
+
+    #[test]
+    fn test_dyadic_ball_add_sub() {
+        let a = DyadicBall::from_interval(&DFI::from_ratio(r(1, 3), 16), 16);
+        let b = DyadicBall::from_interval(&DFI::from_ratio(r(1, 7), 16), 16);
+        let a_interval = a.to_interval();
+        let b_interval = b.to_interval();
+        assert!((&a + &b).to_interval().contains_interval(&(&a_interval + &b_interval)));
+        assert!((&a - &b).to_interval().contains_interval(&(&a_interval - &b_interval)));
+    }
+
+    #[test]
+    fn test_dyadic_ball_mul() {
+        let a = DyadicBall::from_interval(&DFI::from_ratio(r(1, 3), 32), 32);
+        let b = DyadicBall::from_interval(&DFI::from_ratio(r(-2, 5), 32), 32);
+        let product = &a * &b;
+        // multiplying doesn't grow log2_denom, unlike DyadicFractionInterval
+        assert_eq!(product.log2_denom(), 32);
+        assert!(product
+            .to_interval()
+            .contains_interval(&(a.to_interval() * b.to_interval())));
+    }
 }
@@ -6,13 +6,18 @@ extern crate lazy_static;
 
 pub mod algebraic_numbers;
 pub(crate) mod array2d;
+pub mod inari_interop;
 pub mod interval_arithmetic;
 pub(crate) mod lattice;
 pub mod mod_int;
+pub mod number_field;
+pub mod p_adic;
 pub mod polynomial;
 pub mod prelude;
 pub mod python;
 pub(crate) mod quadratic_numbers;
+pub(crate) mod rns;
+pub mod rug_interop;
 pub mod traits;
 pub mod util;
 
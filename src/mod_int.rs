@@ -2,24 +2,28 @@
 // See Notices.txt for copyright information
 
 use crate::{
-    polynomial::{DivisorIsOne, PolynomialCoefficient, PolynomialReducingFactorSupported},
+    polynomial::{DivisorIsOne, Polynomial, PolynomialCoefficient, PolynomialReducingFactorSupported},
     traits::{
         AlwaysExactDiv, AlwaysExactDivAssign, ExactDiv, ExactDivAssign, ExtendedGCD,
         ExtendedGCDResult, GCD,
     },
-    util::BaseAndExponent,
+    util::{BaseAndExponent, IsPseudoPrime},
 };
 use num_bigint::{BigInt, BigUint};
 use num_integer::Integer;
 use num_traits::{
-    CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, FromPrimitive, One, ToPrimitive, Zero,
+    CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, FromPrimitive, Inv, One, Signed, ToPrimitive,
+    Zero,
 };
 use std::{
     borrow::{Borrow, Cow},
     convert::{identity, TryInto},
     fmt,
     hash::Hash,
-    ops::{Add, AddAssign, Deref, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    ops::{
+        Add, AddAssign, Deref, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub,
+        SubAssign,
+    },
 };
 
 pub trait ModularReduce: Clone + Eq {
@@ -194,6 +198,19 @@ pub trait StaticModulus<Value: Clone + Eq>: Modulus<Value> + 'static + Copy + De
 
 pub trait PrimePowerModulus<Value: Integer + Clone>: Modulus<Value> {
     fn base_and_exponent(&self) -> BaseAndExponent<Value>;
+    /// the order of the multiplicative group of units modulo this modulus,
+    /// i.e. `euler_phi(base) * base.pow(exponent - 1)`
+    fn unit_group_order(&self) -> Value
+    where
+        Value: crate::util::IsPseudoPrime,
+    {
+        let BaseAndExponent { base, exponent } = self.base_and_exponent();
+        let mut order = crate::util::euler_phi(&base);
+        for _ in 1..exponent {
+            order = order * base.clone();
+        }
+        order
+    }
 }
 
 pub trait PrimeModulus<Value: Integer + Clone>: PrimePowerModulus<Value> {}
@@ -346,6 +363,61 @@ impl<T: fmt::Display> fmt::Display for KnownOdd<T> {
     }
 }
 
+#[derive(Copy, Clone, Hash, Eq, PartialEq, Debug, Default)]
+pub struct NotPrime;
+
+impl fmt::Display for NotPrime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "value is not prime")
+    }
+}
+
+impl std::error::Error for NotPrime {}
+
+/// a modulus whose primality is checked (using a probabilistic primality
+/// test) at construction time rather than being asserted by the caller,
+/// unlike [`KnownPrime`]
+#[derive(Copy, Clone, Hash, Eq, PartialEq, Debug)]
+pub struct VerifiedPrimeModulus<V>(V);
+
+impl<V: IsPseudoPrime> VerifiedPrimeModulus<V> {
+    pub fn new(value: V) -> Result<Self, NotPrime> {
+        if value.is_pseudo_prime() {
+            Ok(VerifiedPrimeModulus(value))
+        } else {
+            Err(NotPrime)
+        }
+    }
+    pub fn into_inner(self) -> V {
+        self.0
+    }
+}
+
+impl<V: Clone + Eq> Modulus<V> for VerifiedPrimeModulus<V> {
+    #[inline]
+    fn to_modulus(&self) -> Cow<V> {
+        Cow::Borrowed(&self.0)
+    }
+}
+
+impl<V: Integer + Clone> PrimePowerModulus<V> for VerifiedPrimeModulus<V> {
+    #[inline]
+    fn base_and_exponent(&self) -> BaseAndExponent<V> {
+        BaseAndExponent {
+            base: self.0.clone(),
+            exponent: 1,
+        }
+    }
+}
+
+impl<V: Integer + Clone> PrimeModulus<V> for VerifiedPrimeModulus<V> {}
+
+impl<T: fmt::Display> fmt::Display for VerifiedPrimeModulus<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
 macro_rules! impl_int_modulus {
     ($t:ty, $wide:ty, $to_wide:expr, $from_wide:expr, $from_bigint:ident) => {
         impl Modulus<Self> for $t {
@@ -664,6 +736,63 @@ impl<V, M: PartialEq> ModularInteger<V, M> {
     }
 }
 
+impl<V: ModularReduce + Eq, M: Modulus<V>> ModularInteger<V, M> {
+    /// like `self + rhs`, but skips checking that the moduli match in
+    /// release builds, where comparing the modulus (e.g. a `BigInt`) can be
+    /// a measurable cost in tight loops; still checked with `debug_assert!`
+    ///
+    /// # Safety
+    ///
+    /// not actually unsafe in the memory-safety sense; the `_unchecked`
+    /// naming just signals that calling this with mismatched moduli
+    /// produces a meaningless result instead of panicking
+    pub fn add_unchecked(&self, rhs: &Self) -> Self {
+        debug_assert!(self.has_matching_moduli(rhs), "moduli don't match");
+        ModularInteger {
+            value: self.value.modular_add_ref_ref(&rhs.value, &self.modulus),
+            modulus: self.modulus.clone(),
+        }
+    }
+    /// like `self - rhs`, but skips checking that the moduli match in
+    /// release builds; see [`Self::add_unchecked`] for details
+    pub fn sub_unchecked(&self, rhs: &Self) -> Self {
+        debug_assert!(self.has_matching_moduli(rhs), "moduli don't match");
+        ModularInteger {
+            value: self.value.modular_sub_ref_ref(&rhs.value, &self.modulus),
+            modulus: self.modulus.clone(),
+        }
+    }
+    /// like `self * rhs`, but skips checking that the moduli match in
+    /// release builds; see [`Self::add_unchecked`] for details
+    pub fn mul_unchecked(&self, rhs: &Self) -> Self {
+        debug_assert!(self.has_matching_moduli(rhs), "moduli don't match");
+        ModularInteger {
+            value: self.value.modular_mul_ref_ref(&rhs.value, &self.modulus),
+            modulus: self.modulus.clone(),
+        }
+    }
+}
+
+/// the error returned by the `try_*` arithmetic methods on [`ModularInteger`]
+/// when the two operands don't share the same modulus
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ModulusMismatchError<M> {
+    pub lhs_modulus: M,
+    pub rhs_modulus: M,
+}
+
+impl<M: fmt::Debug> fmt::Display for ModulusMismatchError<M> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "moduli don't match: {:?} != {:?}",
+            self.lhs_modulus, self.rhs_modulus
+        )
+    }
+}
+
+impl<M: fmt::Debug> std::error::Error for ModulusMismatchError<M> {}
+
 impl<V, M> Into<(V, M)> for ModularInteger<V, M> {
     fn into(self) -> (V, M) {
         (self.value, self.modulus)
@@ -809,6 +938,48 @@ impl<'l, 'r, V: ModularReduce + Eq, M: Modulus<V>> Add<&'r ModularInteger<V, M>>
     }
 }
 
+impl<V: ModularReduce + Eq, M: Modulus<V>> ModularInteger<V, M> {
+    fn mismatch_error(&self, rhs: &Self) -> ModulusMismatchError<M> {
+        ModulusMismatchError {
+            lhs_modulus: self.modulus.clone(),
+            rhs_modulus: rhs.modulus.clone(),
+        }
+    }
+    /// like `self + rhs`, but returns a [`ModulusMismatchError`] instead of panicking
+    /// when the moduli don't match
+    pub fn try_add(&self, rhs: &Self) -> Result<Self, ModulusMismatchError<M>> {
+        if !self.has_matching_moduli(rhs) {
+            return Err(self.mismatch_error(rhs));
+        }
+        Ok(ModularInteger {
+            value: self.value.modular_add_ref_ref(&rhs.value, &self.modulus),
+            modulus: self.modulus.clone(),
+        })
+    }
+    /// like `self - rhs`, but returns a [`ModulusMismatchError`] instead of panicking
+    /// when the moduli don't match
+    pub fn try_sub(&self, rhs: &Self) -> Result<Self, ModulusMismatchError<M>> {
+        if !self.has_matching_moduli(rhs) {
+            return Err(self.mismatch_error(rhs));
+        }
+        Ok(ModularInteger {
+            value: self.value.modular_sub_ref_ref(&rhs.value, &self.modulus),
+            modulus: self.modulus.clone(),
+        })
+    }
+    /// like `self * rhs`, but returns a [`ModulusMismatchError`] instead of panicking
+    /// when the moduli don't match
+    pub fn try_mul(&self, rhs: &Self) -> Result<Self, ModulusMismatchError<M>> {
+        if !self.has_matching_moduli(rhs) {
+            return Err(self.mismatch_error(rhs));
+        }
+        Ok(ModularInteger {
+            value: self.value.modular_mul_ref_ref(&rhs.value, &self.modulus),
+            modulus: self.modulus.clone(),
+        })
+    }
+}
+
 impl<V: ModularReduce + Eq, M: Modulus<V>> CheckedAdd for ModularInteger<V, M> {
     fn checked_add(&self, rhs: &Self) -> Option<Self> {
         if !self.has_matching_moduli(rhs) {
@@ -1016,155 +1187,1009 @@ impl<V: ModularReduce + Eq + One + Zero + GCD<Output = V> + ExtendedGCD, M: Modu
         self.checked_inverse()
             .expect("value has no modular inverse")
     }
+    /// like `self / rhs`, but returns a [`ModulusMismatchError`] instead of panicking
+    /// when the moduli don't match; still panics if `rhs` has no modular inverse
+    pub fn try_div(&self, rhs: &Self) -> Result<Self, ModulusMismatchError<M>> {
+        if !self.has_matching_moduli(rhs) {
+            return Err(ModulusMismatchError {
+                lhs_modulus: self.modulus.clone(),
+                rhs_modulus: rhs.modulus.clone(),
+            });
+        }
+        let rhs_inverse = rhs.inverse();
+        Ok(ModularInteger {
+            value: self.value.modular_mul_ref_ref(&rhs_inverse.value, &self.modulus),
+            modulus: self.modulus.clone(),
+        })
+    }
 }
 
-impl<V: ModularReduce + Eq + One + Zero + GCD<Output = V> + ExtendedGCD, M: Modulus<V>> DivAssign
+impl<V: ModularReduce + Eq + One + Zero + GCD<Output = V> + ExtendedGCD, M: Modulus<V>> Inv
     for ModularInteger<V, M>
 {
-    fn div_assign(&mut self, rhs: ModularInteger<V, M>) {
-        self.mul_assign(rhs.inverse())
+    type Output = ModularInteger<V, M>;
+    fn inv(self) -> Self::Output {
+        self.inverse()
     }
 }
 
-impl<V: ModularReduce + Eq + One + Zero + GCD<Output = V> + ExtendedGCD, M: Modulus<V>>
-    DivAssign<&'_ ModularInteger<V, M>> for ModularInteger<V, M>
+impl<'a, V: ModularReduce + Eq + One + Zero + GCD<Output = V> + ExtendedGCD, M: Modulus<V>> Inv
+    for &'a ModularInteger<V, M>
 {
-    fn div_assign(&mut self, rhs: &ModularInteger<V, M>) {
-        self.mul_assign(rhs.inverse())
+    type Output = ModularInteger<V, M>;
+    fn inv(self) -> Self::Output {
+        self.inverse()
     }
 }
 
-impl<V: ModularReduce + Eq + One + Zero + GCD<Output = V> + ExtendedGCD, M: Modulus<V>> Div
-    for ModularInteger<V, M>
+impl<
+        V: ModularReducePow<BigInt> + Eq + One + Zero + GCD<Output = V> + ExtendedGCD,
+        M: Modulus<V>,
+    > ModularInteger<V, M>
 {
-    type Output = ModularInteger<V, M>;
-    fn div(self, rhs: ModularInteger<V, M>) -> ModularInteger<V, M> {
-        self.mul(rhs.inverse())
+    /// raises `self` to `exponent`, allowing negative exponents by first
+    /// taking the modular inverse; returns `None` if `exponent` is negative
+    /// and `self` has no modular inverse
+    pub fn checked_pow(&self, exponent: &BigInt) -> Option<Self> {
+        if exponent.is_negative() {
+            self.checked_inverse()?.checked_pow(&-exponent)
+        } else {
+            Some(ModularInteger::new(
+                self.value.pow_modular_reduce(exponent, &self.modulus),
+                self.modulus.clone(),
+            ))
+        }
     }
 }
 
-impl<V: ModularReduce + Eq + One + Zero + GCD<Output = V> + ExtendedGCD, M: Modulus<V>>
-    Div<ModularInteger<V, M>> for &'_ ModularInteger<V, M>
-{
-    type Output = ModularInteger<V, M>;
-    fn div(self, rhs: ModularInteger<V, M>) -> ModularInteger<V, M> {
-        self.mul(&rhs.inverse())
+impl<V: ModularReduce + Eq + One, M: Modulus<V> + Clone> ModularInteger<V, M> {
+    /// raises `self` to the power given by `exponent_bits_msb_first`, one
+    /// bit at a time, using square-and-multiply
+    ///
+    /// unlike `pow_modular_reduce`, which repeatedly clones and halves the
+    /// whole exponent, this reads the exponent bit by bit and never
+    /// allocates or does arithmetic on the exponent itself, which matters
+    /// when the exponent has millions of bits
+    pub fn pow_modular_reduce_bits<I: IntoIterator<Item = bool>>(
+        &self,
+        exponent_bits_msb_first: I,
+    ) -> Self {
+        let mut retval = ModularInteger::new(V::one(), self.modulus.clone());
+        for bit in exponent_bits_msb_first {
+            retval = &retval * &retval;
+            if bit {
+                retval = &retval * self;
+            }
+        }
+        retval
+    }
+    /// like [`Self::pow_modular_reduce_bits`] but reads the exponent's bits
+    /// directly out of a [`BigUint`] instead of requiring the caller to
+    /// build a bit iterator themselves
+    pub fn pow_modular_reduce_biguint(&self, exponent: &BigUint) -> Self {
+        let bit_count = exponent.bits();
+        self.pow_modular_reduce_bits((0..bit_count).rev().map(|index| exponent.bit(index)))
     }
 }
 
-impl<V: ModularReduce + Eq + One + Zero + GCD<Output = V> + ExtendedGCD, M: Modulus<V>>
-    Div<&'_ ModularInteger<V, M>> for ModularInteger<V, M>
-{
-    type Output = ModularInteger<V, M>;
-    fn div(self, rhs: &ModularInteger<V, M>) -> ModularInteger<V, M> {
-        self.mul(rhs.inverse())
-    }
+lazy_static! {
+    /// caches, per `StaticModulus` type, a table mapping each residue to
+    /// its modular inverse (or `0` if it has none), for use by
+    /// [`ModularInteger::table_checked_inverse`]
+    static ref STATIC_MODULUS_INVERSE_TABLES: std::sync::Mutex<
+        std::collections::HashMap<std::any::TypeId, std::sync::Arc<Vec<u16>>>,
+    > = std::sync::Mutex::new(std::collections::HashMap::new());
 }
 
-impl<'a, 'b, V: ModularReduce + Eq + One + Zero + GCD<Output = V> + ExtendedGCD, M: Modulus<V>>
-    Div<&'a ModularInteger<V, M>> for &'b ModularInteger<V, M>
+impl<
+        V: ModularReduce + Eq + One + Zero + GCD<Output = V> + ExtendedGCD + ToPrimitive + FromPrimitive,
+        M: StaticModulus<V>,
+    > ModularInteger<V, M>
 {
-    type Output = ModularInteger<V, M>;
-    fn div(self, rhs: &ModularInteger<V, M>) -> ModularInteger<V, M> {
-        self.mul(&rhs.inverse())
+    /// like [`Self::checked_inverse`], but for `StaticModulus` types with a
+    /// modulus of at most `2^16`, uses a lookup table computed once per
+    /// modulus type instead of running the extended Euclidean algorithm on
+    /// every call; GF(2), GF(3), and GF(5) polynomial arithmetic is common
+    /// enough that this pays for itself quickly
+    ///
+    /// # Panics
+    ///
+    /// panics if the modulus is greater than `2^16`
+    pub fn table_checked_inverse(&self) -> Option<Self> {
+        let table = STATIC_MODULUS_INVERSE_TABLES
+            .lock()
+            .expect("lock poisoned")
+            .entry(std::any::TypeId::of::<M>())
+            .or_insert_with(|| {
+                let modulus = M::get_modulus();
+                let modulus_usize = modulus
+                    .to_usize()
+                    .filter(|&m| m <= usize::from(u16::MAX) + 1)
+                    .expect("modulus too large for a lookup table");
+                let table = (0..modulus_usize)
+                    .map(|residue| {
+                        if residue == 0 {
+                            return 0;
+                        }
+                        let value = V::from_usize(residue).expect("residue fits in V");
+                        let ExtendedGCDResult { gcd, x, .. } = value.extended_gcd(&modulus);
+                        if gcd.is_one() {
+                            ModularInteger::<V, M>::new(x, M::default())
+                                .value()
+                                .to_u16()
+                                .expect("inverse fits in a u16")
+                        } else {
+                            0
+                        }
+                    })
+                    .collect();
+                std::sync::Arc::new(table)
+            })
+            .clone();
+        let index = self.value.to_usize().expect("value fits in a usize");
+        match table[index] {
+            0 => None,
+            inverse => Some(ModularInteger::new(
+                V::from_u16(inverse).expect("inverse fits in V"),
+                self.modulus.clone(),
+            )),
+        }
+    }
+    /// like [`Self::table_checked_inverse`] but panics instead of returning
+    /// `None` when `self` has no modular inverse
+    pub fn table_inverse(&self) -> Self {
+        self.table_checked_inverse()
+            .expect("value has no modular inverse")
     }
 }
 
-impl<V: ModularReduce + Eq + One + Zero + GCD<Output = V> + ExtendedGCD, M: Modulus<V>> CheckedDiv
-    for ModularInteger<V, M>
-{
-    fn checked_div(&self, rhs: &Self) -> Option<Self> {
-        self.checked_mul(&rhs.checked_inverse()?)
-    }
+/// a dense matrix of [`ModularInteger`]s, stored in row-major order
+///
+/// requires a prime modulus so every nonzero element is invertible, which
+/// Gaussian elimination relies on for computing rank, determinant, the
+/// inverse, a basis for the kernel, and solutions to linear systems
+#[derive(Clone, Debug)]
+pub struct ModularMatrix<V, M> {
+    rows: usize,
+    cols: usize,
+    modulus: M,
+    elements: Vec<ModularInteger<V, M>>,
 }
 
-macro_rules! impl_exact_div {
-    (($($lifetimes:tt),*), $v:ident, $m:ident, $lhs:ty, $rhs:ty) => {
-        impl<$($lifetimes,)* $v, $m> ExactDiv<$rhs> for $lhs
-        where
-            $v: ModularReduce + Eq + One + Zero + GCD<Output = $v> + ExtendedGCD, $m: Modulus<$v>
-        {
-            type Output = ModularInteger<$v, $m>;
-            fn exact_div(self, rhs: $rhs) -> Self::Output {
-                self.div(rhs)
+impl<
+        V: ModularReduce + Eq + Zero + One + Clone + GCD<Output = V> + ExtendedGCD + Integer,
+        M: PrimeModulus<V> + Clone,
+    > ModularMatrix<V, M>
+{
+    /// constructs a matrix from `elements` in row-major order
+    pub fn new(rows: usize, cols: usize, modulus: M, elements: Vec<ModularInteger<V, M>>) -> Self {
+        assert_eq!(
+            rows * cols,
+            elements.len(),
+            "wrong number of elements for matrix size"
+        );
+        Self {
+            rows,
+            cols,
+            modulus,
+            elements,
+        }
+    }
+    /// constructs a `rows` by `cols` matrix with every element set to zero
+    pub fn zero(rows: usize, cols: usize, modulus: M) -> Self {
+        let element = ModularInteger::new(V::zero(), modulus.clone());
+        Self::new(rows, cols, modulus, vec![element; rows * cols])
+    }
+    /// constructs the `size` by `size` identity matrix
+    pub fn identity(size: usize, modulus: M) -> Self {
+        let mut retval = Self::zero(size, size, modulus.clone());
+        for i in 0..size {
+            *retval.get_mut(i, i) = ModularInteger::new(V::one(), modulus.clone());
+        }
+        retval
+    }
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+    pub fn modulus(&self) -> &M {
+        &self.modulus
+    }
+    pub fn get(&self, row: usize, col: usize) -> &ModularInteger<V, M> {
+        &self.elements[row * self.cols + col]
+    }
+    pub fn get_mut(&mut self, row: usize, col: usize) -> &mut ModularInteger<V, M> {
+        &mut self.elements[row * self.cols + col]
+    }
+    fn swap_rows(&mut self, row1: usize, row2: usize) {
+        if row1 == row2 {
+            return;
+        }
+        for col in 0..self.cols {
+            self.elements.swap(row1 * self.cols + col, row2 * self.cols + col);
+        }
+    }
+    /// row-reduces `self` in place to reduced row echelon form, only
+    /// searching for pivots in the first `pivot_cols_limit` columns (the
+    /// remaining columns, if any, are augmented columns such as an
+    /// identity matrix or a right-hand side that are carried along but
+    /// never used to determine rank); returns the pivot column of each
+    /// pivot row in order, so the number of pivot rows found is the rank
+    fn row_reduce(&mut self, pivot_cols_limit: usize) -> Vec<usize> {
+        let mut pivot_cols = Vec::new();
+        let mut pivot_row = 0;
+        for col in 0..pivot_cols_limit {
+            if pivot_row >= self.rows {
+                break;
             }
-            fn checked_exact_div(self, rhs: $rhs) -> Option<Self::Output> {
-                self.checked_div(rhs.borrow())
+            let found_row =
+                (pivot_row..self.rows).find(|&row| !self.get(row, col).value().is_zero());
+            let found_row = match found_row {
+                Some(found_row) => found_row,
+                None => continue,
+            };
+            self.swap_rows(pivot_row, found_row);
+            let pivot_inverse = self.get(pivot_row, col).inverse();
+            for c in 0..self.cols {
+                let value = self.get(pivot_row, c) * &pivot_inverse;
+                *self.get_mut(pivot_row, c) = value;
+            }
+            for row in 0..self.rows {
+                if row == pivot_row || self.get(row, col).value().is_zero() {
+                    continue;
+                }
+                let factor = self.get(row, col).clone();
+                for c in 0..self.cols {
+                    let subtrahend = &factor * self.get(pivot_row, c);
+                    let value = self.get(row, c) - &subtrahend;
+                    *self.get_mut(row, c) = value;
+                }
             }
+            pivot_cols.push(col);
+            pivot_row += 1;
         }
-
-        impl<$($lifetimes,)* $v, $m> AlwaysExactDiv<$rhs> for $lhs
-        where
-            $v: ModularReduce + Integer + GCD<Output = $v> + ExtendedGCD,
-            $m: PrimeModulus<$v>,
-        {
+        pivot_cols
+    }
+    /// the rank of `self`, i.e. the number of linearly independent rows
+    pub fn rank(&self) -> usize {
+        let cols = self.cols;
+        self.clone().row_reduce(cols).len()
+    }
+    /// the determinant of `self`; only defined for square matrices
+    pub fn determinant(&self) -> ModularInteger<V, M> {
+        assert_eq!(
+            self.rows, self.cols,
+            "determinant is only defined for square matrices"
+        );
+        let mut working = self.clone();
+        let mut result = ModularInteger::new(V::one(), self.modulus.clone());
+        for col in 0..self.cols {
+            let found_row =
+                (col..self.rows).find(|&row| !working.get(row, col).value().is_zero());
+            let found_row = match found_row {
+                Some(found_row) => found_row,
+                None => return ModularInteger::new(V::zero(), self.modulus.clone()),
+            };
+            if found_row != col {
+                working.swap_rows(found_row, col);
+                result = -result;
+            }
+            result = &result * working.get(col, col);
+            let pivot_inverse = working.get(col, col).inverse();
+            for row in (col + 1)..self.rows {
+                if working.get(row, col).value().is_zero() {
+                    continue;
+                }
+                let factor = working.get(row, col) * &pivot_inverse;
+                for c in col..self.cols {
+                    let subtrahend = &factor * working.get(col, c);
+                    let value = working.get(row, c) - &subtrahend;
+                    *working.get_mut(row, c) = value;
+                }
+            }
         }
-    };
-    (assign ($($lifetimes:tt),*), $v:ident, $m:ident, $lhs:ty, $rhs:ty) => {
-        impl_exact_div!(($($lifetimes),*), $v, $m, $lhs, $rhs);
-
-        impl<$($lifetimes,)* $v, $m> ExactDivAssign<$rhs> for $lhs
-        where
-            $v: ModularReduce + Eq + One + Zero + GCD<Output = $v> + ExtendedGCD, $m: Modulus<$v>
-        {
-            fn exact_div_assign(&mut self, rhs: $rhs) {
-                self.div_assign(rhs);
+        result
+    }
+    /// the multiplicative inverse of `self`, or `None` if `self` is singular;
+    /// only defined for square matrices
+    pub fn try_inverse(&self) -> Option<Self> {
+        assert_eq!(
+            self.rows, self.cols,
+            "inverse is only defined for square matrices"
+        );
+        let size = self.rows;
+        let mut augmented = ModularMatrix::zero(size, size * 2, self.modulus.clone());
+        for row in 0..size {
+            for col in 0..size {
+                *augmented.get_mut(row, col) = self.get(row, col).clone();
             }
-            fn checked_exact_div_assign(&mut self, rhs: $rhs) -> Result<(), ()> {
-                (&*self)
-                    .checked_exact_div(rhs)
-                    .map(|v| {
-                        *self = v;
-                    })
-                    .ok_or(())
+            *augmented.get_mut(row, size + row) = ModularInteger::new(V::one(), self.modulus.clone());
+        }
+        let pivot_cols = augmented.row_reduce(size);
+        if pivot_cols.len() != size {
+            return None;
+        }
+        let mut result = ModularMatrix::zero(size, size, self.modulus.clone());
+        for row in 0..size {
+            for col in 0..size {
+                *result.get_mut(row, col) = augmented.get(row, size + col).clone();
             }
         }
-
-        impl<$($lifetimes,)* $v, $m> AlwaysExactDivAssign<$rhs> for $lhs
-        where
-            $v: ModularReduce + Integer + GCD<Output = $v> + ExtendedGCD,
-            $m: PrimeModulus<$v>,
-        {
+        Some(result)
+    }
+    /// a basis for the kernel (null space) of `self`, i.e. the set of vectors
+    /// `x` such that `self * x == 0`; each basis vector is returned as a
+    /// `Vec` of length `self.cols()`
+    pub fn kernel_basis(&self) -> Vec<Vec<ModularInteger<V, M>>> {
+        let mut working = self.clone();
+        let cols = self.cols;
+        let pivot_cols = working.row_reduce(cols);
+        let free_cols = (0..self.cols).filter(|col| !pivot_cols.contains(col));
+        free_cols
+            .map(|free_col| {
+                let mut vector =
+                    vec![ModularInteger::new(V::zero(), self.modulus.clone()); self.cols];
+                vector[free_col] = ModularInteger::new(V::one(), self.modulus.clone());
+                for (pivot_row, &pivot_col) in pivot_cols.iter().enumerate() {
+                    vector[pivot_col] = -working.get(pivot_row, free_col).clone();
+                }
+                vector
+            })
+            .collect()
+    }
+    /// solves the linear system `self * x == right_hand_side` for `x`,
+    /// returning one solution if any exist, or `None` if the system is
+    /// inconsistent
+    pub fn solve(
+        &self,
+        right_hand_side: &[ModularInteger<V, M>],
+    ) -> Option<Vec<ModularInteger<V, M>>> {
+        assert_eq!(right_hand_side.len(), self.rows);
+        let mut augmented = ModularMatrix::zero(self.rows, self.cols + 1, self.modulus.clone());
+        for row in 0..self.rows {
+            for col in 0..self.cols {
+                *augmented.get_mut(row, col) = self.get(row, col).clone();
+            }
+            *augmented.get_mut(row, self.cols) = right_hand_side[row].clone();
         }
-    };
+        let pivot_cols = augmented.row_reduce(self.cols);
+        for row in pivot_cols.len()..self.rows {
+            if !augmented.get(row, self.cols).value().is_zero() {
+                return None;
+            }
+        }
+        let mut solution =
+            vec![ModularInteger::new(V::zero(), self.modulus.clone()); self.cols];
+        for (pivot_row, &pivot_col) in pivot_cols.iter().enumerate() {
+            solution[pivot_col] = augmented.get(pivot_row, self.cols).clone();
+        }
+        Some(solution)
+    }
 }
 
-impl_exact_div!(assign (), V, M, ModularInteger<V, M>, ModularInteger<V, M>);
-impl_exact_div!(assign ('r), V, M, ModularInteger<V, M>, &'r ModularInteger<V, M>);
-impl_exact_div!(('l), V, M, &'l ModularInteger<V, M>, ModularInteger<V, M>);
-impl_exact_div!(('l, 'r), V, M, &'l ModularInteger<V, M>, &'r ModularInteger<V, M>);
+impl<V, M> Index<(usize, usize)> for ModularMatrix<V, M> {
+    type Output = ModularInteger<V, M>;
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        &self.elements[row * self.cols + col]
+    }
+}
 
-impl<V, M> PolynomialCoefficient for ModularInteger<V, M>
-where
-    V: ModularReducePow<usize> + Eq + One + Zero + fmt::Debug + Hash,
-    M: Modulus<V> + fmt::Debug + Hash,
-{
-    type Element = Self;
-    type Divisor = DivisorIsOne;
-    const NESTING_DEPTH: usize = 0;
-    fn is_element_zero(element: &Self::Element) -> bool {
-        element.value.is_zero()
+impl<V, M> IndexMut<(usize, usize)> for ModularMatrix<V, M> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        &mut self.elements[row * self.cols + col]
     }
-    fn is_element_one(element: &Self::Element) -> bool {
-        element.value.is_one()
+}
+
+/// a dense vector of values sharing a single modulus, stored as a plain
+/// `Vec<V>` of already-reduced residues rather than a `Vec` of
+/// [`ModularInteger`]s
+///
+/// this avoids storing the modulus once per element, which matters for
+/// large arrays such as polynomial coefficient vectors where the modulus
+/// is otherwise duplicated needlessly and defeats cache locality
+#[derive(Clone, Debug)]
+pub struct ModularVec<V, M> {
+    modulus: M,
+    values: Vec<V>,
+}
+
+impl<V: ModularReduce + Zero + Clone, M: Modulus<V> + Clone> ModularVec<V, M> {
+    /// constructs a vector from `values`, reducing each one modulo `modulus`
+    pub fn new(modulus: M, values: Vec<V>) -> Self {
+        let values = values
+            .into_iter()
+            .map(|value| value.modular_reduce(&modulus))
+            .collect();
+        Self { modulus, values }
+    }
+    /// constructs a vector of `len` zeros
+    pub fn zero(len: usize, modulus: M) -> Self {
+        Self {
+            values: vec![V::zero(); len],
+            modulus,
+        }
     }
-    fn is_coefficient_zero(coefficient: &Self) -> bool {
-        coefficient.value.is_zero()
+    pub fn len(&self) -> usize {
+        self.values.len()
     }
-    fn is_coefficient_one(coefficient: &Self) -> bool {
-        coefficient.value.is_one()
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
     }
-    fn set_element_zero(element: &mut Self::Element) {
-        element.value.set_zero();
+    pub fn modulus(&self) -> &M {
+        &self.modulus
     }
-    fn set_element_one(element: &mut Self::Element) {
-        element.value = V::modular_reduce(V::one(), &element.modulus);
+    /// the residues making up `self`; each is in `0..modulus`
+    pub fn values(&self) -> &[V] {
+        &self.values
     }
-    fn set_coefficient_zero(coefficient: &mut Self) {
-        Self::set_element_zero(coefficient);
+    pub fn get(&self, index: usize) -> ModularInteger<V, M> {
+        ModularInteger::new(self.values[index].clone(), self.modulus.clone())
     }
-    fn set_coefficient_one(coefficient: &mut Self) {
-        Self::set_element_one(coefficient);
+    pub fn set(&mut self, index: usize, value: ModularInteger<V, M>) {
+        assert!(
+            value.modulus() == &self.modulus,
+            "modulus mismatch in ModularVec::set"
+        );
+        self.values[index] = value.value().clone();
+    }
+    fn assert_same_length_and_modulus(&self, rhs: &Self) {
+        assert!(self.modulus == rhs.modulus, "modulus mismatch");
+        assert_eq!(self.len(), rhs.len(), "length mismatch");
+    }
+    /// elementwise addition
+    pub fn add(&self, rhs: &Self) -> Self {
+        self.assert_same_length_and_modulus(rhs);
+        let values = self
+            .values
+            .iter()
+            .zip(&rhs.values)
+            .map(|(a, b)| a.modular_add_ref_ref(b, &self.modulus))
+            .collect();
+        Self {
+            modulus: self.modulus.clone(),
+            values,
+        }
+    }
+    /// elementwise subtraction
+    pub fn sub(&self, rhs: &Self) -> Self {
+        self.assert_same_length_and_modulus(rhs);
+        let values = self
+            .values
+            .iter()
+            .zip(&rhs.values)
+            .map(|(a, b)| a.modular_sub_ref_ref(b, &self.modulus))
+            .collect();
+        Self {
+            modulus: self.modulus.clone(),
+            values,
+        }
+    }
+    /// elementwise multiplication
+    pub fn mul(&self, rhs: &Self) -> Self {
+        self.assert_same_length_and_modulus(rhs);
+        let values = self
+            .values
+            .iter()
+            .zip(&rhs.values)
+            .map(|(a, b)| a.modular_mul_ref_ref(b, &self.modulus))
+            .collect();
+        Self {
+            modulus: self.modulus.clone(),
+            values,
+        }
+    }
+    /// elementwise negation
+    pub fn neg(&self) -> Self {
+        let values = self
+            .values
+            .iter()
+            .map(|a| a.modular_neg_ref(&self.modulus))
+            .collect();
+        Self {
+            modulus: self.modulus.clone(),
+            values,
+        }
+    }
+    /// multiplies every element by `scalar`
+    pub fn scalar_mul(&self, scalar: &ModularInteger<V, M>) -> Self {
+        assert!(
+            scalar.modulus() == &self.modulus,
+            "modulus mismatch in ModularVec::scalar_mul"
+        );
+        let values = self
+            .values
+            .iter()
+            .map(|a| a.modular_mul_ref_ref(scalar.value(), &self.modulus))
+            .collect();
+        Self {
+            modulus: self.modulus.clone(),
+            values,
+        }
+    }
+    /// adds `scalar` to every element
+    pub fn scalar_add(&self, scalar: &ModularInteger<V, M>) -> Self {
+        assert!(
+            scalar.modulus() == &self.modulus,
+            "modulus mismatch in ModularVec::scalar_add"
+        );
+        let values = self
+            .values
+            .iter()
+            .map(|a| a.modular_add_ref_ref(scalar.value(), &self.modulus))
+            .collect();
+        Self {
+            modulus: self.modulus.clone(),
+            values,
+        }
+    }
+}
+
+impl<V, M> Index<usize> for ModularVec<V, M> {
+    type Output = V;
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.values[index]
+    }
+}
+
+macro_rules! impl_scalar_arithmetic {
+    ($t:ty, |$rhs:ident, $modulus:ident| $convert:expr) => {
+        impl<V: ModularReduce + Eq, M: Modulus<V>> Add<$t> for ModularInteger<V, M> {
+            type Output = ModularInteger<V, M>;
+            fn add(self, $rhs: $t) -> Self::Output {
+                let $modulus = &self.modulus;
+                let rhs = $convert;
+                ModularInteger {
+                    value: self.value.modular_add_move_move(rhs, &self.modulus),
+                    modulus: self.modulus,
+                }
+            }
+        }
+        impl<'l, V: ModularReduce + Eq, M: Modulus<V>> Add<$t> for &'l ModularInteger<V, M> {
+            type Output = ModularInteger<V, M>;
+            fn add(self, $rhs: $t) -> Self::Output {
+                let $modulus = &self.modulus;
+                let rhs = $convert;
+                ModularInteger {
+                    value: self.value.modular_add_ref_move(rhs, &self.modulus),
+                    modulus: self.modulus.clone(),
+                }
+            }
+        }
+        impl<V: ModularReduce + Eq, M: Modulus<V>> AddAssign<$t> for ModularInteger<V, M> {
+            fn add_assign(&mut self, $rhs: $t) {
+                let $modulus = &self.modulus;
+                let rhs = $convert;
+                self.value.modular_add_move_assign(rhs, &self.modulus);
+            }
+        }
+        impl<V: ModularReduce + Eq, M: Modulus<V>> Sub<$t> for ModularInteger<V, M> {
+            type Output = ModularInteger<V, M>;
+            fn sub(self, $rhs: $t) -> Self::Output {
+                let $modulus = &self.modulus;
+                let rhs = $convert;
+                ModularInteger {
+                    value: self.value.modular_sub_move_move(rhs, &self.modulus),
+                    modulus: self.modulus,
+                }
+            }
+        }
+        impl<'l, V: ModularReduce + Eq, M: Modulus<V>> Sub<$t> for &'l ModularInteger<V, M> {
+            type Output = ModularInteger<V, M>;
+            fn sub(self, $rhs: $t) -> Self::Output {
+                let $modulus = &self.modulus;
+                let rhs = $convert;
+                ModularInteger {
+                    value: self.value.modular_sub_ref_move(rhs, &self.modulus),
+                    modulus: self.modulus.clone(),
+                }
+            }
+        }
+        impl<V: ModularReduce + Eq, M: Modulus<V>> SubAssign<$t> for ModularInteger<V, M> {
+            fn sub_assign(&mut self, $rhs: $t) {
+                let $modulus = &self.modulus;
+                let rhs = $convert;
+                self.value.modular_sub_move_assign(rhs, &self.modulus);
+            }
+        }
+        impl<V: ModularReduce + Eq, M: Modulus<V>> Mul<$t> for ModularInteger<V, M> {
+            type Output = ModularInteger<V, M>;
+            fn mul(self, $rhs: $t) -> Self::Output {
+                let $modulus = &self.modulus;
+                let rhs = $convert;
+                ModularInteger {
+                    value: self.value.modular_mul_move_move(rhs, &self.modulus),
+                    modulus: self.modulus,
+                }
+            }
+        }
+        impl<'l, V: ModularReduce + Eq, M: Modulus<V>> Mul<$t> for &'l ModularInteger<V, M> {
+            type Output = ModularInteger<V, M>;
+            fn mul(self, $rhs: $t) -> Self::Output {
+                let $modulus = &self.modulus;
+                let rhs = $convert;
+                ModularInteger {
+                    value: self.value.modular_mul_ref_move(rhs, &self.modulus),
+                    modulus: self.modulus.clone(),
+                }
+            }
+        }
+        impl<V: ModularReduce + Eq, M: Modulus<V>> MulAssign<$t> for ModularInteger<V, M> {
+            fn mul_assign(&mut self, $rhs: $t) {
+                let $modulus = &self.modulus;
+                let rhs = $convert;
+                self.value.modular_mul_move_assign(rhs, &self.modulus);
+            }
+        }
+    };
+}
+
+impl_scalar_arithmetic!(u64, |rhs, modulus| V::modular_reduce_from_u64(rhs, modulus));
+impl_scalar_arithmetic!(i64, |rhs, modulus| V::modular_reduce_from_i64(rhs, modulus));
+impl_scalar_arithmetic!(BigInt, |rhs, modulus| V::modular_reduce_from_bigint(&rhs, modulus));
+
+/// computes `(U_k, V_k)` of the Lucas sequences defined by `U_0 = 0, U_1 = 1,
+/// U_{n+2} = p * U_{n+1} - q * U_n` and `V_0 = 2, V_1 = p, V_{n+2} = p *
+/// V_{n+1} - q * V_n`, modulo `p`'s and `q`'s shared modulus, using the fast
+/// doubling identities `U_{2n} = U_n * (2 * U_{n+1} - p * U_n)` and
+/// `U_{2n+1} = U_{n+1}^2 - q * U_n^2`
+pub fn lucas_uv<V: ModularReduce + Eq + Zero + One + Clone, M: Modulus<V> + Clone>(
+    p: &ModularInteger<V, M>,
+    q: &ModularInteger<V, M>,
+    k: &BigUint,
+) -> (ModularInteger<V, M>, ModularInteger<V, M>) {
+    let modulus = p.modulus.clone();
+    let mut u = ModularInteger::new(V::zero(), modulus.clone());
+    let mut u_next = ModularInteger::new(V::one(), modulus);
+    for i in (0..k.bits()).rev() {
+        let doubled_u = &u * (&u_next * 2u64 - p * &u);
+        let doubled_u_next = &u_next * &u_next - q * (&u * &u);
+        if k.bit(i) {
+            let incremented = p * &doubled_u_next - q * &doubled_u;
+            u = doubled_u_next;
+            u_next = incremented;
+        } else {
+            u = doubled_u;
+            u_next = doubled_u_next;
+        }
+    }
+    let v = &u_next * 2u64 - p * &u;
+    (u, v)
+}
+
+fn distinct_prime_factors_u64(mut n: u64) -> Vec<u64> {
+    let mut factors = Vec::new();
+    let mut divisor = 2;
+    while divisor * divisor <= n {
+        if n % divisor == 0 {
+            factors.push(divisor);
+            while n % divisor == 0 {
+                n /= divisor;
+            }
+        }
+        divisor += 1;
+    }
+    if n > 1 {
+        factors.push(n);
+    }
+    factors
+}
+
+impl<V: ModularReducePow<V> + Integer + Clone + ToPrimitive + FromPrimitive, M: PrimeModulus<V> + Clone>
+    ModularInteger<V, M>
+{
+    /// the multiplicative order of `self` modulo a prime, i.e. the smallest
+    /// positive `k` such that `self.pow(k) == 1`; returns `None` if `self`
+    /// is zero or the modulus is too large to factor `modulus - 1`
+    pub fn multiplicative_order(&self) -> Option<BigUint> {
+        if self.value.is_zero() {
+            return None;
+        }
+        let p = self.modulus.to_modulus().into_owned().to_u64()?;
+        let group_order = p.checked_sub(1)?;
+        let mut order = group_order;
+        for prime in distinct_prime_factors_u64(group_order) {
+            while order % prime == 0 {
+                let candidate = order / prime;
+                let candidate_v = V::from_u64(candidate)?;
+                if self
+                    .value
+                    .pow_modular_reduce(&candidate_v, &self.modulus)
+                    .is_one()
+                {
+                    order = candidate;
+                } else {
+                    break;
+                }
+            }
+        }
+        Some(BigUint::from(order))
+    }
+    /// tests whether `self` is a quadratic residue modulo a prime, i.e.
+    /// whether there exists `x` such that `x * x == self`; zero is
+    /// considered a quadratic residue; uses Euler's criterion
+    pub fn is_quadratic_residue(&self) -> bool {
+        if self.value.is_zero() {
+            return true;
+        }
+        let p = self.modulus.to_modulus().into_owned();
+        let two = V::one() + V::one();
+        let exponent = (p - V::one()) / two;
+        self.value
+            .pow_modular_reduce(&exponent, &self.modulus)
+            .is_one()
+    }
+    /// enumerates all quadratic residues modulo `modulus`, including zero;
+    /// returns `None` if the modulus is too large to convert to `u64`
+    pub fn quadratic_residues(modulus: M) -> Option<impl Iterator<Item = Self>> {
+        let p = modulus.to_modulus().into_owned().to_u64()?;
+        Some((0..p).filter_map(move |i| {
+            let value = ModularInteger::new(V::from_u64(i)?, modulus.clone());
+            if value.is_quadratic_residue() {
+                Some(value)
+            } else {
+                None
+            }
+        }))
+    }
+}
+
+fn pow_mod_u64(base: u64, mut exp: u64, modulus: u64) -> u64 {
+    let mut result = 1u128;
+    let mut base = u128::from(base) % u128::from(modulus);
+    let modulus = u128::from(modulus);
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exp >>= 1;
+    }
+    result as u64
+}
+
+fn mod_inverse_u64(a: u64, modulus: u64) -> Option<u64> {
+    let (mut old_r, mut r) = (a as i128, modulus as i128);
+    let (mut old_s, mut s) = (1i128, 0i128);
+    while r != 0 {
+        let quotient = old_r / r;
+        let new_r = old_r - quotient * r;
+        old_r = r;
+        r = new_r;
+        let new_s = old_s - quotient * s;
+        old_s = s;
+        s = new_s;
+    }
+    if old_r != 1 {
+        return None;
+    }
+    let modulus = modulus as i128;
+    Some((((old_s % modulus) + modulus) % modulus) as u64)
+}
+
+fn find_primitive_root_u64(p: u64) -> Option<u64> {
+    let group_order = p.checked_sub(1)?;
+    let prime_factors = distinct_prime_factors_u64(group_order);
+    (1..p).find(|&candidate| {
+        prime_factors
+            .iter()
+            .all(|&prime| pow_mod_u64(candidate, group_order / prime, p) != 1)
+    })
+}
+
+fn discrete_log_bsgs_u64(generator: u64, value: u64, p: u64) -> Option<u64> {
+    let step_count = (p as f64).sqrt().ceil() as u64 + 1;
+    let mut baby_steps = std::collections::HashMap::new();
+    let mut current = 1u64;
+    for j in 0..step_count {
+        baby_steps.entry(current).or_insert(j);
+        current = (u128::from(current) * u128::from(generator) % u128::from(p)) as u64;
+    }
+    let factor = mod_inverse_u64(pow_mod_u64(generator, step_count, p), p)?;
+    let mut giant_step_value = value % p;
+    for i in 0..step_count {
+        if let Some(&j) = baby_steps.get(&giant_step_value) {
+            let candidate = i * step_count + j;
+            if pow_mod_u64(generator, candidate, p) == value {
+                return Some(candidate);
+            }
+        }
+        giant_step_value = (u128::from(giant_step_value) * u128::from(factor) % u128::from(p)) as u64;
+    }
+    None
+}
+
+impl<V: ModularReducePow<V> + Integer + Clone + ToPrimitive + FromPrimitive, M: PrimeModulus<V> + Clone>
+    ModularInteger<V, M>
+{
+    /// computes an `r`th root of `self` modulo a prime using the
+    /// Adleman-Manders-Miller approach of solving for a discrete logarithm
+    /// in the cyclic group of units mod p and dividing it by `r`; returns
+    /// `None` if no such root exists or the modulus is too large to factor
+    /// `modulus - 1`
+    pub fn nth_root(&self, r: u64) -> Option<Self> {
+        if self.value.is_zero() {
+            return Some(self.clone());
+        }
+        let p = self.modulus.to_modulus().into_owned().to_u64()?;
+        let group_order = p.checked_sub(1)?;
+        let generator = find_primitive_root_u64(p)?;
+        let a = self.value.to_u64()?;
+        let k = discrete_log_bsgs_u64(generator, a, p)?;
+        let d = {
+            let (mut x, mut y) = (r, group_order);
+            while y != 0 {
+                let t = y;
+                y = x % y;
+                x = t;
+            }
+            x
+        };
+        if k % d != 0 {
+            return None;
+        }
+        let modulus_reduced = group_order / d;
+        let r_reduced = (r / d) % modulus_reduced;
+        let k_reduced = (k / d) % modulus_reduced;
+        let y = if modulus_reduced <= 1 {
+            0
+        } else {
+            let r_inv = mod_inverse_u64(r_reduced, modulus_reduced)?;
+            (u128::from(k_reduced) * u128::from(r_inv) % u128::from(modulus_reduced)) as u64
+        };
+        let x = pow_mod_u64(generator, y, p);
+        Some(ModularInteger::new(V::from_u64(x)?, self.modulus.clone()))
+    }
+}
+
+impl<V: ModularReduce + Eq + One + Zero + GCD<Output = V> + ExtendedGCD, M: Modulus<V>> DivAssign
+    for ModularInteger<V, M>
+{
+    fn div_assign(&mut self, rhs: ModularInteger<V, M>) {
+        self.mul_assign(rhs.inverse())
+    }
+}
+
+impl<V: ModularReduce + Eq + One + Zero + GCD<Output = V> + ExtendedGCD, M: Modulus<V>>
+    DivAssign<&'_ ModularInteger<V, M>> for ModularInteger<V, M>
+{
+    fn div_assign(&mut self, rhs: &ModularInteger<V, M>) {
+        self.mul_assign(rhs.inverse())
+    }
+}
+
+impl<V: ModularReduce + Eq + One + Zero + GCD<Output = V> + ExtendedGCD, M: Modulus<V>> Div
+    for ModularInteger<V, M>
+{
+    type Output = ModularInteger<V, M>;
+    fn div(self, rhs: ModularInteger<V, M>) -> ModularInteger<V, M> {
+        self.mul(rhs.inverse())
+    }
+}
+
+impl<V: ModularReduce + Eq + One + Zero + GCD<Output = V> + ExtendedGCD, M: Modulus<V>>
+    Div<ModularInteger<V, M>> for &'_ ModularInteger<V, M>
+{
+    type Output = ModularInteger<V, M>;
+    fn div(self, rhs: ModularInteger<V, M>) -> ModularInteger<V, M> {
+        self.mul(&rhs.inverse())
+    }
+}
+
+impl<V: ModularReduce + Eq + One + Zero + GCD<Output = V> + ExtendedGCD, M: Modulus<V>>
+    Div<&'_ ModularInteger<V, M>> for ModularInteger<V, M>
+{
+    type Output = ModularInteger<V, M>;
+    fn div(self, rhs: &ModularInteger<V, M>) -> ModularInteger<V, M> {
+        self.mul(rhs.inverse())
+    }
+}
+
+impl<'a, 'b, V: ModularReduce + Eq + One + Zero + GCD<Output = V> + ExtendedGCD, M: Modulus<V>>
+    Div<&'a ModularInteger<V, M>> for &'b ModularInteger<V, M>
+{
+    type Output = ModularInteger<V, M>;
+    fn div(self, rhs: &ModularInteger<V, M>) -> ModularInteger<V, M> {
+        self.mul(&rhs.inverse())
+    }
+}
+
+impl<V: ModularReduce + Eq + One + Zero + GCD<Output = V> + ExtendedGCD, M: Modulus<V>> CheckedDiv
+    for ModularInteger<V, M>
+{
+    fn checked_div(&self, rhs: &Self) -> Option<Self> {
+        self.checked_mul(&rhs.checked_inverse()?)
+    }
+}
+
+macro_rules! impl_exact_div {
+    (($($lifetimes:tt),*), $v:ident, $m:ident, $lhs:ty, $rhs:ty) => {
+        impl<$($lifetimes,)* $v, $m> ExactDiv<$rhs> for $lhs
+        where
+            $v: ModularReduce + Eq + One + Zero + GCD<Output = $v> + ExtendedGCD, $m: Modulus<$v>
+        {
+            type Output = ModularInteger<$v, $m>;
+            fn exact_div(self, rhs: $rhs) -> Self::Output {
+                self.div(rhs)
+            }
+            fn checked_exact_div(self, rhs: $rhs) -> Option<Self::Output> {
+                self.checked_div(rhs.borrow())
+            }
+        }
+
+        impl<$($lifetimes,)* $v, $m> AlwaysExactDiv<$rhs> for $lhs
+        where
+            $v: ModularReduce + Integer + GCD<Output = $v> + ExtendedGCD,
+            $m: PrimeModulus<$v>,
+        {
+        }
+    };
+    (assign ($($lifetimes:tt),*), $v:ident, $m:ident, $lhs:ty, $rhs:ty) => {
+        impl_exact_div!(($($lifetimes),*), $v, $m, $lhs, $rhs);
+
+        impl<$($lifetimes,)* $v, $m> ExactDivAssign<$rhs> for $lhs
+        where
+            $v: ModularReduce + Eq + One + Zero + GCD<Output = $v> + ExtendedGCD, $m: Modulus<$v>
+        {
+            fn exact_div_assign(&mut self, rhs: $rhs) {
+                self.div_assign(rhs);
+            }
+            fn checked_exact_div_assign(&mut self, rhs: $rhs) -> Result<(), ()> {
+                (&*self)
+                    .checked_exact_div(rhs)
+                    .map(|v| {
+                        *self = v;
+                    })
+                    .ok_or(())
+            }
+        }
+
+        impl<$($lifetimes,)* $v, $m> AlwaysExactDivAssign<$rhs> for $lhs
+        where
+            $v: ModularReduce + Integer + GCD<Output = $v> + ExtendedGCD,
+            $m: PrimeModulus<$v>,
+        {
+        }
+    };
+}
+
+impl_exact_div!(assign (), V, M, ModularInteger<V, M>, ModularInteger<V, M>);
+impl_exact_div!(assign ('r), V, M, ModularInteger<V, M>, &'r ModularInteger<V, M>);
+impl_exact_div!(('l), V, M, &'l ModularInteger<V, M>, ModularInteger<V, M>);
+impl_exact_div!(('l, 'r), V, M, &'l ModularInteger<V, M>, &'r ModularInteger<V, M>);
+
+impl<V, M> PolynomialCoefficient for ModularInteger<V, M>
+where
+    V: ModularReducePow<usize> + Eq + One + Zero + fmt::Debug + Hash,
+    M: Modulus<V> + fmt::Debug + Hash,
+{
+    type Element = Self;
+    type Divisor = DivisorIsOne;
+    const NESTING_DEPTH: usize = 0;
+    fn is_element_zero(element: &Self::Element) -> bool {
+        element.value.is_zero()
+    }
+    fn is_element_one(element: &Self::Element) -> bool {
+        element.value.is_one()
+    }
+    fn is_coefficient_zero(coefficient: &Self) -> bool {
+        coefficient.value.is_zero()
+    }
+    fn is_coefficient_one(coefficient: &Self) -> bool {
+        coefficient.value.is_one()
+    }
+    fn set_element_zero(element: &mut Self::Element) {
+        element.value.set_zero();
+    }
+    fn set_element_one(element: &mut Self::Element) {
+        element.value = V::modular_reduce(V::one(), &element.modulus);
+    }
+    fn set_coefficient_zero(coefficient: &mut Self) {
+        Self::set_element_zero(coefficient);
+    }
+    fn set_coefficient_one(coefficient: &mut Self) {
+        Self::set_element_one(coefficient);
     }
     fn make_zero_element(element: Cow<Self::Element>) -> Self::Element {
         let modulus = match element {
@@ -1238,6 +2263,72 @@ where
     }
 }
 
+impl<V, M> Polynomial<ModularInteger<V, M>>
+where
+    V: ModularReducePow<usize> + Eq + One + Zero + fmt::Debug + Hash,
+    M: Modulus<V> + fmt::Debug + Hash,
+{
+    /// the modulus shared by all of `self`'s coefficients, or `None` for the
+    /// zero polynomial, which has no coefficients to take a modulus from
+    fn modulus(&self) -> Result<Option<M>, ModulusMismatchError<M>> {
+        let mut coefficients = self.iter();
+        let first = match coefficients.next() {
+            Some(first) => first,
+            None => return Ok(None),
+        };
+        for coefficient in coefficients {
+            if !first.has_matching_moduli(&coefficient) {
+                return Err(first.mismatch_error(&coefficient));
+            }
+        }
+        Ok(Some(first.modulus().clone()))
+    }
+    fn check_compatible_modulus(&self, rhs: &Self) -> Result<(), ModulusMismatchError<M>> {
+        if let (Some(lhs_modulus), Some(rhs_modulus)) = (self.modulus()?, rhs.modulus()?) {
+            if lhs_modulus != rhs_modulus {
+                return Err(ModulusMismatchError {
+                    lhs_modulus,
+                    rhs_modulus,
+                });
+            }
+        }
+        Ok(())
+    }
+    /// builds a polynomial from `coefficients`, checking first that they all
+    /// share a single modulus; unlike the plain `From<Vec<_>>` conversion
+    /// (which panics deep inside the first arithmetic operation that
+    /// notices the mismatch), this reports a [`ModulusMismatchError`]
+    /// immediately
+    pub fn try_from_coefficients(
+        coefficients: Vec<ModularInteger<V, M>>,
+    ) -> Result<Self, ModulusMismatchError<M>> {
+        for pair in coefficients.windows(2) {
+            if !pair[0].has_matching_moduli(&pair[1]) {
+                return Err(pair[0].mismatch_error(&pair[1]));
+            }
+        }
+        Ok(Polynomial::from(coefficients))
+    }
+    /// like `self + rhs`, but returns a [`ModulusMismatchError`] instead of
+    /// panicking when `self` and `rhs` don't share a modulus
+    pub fn try_add(&self, rhs: &Self) -> Result<Self, ModulusMismatchError<M>> {
+        self.check_compatible_modulus(rhs)?;
+        Ok(self + rhs)
+    }
+    /// like `self - rhs`, but returns a [`ModulusMismatchError`] instead of
+    /// panicking when `self` and `rhs` don't share a modulus
+    pub fn try_sub(&self, rhs: &Self) -> Result<Self, ModulusMismatchError<M>> {
+        self.check_compatible_modulus(rhs)?;
+        Ok(self - rhs)
+    }
+    /// like `self * rhs`, but returns a [`ModulusMismatchError`] instead of
+    /// panicking when `self` and `rhs` don't share a modulus
+    pub fn try_mul(&self, rhs: &Self) -> Result<Self, ModulusMismatchError<M>> {
+        self.check_compatible_modulus(rhs)?;
+        Ok(self.clone() * rhs.clone())
+    }
+}
+
 impl<V, M> PolynomialReducingFactorSupported for ModularInteger<V, M>
 where
     V: ModularReducePow<usize> + Integer + fmt::Debug + Hash,
@@ -1251,11 +2342,843 @@ where
     }
 }
 
+/// a monic irreducible polynomial defining a `GF(p^n)` extension of `GF(p)`,
+/// stored as its non-leading coefficients (indices `0..degree`, low-to-high)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExtensionFieldModulus<V, M> {
+    non_leading_coefficients: Vec<ModularInteger<V, M>>,
+}
+
+impl<V: ModularReducePow<usize> + Integer + Clone, M: PrimeModulus<V> + Clone>
+    ExtensionFieldModulus<V, M>
+{
+    /// `non_leading_coefficients` are the coefficients of `x^0 ..= x^(n - 1)`
+    /// of the monic degree-`n` irreducible polynomial defining the extension;
+    /// primality/irreducibility are not checked
+    pub fn new(non_leading_coefficients: Vec<ModularInteger<V, M>>) -> Self {
+        assert!(
+            !non_leading_coefficients.is_empty(),
+            "extension field modulus must have positive degree"
+        );
+        ExtensionFieldModulus {
+            non_leading_coefficients,
+        }
+    }
+    pub fn degree(&self) -> usize {
+        self.non_leading_coefficients.len()
+    }
+    pub fn prime_modulus(&self) -> &M {
+        self.non_leading_coefficients[0].modulus()
+    }
+}
+
+/// an element of the finite field `GF(p^n)` represented as a polynomial of
+/// degree less than `n` over `GF(p)`, reduced modulo an
+/// [`ExtensionFieldModulus`]
+#[derive(Clone, Debug)]
+pub struct GaloisFieldElement<V, M> {
+    /// coefficients of `x^0 ..= x^(n - 1)`, always of length `modulus.degree()`
+    coefficients: Vec<ModularInteger<V, M>>,
+    modulus: std::sync::Arc<ExtensionFieldModulus<V, M>>,
+}
+
+impl<V: ModularReducePow<usize> + Integer + Clone, M: PrimeModulus<V> + Clone>
+    GaloisFieldElement<V, M>
+{
+    pub fn new(
+        mut coefficients: Vec<ModularInteger<V, M>>,
+        modulus: std::sync::Arc<ExtensionFieldModulus<V, M>>,
+    ) -> Self {
+        let prime = modulus.prime_modulus().clone();
+        coefficients.resize_with(modulus.degree(), || ModularInteger::new(V::zero(), prime.clone()));
+        GaloisFieldElement {
+            coefficients,
+            modulus,
+        }
+    }
+    pub fn coefficients(&self) -> &[ModularInteger<V, M>] {
+        &self.coefficients
+    }
+    pub fn modulus(&self) -> &std::sync::Arc<ExtensionFieldModulus<V, M>> {
+        &self.modulus
+    }
+    fn zero_like(&self) -> Self {
+        GaloisFieldElement::new(Vec::new(), self.modulus.clone())
+    }
+    fn mul(&self, rhs: &Self) -> Self {
+        assert!(self.modulus == rhs.modulus, "mismatched extension field moduli");
+        let degree = self.modulus.degree();
+        let prime = self.modulus.prime_modulus();
+        let mut product = vec![ModularInteger::new(V::zero(), prime.clone()); 2 * degree - 1];
+        for (i, l) in self.coefficients.iter().enumerate() {
+            for (j, r) in rhs.coefficients.iter().enumerate() {
+                product[i + j] += l * r;
+            }
+        }
+        // reduce modulo x^degree = -sum(non_leading_coefficients[i] * x^i)
+        for i in (degree..product.len()).rev() {
+            let factor = product[i].clone();
+            if !factor.value().is_zero() {
+                product[i] = ModularInteger::new(V::zero(), prime.clone());
+                for (j, modulus_coefficient) in
+                    self.modulus.non_leading_coefficients.iter().enumerate()
+                {
+                    product[i - degree + j] -= &factor * modulus_coefficient;
+                }
+            }
+        }
+        product.truncate(degree);
+        GaloisFieldElement {
+            coefficients: product,
+            modulus: self.modulus.clone(),
+        }
+    }
+    /// the Frobenius endomorphism `x -> x^p`, an automorphism of `GF(p^n)`
+    /// fixing `GF(p)` pointwise; computed with a single modular
+    /// exponentiation rather than `n` general-purpose multiplications
+    pub fn frobenius(&self) -> Self {
+        self.pow(self.modulus.prime_modulus().to_modulus().into_owned())
+    }
+    /// `self` raised to the power `self.frobenius()` applied `k` times, i.e.
+    /// `x -> x^(p^k)`, computed directly rather than by iterating
+    /// [`Self::frobenius`] `k` times
+    pub fn frobenius_pow(&self, k: u32) -> Self {
+        let p = self.modulus.prime_modulus().to_modulus().into_owned();
+        let mut p_to_the_k = V::one();
+        for _ in 0..k {
+            p_to_the_k = p_to_the_k * p.clone();
+        }
+        self.pow(p_to_the_k)
+    }
+    fn pow(&self, mut exponent: V) -> Self {
+        let one = ModularInteger::new(V::one(), self.modulus.prime_modulus().clone());
+        let mut coefficients = vec![ModularInteger::new(V::zero(), self.modulus.prime_modulus().clone()); self.modulus.degree()];
+        coefficients[0] = one;
+        let mut retval = GaloisFieldElement {
+            coefficients,
+            modulus: self.modulus.clone(),
+        };
+        let mut base = self.clone();
+        while !exponent.is_zero() {
+            if exponent.is_odd() {
+                retval = retval.mul(&base);
+            }
+            exponent = exponent / (V::one() + V::one());
+            if !exponent.is_zero() {
+                base = base.mul(&base);
+            }
+        }
+        retval
+    }
+    /// the trace of `self` down to `GF(p)`: `sum(self.frobenius_pow(i) for i in 0..n)`
+    pub fn field_trace(&self) -> ModularInteger<V, M> {
+        let degree = self.modulus.degree();
+        let mut sum = self.zero_like();
+        let mut conjugate = self.clone();
+        for _ in 0..degree {
+            for (l, r) in sum.coefficients.iter_mut().zip(conjugate.coefficients.iter()) {
+                *l += r;
+            }
+            conjugate = conjugate.frobenius();
+        }
+        sum.coefficients[0].clone()
+    }
+    /// the norm of `self` down to `GF(p)`: `product(self.frobenius_pow(i) for i in 0..n)`
+    pub fn field_norm(&self) -> ModularInteger<V, M> {
+        let degree = self.modulus.degree();
+        let mut product = {
+            let mut coefficients =
+                vec![ModularInteger::new(V::zero(), self.modulus.prime_modulus().clone()); degree];
+            coefficients[0] = ModularInteger::new(V::one(), self.modulus.prime_modulus().clone());
+            GaloisFieldElement {
+                coefficients,
+                modulus: self.modulus.clone(),
+            }
+        };
+        let mut conjugate = self.clone();
+        for _ in 0..degree {
+            product = product.mul(&conjugate);
+            conjugate = conjugate.frobenius();
+        }
+        product.coefficients[0].clone()
+    }
+}
+
+fn is_pow2(n: usize) -> bool {
+    n != 0 && n & (n - 1) == 0
+}
+
+fn bit_reverse_permute<T>(values: &mut [T]) {
+    let n = values.len();
+    if n <= 1 {
+        return;
+    }
+    let bits = n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - bits);
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+}
+
+/// the largest power of two dividing `modulus - 1`, i.e. the largest NTT
+/// order directly supported by `modulus`
+pub fn max_two_adic_order<V, M>(modulus: &M) -> u32
+where
+    V: Integer + Clone,
+    M: PrimeModulus<V>,
+{
+    let p = modulus.to_modulus().into_owned();
+    let mut p_minus_one = p - V::one();
+    let mut order = 0;
+    while p_minus_one.is_even() {
+        p_minus_one = p_minus_one / (V::one() + V::one());
+        order += 1;
+    }
+    order
+}
+
+/// finds a primitive `order`th root of unity modulo `modulus`; `order` must
+/// be a power of two dividing `modulus - 1`
+pub fn find_root_of_unity<V, M>(order: usize, modulus: &M) -> ModularInteger<V, M>
+where
+    V: ModularReducePow<V> + Integer + FromPrimitive + Clone,
+    M: PrimeModulus<V> + Clone,
+{
+    let p = modulus.to_modulus().into_owned();
+    let order_v = V::from_usize(order).expect("NTT order doesn't fit in modulus type");
+    let p_minus_one = p.clone() - V::one();
+    assert!(
+        (p_minus_one.clone() % order_v.clone()).is_zero(),
+        "requested order must divide modulus - 1"
+    );
+    let exponent = p_minus_one / order_v.clone();
+    let half_order = if order > 1 {
+        Some(order_v.clone() / (V::one() + V::one()))
+    } else {
+        None
+    };
+    let mut base = V::from_u8(2).expect("2 doesn't fit in modulus type");
+    loop {
+        let root = base.pow_modular_reduce(&exponent, modulus);
+        let is_primitive = !root.is_zero()
+            && match &half_order {
+                None => true,
+                Some(half_order) => !root.pow_modular_reduce(half_order, modulus).is_one(),
+            };
+        if is_primitive {
+            return ModularInteger::new(root, modulus.clone());
+        }
+        base = base + V::one();
+        assert!(
+            base < p,
+            "could not find a primitive root of unity of the requested order"
+        );
+    }
+}
+
+fn ntt_butterflies<V, M>(values: &mut [ModularInteger<V, M>], modulus: &M, inverse: bool)
+where
+    V: ModularReducePow<V> + Integer + FromPrimitive + Clone + GCD<Output = V> + ExtendedGCD,
+    M: PrimeModulus<V> + Clone,
+{
+    let n = values.len();
+    let mut len = 2;
+    while len <= n {
+        let mut root = find_root_of_unity(len, modulus);
+        if inverse {
+            root = root.inverse();
+        }
+        let mut i = 0;
+        while i < n {
+            let mut w = ModularInteger::new(V::one(), modulus.clone());
+            for j in 0..len / 2 {
+                let u = values[i + j].clone();
+                let v = &values[i + j + len / 2] * &w;
+                values[i + j] = &u + &v;
+                values[i + j + len / 2] = &u - &v;
+                w = &w * &root;
+            }
+            i += len;
+        }
+        len *= 2;
+    }
+}
+
+/// in-place forward number-theoretic transform of `values` over an
+/// NTT-friendly prime `ModularInteger` modulus; `values.len()` must be a
+/// power of two dividing `modulus - 1`
+pub fn forward_ntt<V, M>(values: &mut [ModularInteger<V, M>])
+where
+    V: ModularReducePow<V> + Integer + FromPrimitive + Clone + GCD<Output = V> + ExtendedGCD,
+    M: PrimeModulus<V> + Clone,
+{
+    let n = values.len();
+    assert!(is_pow2(n), "NTT length must be a power of two");
+    if n <= 1 {
+        return;
+    }
+    let modulus = values[0].modulus().clone();
+    bit_reverse_permute(values);
+    ntt_butterflies(values, &modulus, false);
+}
+
+/// in-place inverse number-theoretic transform; the exact inverse of
+/// [`forward_ntt`] for the same length
+pub fn inverse_ntt<V, M>(values: &mut [ModularInteger<V, M>])
+where
+    V: ModularReducePow<V> + Integer + FromPrimitive + Clone + GCD<Output = V> + ExtendedGCD,
+    M: PrimeModulus<V> + Clone,
+{
+    let n = values.len();
+    assert!(is_pow2(n), "NTT length must be a power of two");
+    if n <= 1 {
+        return;
+    }
+    let modulus = values[0].modulus().clone();
+    bit_reverse_permute(values);
+    ntt_butterflies(values, &modulus, true);
+    let n_inv = ModularInteger::new(
+        V::from_usize(n).expect("n doesn't fit in modulus type"),
+        modulus,
+    )
+    .inverse();
+    for value in values.iter_mut() {
+        *value = &*value * &n_inv;
+    }
+}
+
+fn hensel_mod_inverse<V>(value: &V, modulus: &V) -> V
+where
+    V: Integer + Clone + GCD<Output = V> + ExtendedGCD,
+{
+    let ExtendedGCDResult { gcd, x, .. } = ExtendedGCD::extended_gcd(value, modulus);
+    assert!(
+        gcd.is_one(),
+        "value has no inverse modulo the given prime power"
+    );
+    x.mod_floor(modulus)
+}
+
+/// lifts `initial_inverse`, an inverse of `value` modulo `modulus`'s prime
+/// base, to an inverse of `value` modulo the full prime power `modulus`,
+/// using quadratic (Newton) Hensel lifting
+pub fn hensel_lift_inverse<V, M>(
+    value: &V,
+    initial_inverse: &V,
+    modulus: &M,
+) -> ModularInteger<V, M>
+where
+    V: ModularReduce + Integer + Clone + GCD<Output = V> + ExtendedGCD,
+    M: PrimePowerModulus<V> + Clone,
+{
+    let base = modulus.base_and_exponent().base;
+    let target = modulus.to_modulus().into_owned();
+    let mut current_modulus = base;
+    let mut x = initial_inverse.mod_floor(&current_modulus);
+    let two = V::one() + V::one();
+    while current_modulus < target {
+        let mut next_modulus = current_modulus.clone() * current_modulus.clone();
+        if next_modulus > target {
+            next_modulus = target.clone();
+        }
+        let t = (two.clone() - value.clone() * x.clone()).mod_floor(&next_modulus);
+        x = (x * t).mod_floor(&next_modulus);
+        current_modulus = next_modulus;
+    }
+    ModularInteger::new(x, modulus.clone())
+}
+
+/// lifts `initial_sqrt`, a square root of `value` modulo `modulus`'s (odd)
+/// prime base, to a square root of `value` modulo the full prime power
+/// `modulus`, using quadratic Hensel lifting
+pub fn hensel_lift_sqrt<V, M>(value: &V, initial_sqrt: &V, modulus: &M) -> ModularInteger<V, M>
+where
+    V: ModularReduce + Integer + Clone + GCD<Output = V> + ExtendedGCD,
+    M: PrimePowerModulus<V> + Clone,
+{
+    let base = modulus.base_and_exponent().base;
+    let target = modulus.to_modulus().into_owned();
+    let mut current_modulus = base;
+    let mut root = initial_sqrt.mod_floor(&current_modulus);
+    let two = V::one() + V::one();
+    while current_modulus < target {
+        let mut next_modulus = current_modulus.clone() * current_modulus.clone();
+        if next_modulus > target {
+            next_modulus = target.clone();
+        }
+        let two_root_inverse = hensel_mod_inverse(
+            &(two.clone() * root.clone()).mod_floor(&next_modulus),
+            &next_modulus,
+        );
+        let residual = (root.clone() * root.clone() - value.clone()).mod_floor(&next_modulus);
+        root = (root - residual * two_root_inverse).mod_floor(&next_modulus);
+        current_modulus = next_modulus;
+    }
+    ModularInteger::new(root, modulus.clone())
+}
+
+/// lifts `initial_root`, a simple root of `polynomial` modulo `modulus`'s
+/// prime base (i.e. `polynomial(initial_root) == 0` and
+/// `polynomial'(initial_root) != 0` mod the base), to a root modulo the
+/// full prime power `modulus`, using Newton's method
+pub fn hensel_lift_simple_root<V, M>(
+    polynomial: &Polynomial<V>,
+    initial_root: &V,
+    modulus: &M,
+) -> ModularInteger<V, M>
+where
+    V: PolynomialCoefficient<Element = V> + ModularReduce + Integer + Clone + GCD<Output = V> + ExtendedGCD,
+    M: PrimePowerModulus<V> + Clone,
+{
+    let derivative = polynomial.derivative();
+    let base = modulus.base_and_exponent().base;
+    let target = modulus.to_modulus().into_owned();
+    let mut current_modulus = base;
+    let mut root = initial_root.mod_floor(&current_modulus);
+    while current_modulus < target {
+        let mut next_modulus = current_modulus.clone() * current_modulus.clone();
+        if next_modulus > target {
+            next_modulus = target.clone();
+        }
+        let value_at_root = polynomial.eval(&root).mod_floor(&next_modulus);
+        let derivative_at_root = derivative.eval(&root).mod_floor(&next_modulus);
+        let derivative_inverse = hensel_mod_inverse(&derivative_at_root, &next_modulus);
+        root = (root - value_at_root * derivative_inverse).mod_floor(&next_modulus);
+        current_modulus = next_modulus;
+    }
+    ModularInteger::new(root, modulus.clone())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::util::tests::{test_op_helper, test_unary_op_helper};
 
+    #[derive(Copy, Clone, PartialEq, Eq, Debug)]
+    struct TestPrimePowerModulus {
+        value: i64,
+        base: i64,
+        exponent: usize,
+    }
+
+    impl Modulus<i64> for TestPrimePowerModulus {
+        fn to_modulus(&self) -> Cow<i64> {
+            Cow::Owned(self.value)
+        }
+    }
+
+    impl PrimePowerModulus<i64> for TestPrimePowerModulus {
+        fn base_and_exponent(&self) -> BaseAndExponent<i64> {
+            BaseAndExponent {
+                base: self.base,
+                exponent: self.exponent,
+            }
+        }
+    }
+
+    #[test]
+    fn test_unit_group_order() {
+        assert_eq!(PrimePowerModulus::<i64>::unit_group_order(&Mod9), 6);
+        assert_eq!(KnownPrime::new_unsafe(7i64).unit_group_order(), 6);
+    }
+
+    #[test]
+    fn test_multiplicative_order() {
+        let modulus = KnownPrime::new_unsafe(7i64);
+        assert_eq!(
+            ModularInteger::new(3, modulus).multiplicative_order(),
+            Some(BigUint::from(6u32))
+        );
+        assert_eq!(
+            ModularInteger::new(6, modulus).multiplicative_order(),
+            Some(BigUint::from(2u32))
+        );
+        assert_eq!(
+            ModularInteger::new(1, modulus).multiplicative_order(),
+            Some(BigUint::from(1u32))
+        );
+        assert_eq!(
+            ModularInteger::new(0, modulus).multiplicative_order(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_nth_root() {
+        let modulus = KnownPrime::new_unsafe(13i64);
+        // squares mod 13: 1, 4, 9, 3, 12, 10
+        let root = ModularInteger::new(4, modulus).nth_root(2).unwrap();
+        assert_eq!(root.value() * root.value() % 13, 4);
+        // cubes mod 13: 1, 8, 1, 12, 8, 8, 5, 5, 1, 12, 5, 12
+        let root = ModularInteger::new(8, modulus).nth_root(3).unwrap();
+        assert_eq!(
+            root.value() * root.value() % 13 * root.value() % 13,
+            8
+        );
+        // 5 is not a quadratic residue mod 13
+        assert_eq!(ModularInteger::new(5, modulus).nth_root(2), None);
+        assert_eq!(
+            ModularInteger::new(0, modulus).nth_root(4),
+            Some(ModularInteger::new(0, modulus))
+        );
+    }
+
+    #[test]
+    fn test_verified_prime_modulus() {
+        assert_eq!(VerifiedPrimeModulus::new(17i64).unwrap().into_inner(), 17);
+        assert_eq!(VerifiedPrimeModulus::new(15i64), Err(NotPrime));
+        assert_eq!(VerifiedPrimeModulus::new(1i64), Err(NotPrime));
+        let modulus = VerifiedPrimeModulus::new(13i64).unwrap();
+        let value = ModularInteger::new(20i64, modulus);
+        assert_eq!(*value.value(), 7);
+        assert_eq!(*value.inverse().value(), 2);
+    }
+
+    #[test]
+    fn test_checked_pow() {
+        let modulus = KnownPrime::new_unsafe(13i64);
+        let value = ModularInteger::new(2, modulus);
+        assert_eq!(
+            *value.checked_pow(&BigInt::from(5)).unwrap().value(),
+            6 // 2^5 == 32 == 6 (mod 13)
+        );
+        assert_eq!(
+            *value.checked_pow(&BigInt::from(-1)).unwrap().value(),
+            7 // 2 * 7 == 14 == 1 (mod 13)
+        );
+        assert_eq!(
+            *value.checked_pow(&BigInt::from(-3)).unwrap().value(),
+            5 // 7^3 == 343 == 5 (mod 13)
+        );
+        let zero = ModularInteger::new(0, modulus);
+        assert_eq!(zero.checked_pow(&BigInt::from(-1)), None);
+    }
+
+    #[test]
+    fn test_lucas_uv() {
+        let modulus = KnownPrime::new_unsafe(13i64);
+        let p = ModularInteger::new(3, modulus);
+        let q = ModularInteger::new(1, modulus);
+        let check = |k: u64, expected_u: i64, expected_v: i64| {
+            let (u, v) = lucas_uv(&p, &q, &BigUint::from(k));
+            assert_eq!(*u.value(), expected_u, "k = {}", k);
+            assert_eq!(*v.value(), expected_v, "k = {}", k);
+        };
+        check(0, 0, 2);
+        check(1, 1, 3);
+        check(2, 3, 7);
+        check(5, 3, 6);
+        check(10, 5, 8);
+        check(20, 1, 10);
+    }
+
+    #[test]
+    fn test_quadratic_residues() {
+        let modulus = KnownPrime::new_unsafe(13i64);
+        assert!(ModularInteger::new(4, modulus).is_quadratic_residue());
+        assert!(ModularInteger::new(0, modulus).is_quadratic_residue());
+        assert!(!ModularInteger::new(2, modulus).is_quadratic_residue());
+        let residues: Vec<i64> = ModularInteger::<i64, _>::quadratic_residues(modulus)
+            .unwrap()
+            .map(|v| *v.value())
+            .collect();
+        assert_eq!(residues, vec![0, 1, 3, 4, 9, 10, 12]);
+    }
+
+    #[test]
+    fn test_modular_matrix() {
+        let modulus = KnownPrime::new_unsafe(7i64);
+        let m = |v: i64| ModularInteger::new(v, modulus);
+        // [[1, 2], [3, 4]] mod 7
+        let matrix = ModularMatrix::new(2, 2, modulus, vec![m(1), m(2), m(3), m(4)]);
+        assert_eq!(matrix.rank(), 2);
+        assert_eq!(*matrix.determinant().value(), (1 * 4 - 2 * 3i64).rem_euclid(7));
+        let inverse = matrix.try_inverse().expect("matrix should be invertible");
+        for row in 0..2 {
+            for col in 0..2 {
+                let mut sum = m(0);
+                for k in 0..2 {
+                    sum = sum + matrix.get(row, k) * inverse.get(k, col);
+                }
+                assert_eq!(*sum.value(), if row == col { 1 } else { 0 });
+            }
+        }
+        let solution = matrix.solve(&[m(5), m(6)]).expect("system should be solvable");
+        assert_eq!(
+            *(matrix.get(0, 0) * &solution[0] + matrix.get(0, 1) * &solution[1]).value(),
+            5
+        );
+        assert_eq!(
+            *(matrix.get(1, 0) * &solution[0] + matrix.get(1, 1) * &solution[1]).value(),
+            6
+        );
+
+        // singular matrix: rows are multiples of each other
+        let singular = ModularMatrix::new(2, 2, modulus, vec![m(1), m(2), m(2), m(4)]);
+        assert_eq!(singular.rank(), 1);
+        assert!(singular.try_inverse().is_none());
+        assert_eq!(*singular.determinant().value(), 0);
+        let kernel = singular.kernel_basis();
+        assert_eq!(kernel.len(), 1);
+        for basis_vector in &kernel {
+            let mut sum = m(0);
+            for (col, value) in basis_vector.iter().enumerate() {
+                sum = sum + singular.get(0, col) * value;
+            }
+            assert_eq!(*sum.value(), 0);
+        }
+    }
+
+    #[test]
+    fn test_modular_vec() {
+        let modulus = KnownPrime::new_unsafe(7i64);
+        let m = |v: i64| ModularInteger::new(v, modulus);
+        let a = ModularVec::new(modulus, vec![1, 3, 5]);
+        let b = ModularVec::new(modulus, vec![6, 4, 2]);
+        assert_eq!(a.len(), 3);
+        assert_eq!(a.add(&b).values(), &[0, 0, 0]);
+        assert_eq!(a.sub(&b).values(), &[2, 6, 3]);
+        assert_eq!(a.mul(&b).values(), &[6, 5, 3]);
+        assert_eq!(a.neg().values(), &[6, 4, 2]);
+        assert_eq!(a.scalar_mul(&m(2)).values(), &[2, 6, 3]);
+        assert_eq!(a.scalar_add(&m(2)).values(), &[3, 5, 0]);
+        assert_eq!(*a.get(1).value(), 3);
+        let mut c = ModularVec::new(modulus, vec![0, 0, 0]);
+        c.set(1, m(5));
+        assert_eq!(c.values(), &[0, 5, 0]);
+        assert_eq!(a[0], 1);
+
+        let zero = ModularVec::<i64, _>::zero(3, modulus);
+        assert!(!zero.is_empty());
+        assert_eq!(zero.values(), &[0, 0, 0]);
+    }
+
+    #[test]
+    fn test_inv() {
+        use num_traits::Inv;
+        let modulus = KnownPrime::new_unsafe(7i64);
+        let value = ModularInteger::new(3, modulus);
+        assert_eq!(*value.inv().value(), *value.inverse().value());
+        assert_eq!(*(&value).inv().value(), *value.inverse().value());
+        assert_eq!(*(value * value.inv()).value(), 1);
+    }
+
+    #[test]
+    fn test_pow_modular_reduce_bits() {
+        let modulus = KnownPrime::new_unsafe(1_000_000_007i64);
+        let base = ModularInteger::new(3, modulus);
+        for exponent in 0u64..40 {
+            let bits = (0..64).rev().map(|i| (exponent >> i) & 1 != 0);
+            let expected = base.value().pow_modular_reduce(&exponent, modulus);
+            assert_eq!(*base.pow_modular_reduce_bits(bits).value(), expected);
+        }
+        let exponent = BigUint::from(123456789u64);
+        let expected = base.value().pow_modular_reduce(&BigInt::from(123456789), modulus);
+        assert_eq!(*base.pow_modular_reduce_biguint(&exponent).value(), expected);
+    }
+
+    #[test]
+    fn test_unchecked_arithmetic() {
+        let modulus = KnownPrime::new_unsafe(7i64);
+        let a = ModularInteger::new(5, modulus);
+        let b = ModularInteger::new(4, modulus);
+        assert_eq!(*a.add_unchecked(&b).value(), *(a + b).value());
+        assert_eq!(*a.sub_unchecked(&b).value(), *(a - b).value());
+        assert_eq!(*a.mul_unchecked(&b).value(), *(a * b).value());
+    }
+
+    #[test]
+    #[should_panic(expected = "moduli don't match")]
+    fn test_unchecked_arithmetic_debug_assert() {
+        let a = ModularInteger::new(5, KnownPrime::new_unsafe(7i64));
+        let b = ModularInteger::new(4, KnownPrime::new_unsafe(11i64));
+        a.add_unchecked(&b);
+    }
+
+    #[test]
+    fn test_polynomial_try_from_coefficients() {
+        let modulus = KnownPrime::new_unsafe(7i64);
+        let coefficients = vec![
+            ModularInteger::new(1, modulus),
+            ModularInteger::new(2, modulus),
+            ModularInteger::new(3, modulus),
+        ];
+        assert!(Polynomial::try_from_coefficients(coefficients).is_ok());
+        let mismatched = vec![
+            ModularInteger::new(1, KnownPrime::new_unsafe(7i64)),
+            ModularInteger::new(2, KnownPrime::new_unsafe(11i64)),
+        ];
+        assert_eq!(
+            Polynomial::try_from_coefficients(mismatched),
+            Err(ModulusMismatchError {
+                lhs_modulus: KnownPrime::new_unsafe(7i64),
+                rhs_modulus: KnownPrime::new_unsafe(11i64),
+            })
+        );
+    }
+
+    #[test]
+    fn test_polynomial_try_arithmetic() {
+        let modulus_a = KnownPrime::new_unsafe(7i64);
+        let modulus_b = KnownPrime::new_unsafe(11i64);
+        let lhs = Polynomial::from(vec![
+            ModularInteger::new(1, modulus_a),
+            ModularInteger::new(2, modulus_a),
+        ]);
+        let matching_rhs = Polynomial::from(vec![
+            ModularInteger::new(3, modulus_a),
+            ModularInteger::new(4, modulus_a),
+        ]);
+        assert_eq!(lhs.try_add(&matching_rhs), Ok(lhs.clone() + matching_rhs));
+        let mismatched_rhs = Polynomial::from(vec![
+            ModularInteger::new(3, modulus_b),
+            ModularInteger::new(4, modulus_b),
+        ]);
+        let error = ModulusMismatchError {
+            lhs_modulus: modulus_a,
+            rhs_modulus: modulus_b,
+        };
+        assert_eq!(lhs.try_add(&mismatched_rhs), Err(error.clone()));
+        assert_eq!(lhs.try_sub(&mismatched_rhs), Err(error.clone()));
+        assert_eq!(lhs.try_mul(&mismatched_rhs), Err(error));
+    }
+
+    #[test]
+    fn test_table_inverse() {
+        for value in 0i32..7 {
+            let x = ModularInteger::new(value, Mod7);
+            assert_eq!(
+                x.table_checked_inverse().map(|v| *v.value()),
+                x.checked_inverse().map(|v| *v.value())
+            );
+        }
+        assert_eq!(*ModularInteger::new(3i32, Mod7).table_inverse().value(), 5);
+    }
+
+    #[test]
+    fn test_scalar_arithmetic() {
+        let modulus = KnownPrime::new_unsafe(13i64);
+        let mut value = ModularInteger::new(10, modulus);
+        assert_eq!(*(value + 5u64).value(), 2);
+        assert_eq!(*(&value + 5u64).value(), 2);
+        assert_eq!(*(value - 15i64).value(), 8);
+        assert_eq!(*(value * BigInt::from(4)).value(), 1);
+        value += 5u64;
+        assert_eq!(*value.value(), 2);
+        value -= 3i64;
+        assert_eq!(*value.value(), 12);
+        value *= BigInt::from(2);
+        assert_eq!(*value.value(), 11);
+    }
+
+    #[test]
+    fn test_try_arithmetic_mismatch() {
+        let modulus1 = KnownPrime::new_unsafe(13i64);
+        let modulus2 = KnownPrime::new_unsafe(17i64);
+        let a = ModularInteger::new(5, modulus1);
+        let b = ModularInteger::new(5, modulus2);
+        assert_eq!(
+            a.try_add(&b),
+            Err(ModulusMismatchError {
+                lhs_modulus: modulus1,
+                rhs_modulus: modulus2,
+            })
+        );
+        assert!(a.try_sub(&b).is_err());
+        assert!(a.try_mul(&b).is_err());
+        assert!(a.try_div(&b).is_err());
+        let c = ModularInteger::new(9, modulus1);
+        assert_eq!(a.try_add(&c), Ok(ModularInteger::new(14, modulus1)));
+        assert_eq!(a.try_sub(&c), Ok(ModularInteger::new(-4, modulus1)));
+        assert_eq!(a.try_mul(&c), Ok(ModularInteger::new(45, modulus1)));
+    }
+
+    #[test]
+    fn test_hensel_lift_inverse() {
+        let modulus = TestPrimePowerModulus {
+            value: 9,
+            base: 3,
+            exponent: 2,
+        };
+        let lifted = hensel_lift_inverse(&2, &2, &modulus);
+        assert_eq!(*lifted.value(), 5);
+    }
+
+    #[test]
+    fn test_hensel_lift_sqrt() {
+        let modulus = TestPrimePowerModulus {
+            value: 49,
+            base: 7,
+            exponent: 2,
+        };
+        let lifted = hensel_lift_sqrt(&2, &3, &modulus);
+        assert_eq!(*lifted.value(), 10);
+    }
+
+    #[test]
+    fn test_hensel_lift_simple_root() {
+        let modulus = TestPrimePowerModulus {
+            value: 25,
+            base: 5,
+            exponent: 2,
+        };
+        let polynomial: Polynomial<i64> = [-2, 0, 0, 1].iter().copied().collect();
+        let lifted = hensel_lift_simple_root(&polynomial, &3, &modulus);
+        assert_eq!(*lifted.value(), 3);
+    }
+
+    #[test]
+    fn test_find_root_of_unity() {
+        let modulus = KnownPrime::new_unsafe(17i64);
+        assert_eq!(max_two_adic_order(&modulus), 4);
+        let root = find_root_of_unity::<i64, _>(8, &modulus);
+        assert_ne!(*root.value(), 1);
+        assert_eq!(root.value().pow_modular_reduce(&8i64, &modulus), 1);
+        assert_ne!(root.value().pow_modular_reduce(&4i64, &modulus), 1);
+    }
+
+    #[test]
+    fn test_ntt_round_trip() {
+        // 17 is NTT-friendly: 17 - 1 == 16 == 2^4
+        let modulus = KnownPrime::new_unsafe(17i64);
+        let make = |v: i64| ModularInteger::new(v, modulus);
+        let mut values: Vec<_> = [1, 2, 3, 4, 5, 6, 7, 8].iter().map(|&v| make(v)).collect();
+        let original = values.clone();
+        forward_ntt(&mut values);
+        assert_ne!(values, original);
+        inverse_ntt(&mut values);
+        assert_eq!(values, original);
+    }
+
+    #[test]
+    fn test_galois_field_frobenius_trace_norm() {
+        // GF(4) = GF(2)[x] / (x^2 + x + 1)
+        let modulus = std::sync::Arc::new(ExtensionFieldModulus::new(vec![
+            ModularInteger::new(1i32, Mod2),
+            ModularInteger::new(1, Mod2),
+        ]));
+        let make = |c0: i32, c1: i32| {
+            GaloisFieldElement::new(
+                vec![ModularInteger::new(c0, Mod2), ModularInteger::new(c1, Mod2)],
+                modulus.clone(),
+            )
+        };
+        let x = make(0, 1);
+        // x^2 == x + 1
+        let x_squared = x.mul(&x);
+        assert_eq!(x_squared.coefficients(), make(1, 1).coefficients());
+        // frobenius(x) == x^2 == x + 1
+        assert_eq!(x.frobenius().coefficients(), make(1, 1).coefficients());
+        // frobenius_pow(2) == identity on GF(4)
+        assert_eq!(x.frobenius_pow(2).coefficients(), x.coefficients());
+        // trace(x) == x + x^2 == 1
+        assert_eq!(x.field_trace(), ModularInteger::new(1, Mod2));
+        // norm(x) == x * x^2 == x^3 == 1
+        assert_eq!(x.field_norm(), ModularInteger::new(1, Mod2));
+    }
+
     fn test_overflow_for_type<
         T: Modulus<T> + ModularReduce + Sub<Output = T> + Copy + Into<BigInt> + fmt::Debug,
         BigIntToT: Fn(&BigInt) -> Option<T>,
@@ -0,0 +1,355 @@
+// SPDX-License-Identifier: LGPL-2.1-or-later
+// See Notices.txt for copyright information
+
+//! algebraic number fields represented by a primitive element, and field
+//! elements represented as polynomials in that element
+//!
+//! computing with several [`RealAlgebraicNumber`]s known to all lie in the
+//! same number field by combining them directly is asymptotically
+//! expensive, since every arithmetic operation on [`RealAlgebraicNumber`]s
+//! recomputes a resultant of their minimal polynomials from scratch.
+//! [`NumberFieldElement`] instead fixes a single defining polynomial once,
+//! via [`NumberField`], and does arithmetic by reducing polynomials modulo
+//! it, which is much cheaper for repeated operations among elements of the
+//! same field
+
+use crate::{
+    algebraic_numbers::RealAlgebraicNumber,
+    polynomial::Polynomial,
+    traits::{ExtendedGCD, ExtendedGCDResult},
+};
+use num_bigint::BigInt;
+use num_rational::Ratio;
+use num_traits::{One, Zero};
+use std::{
+    error::Error,
+    fmt,
+    ops::{Add, Div, Mul, Neg, Sub},
+    sync::Arc,
+};
+
+/// the number field `Q(alpha)` obtained by adjoining `primitive_element`'s
+/// exact value to the rationals
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NumberField {
+    minimal_polynomial: Polynomial<Ratio<BigInt>>,
+    primitive_element: RealAlgebraicNumber,
+}
+
+impl NumberField {
+    /// creates the number field `Q(alpha)`, where `alpha` is `primitive_element`'s exact value
+    pub fn new(primitive_element: RealAlgebraicNumber) -> Arc<Self> {
+        let minimal_polynomial = primitive_element
+            .minimal_polynomial()
+            .iter()
+            .map(Ratio::from)
+            .collect();
+        Arc::new(Self {
+            minimal_polynomial,
+            primitive_element,
+        })
+    }
+    /// the degree of this number field over `Q`
+    pub fn degree(&self) -> usize {
+        self.minimal_polynomial
+            .degree()
+            .expect("minimal polynomial of an algebraic number is never zero")
+    }
+    /// `primitive_element`'s minimal polynomial, with coefficients converted to [`Ratio<BigInt>`]
+    /// so it can be used directly as a modulus for [`NumberFieldElement`] arithmetic
+    pub fn minimal_polynomial(&self) -> &Polynomial<Ratio<BigInt>> {
+        &self.minimal_polynomial
+    }
+    /// the exact value of `alpha`, the generator this field was created from
+    pub fn primitive_element(&self) -> &RealAlgebraicNumber {
+        &self.primitive_element
+    }
+}
+
+/// the reason an operation between two [`NumberFieldElement`]s failed: they belong to different [`NumberField`]s
+#[derive(Clone, Debug)]
+pub struct MismatchedNumberFieldsError {
+    pub lhs_field: Arc<NumberField>,
+    pub rhs_field: Arc<NumberField>,
+}
+
+impl fmt::Display for MismatchedNumberFieldsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "number fields don't match: {:?} != {:?}",
+            self.lhs_field, self.rhs_field
+        )
+    }
+}
+
+impl Error for MismatchedNumberFieldsError {}
+
+/// an element of a [`NumberField`], represented as a polynomial of degree
+/// less than the field's degree, with rational coefficients, reduced
+/// modulo the field's minimal polynomial
+#[derive(Clone, Debug)]
+pub struct NumberFieldElement {
+    /// coefficients of `alpha^0 ..= alpha^(field.degree() - 1)`, always of length `field.degree()`
+    coefficients: Vec<Ratio<BigInt>>,
+    field: Arc<NumberField>,
+}
+
+impl NumberFieldElement {
+    /// creates a [`NumberFieldElement`] with the given coefficients of `alpha^0, alpha^1, ...`,
+    /// padding with zeros or reducing modulo `field`'s minimal polynomial as needed
+    pub fn new(coefficients: Vec<Ratio<BigInt>>, field: Arc<NumberField>) -> Self {
+        let polynomial: Polynomial<Ratio<BigInt>> = coefficients.into_iter().collect();
+        Self::from_polynomial(polynomial, field)
+    }
+    fn from_polynomial(polynomial: Polynomial<Ratio<BigInt>>, field: Arc<NumberField>) -> Self {
+        let polynomial = polynomial % field.minimal_polynomial();
+        let mut coefficients = polynomial.into_coefficients();
+        coefficients.resize(field.degree(), Ratio::zero());
+        Self { coefficients, field }
+    }
+    /// the zero element of `field`
+    pub fn zero(field: Arc<NumberField>) -> Self {
+        Self::new(Vec::new(), field)
+    }
+    /// the element of `field` equal to `alpha`, `field`'s generator
+    pub fn primitive_element(field: Arc<NumberField>) -> Self {
+        Self::new(vec![Ratio::zero(), Ratio::one()], field)
+    }
+    /// the element of `field` that the rational number `value` embeds to
+    pub fn from_rational(value: Ratio<BigInt>, field: Arc<NumberField>) -> Self {
+        Self::new(vec![value], field)
+    }
+    /// coefficients of `alpha^0 ..= alpha^(field().degree() - 1)`
+    pub fn coefficients(&self) -> &[Ratio<BigInt>] {
+        &self.coefficients
+    }
+    pub fn field(&self) -> &Arc<NumberField> {
+        &self.field
+    }
+    fn as_polynomial(&self) -> Polynomial<Ratio<BigInt>> {
+        self.coefficients.iter().cloned().collect()
+    }
+    /// the embedding of `self` back into [`RealAlgebraicNumber`], computed by evaluating
+    /// `self`'s polynomial at `field().primitive_element()`
+    pub fn to_real_algebraic_number(&self) -> RealAlgebraicNumber {
+        let mut retval = RealAlgebraicNumber::from(BigInt::zero());
+        for coefficient in self.coefficients.iter().rev() {
+            retval = retval * self.field.primitive_element()
+                + RealAlgebraicNumber::from(coefficient.clone());
+        }
+        retval
+    }
+    /// tries to express `value` as an element of `field`, succeeding only when `value` is
+    /// rational or is exactly `field`'s primitive element; recognizing an arbitrary element
+    /// of a proper subfield of `field` would need a much more expensive general algorithm
+    pub fn checked_from_real_algebraic_number(
+        value: &RealAlgebraicNumber,
+        field: Arc<NumberField>,
+    ) -> Option<Self> {
+        if let Some(value) = value.to_rational() {
+            return Some(Self::from_rational(value, field));
+        }
+        if *value == field.primitive_element {
+            return Some(Self::primitive_element(field));
+        }
+        None
+    }
+    fn has_matching_field(&self, rhs: &Self) -> bool {
+        Arc::ptr_eq(&self.field, &rhs.field) || self.field == rhs.field
+    }
+    fn mismatch_error(&self, rhs: &Self) -> MismatchedNumberFieldsError {
+        MismatchedNumberFieldsError {
+            lhs_field: self.field.clone(),
+            rhs_field: rhs.field.clone(),
+        }
+    }
+    /// like `self + rhs`, but returns a [`MismatchedNumberFieldsError`] instead of panicking
+    /// when `self` and `rhs` belong to different number fields
+    pub fn try_add(&self, rhs: &Self) -> Result<Self, MismatchedNumberFieldsError> {
+        if !self.has_matching_field(rhs) {
+            return Err(self.mismatch_error(rhs));
+        }
+        let coefficients = self
+            .coefficients
+            .iter()
+            .zip(&rhs.coefficients)
+            .map(|(l, r)| l + r)
+            .collect();
+        Ok(Self {
+            coefficients,
+            field: self.field.clone(),
+        })
+    }
+    /// like `self - rhs`, but returns a [`MismatchedNumberFieldsError`] instead of panicking
+    /// when `self` and `rhs` belong to different number fields
+    pub fn try_sub(&self, rhs: &Self) -> Result<Self, MismatchedNumberFieldsError> {
+        if !self.has_matching_field(rhs) {
+            return Err(self.mismatch_error(rhs));
+        }
+        let coefficients = self
+            .coefficients
+            .iter()
+            .zip(&rhs.coefficients)
+            .map(|(l, r)| l - r)
+            .collect();
+        Ok(Self {
+            coefficients,
+            field: self.field.clone(),
+        })
+    }
+    /// like `self * rhs`, but returns a [`MismatchedNumberFieldsError`] instead of panicking
+    /// when `self` and `rhs` belong to different number fields
+    pub fn try_mul(&self, rhs: &Self) -> Result<Self, MismatchedNumberFieldsError> {
+        if !self.has_matching_field(rhs) {
+            return Err(self.mismatch_error(rhs));
+        }
+        let product = self.as_polynomial() * rhs.as_polynomial();
+        Ok(Self::from_polynomial(product, self.field.clone()))
+    }
+    /// the multiplicative inverse of `self`, or `None` if `self` is zero
+    pub fn checked_recip(&self) -> Option<Self> {
+        let polynomial = self.as_polynomial();
+        if polynomial.is_zero() {
+            return None;
+        }
+        let ExtendedGCDResult { x, .. } = polynomial.extended_gcd(self.field.minimal_polynomial());
+        Some(Self::from_polynomial(x, self.field.clone()))
+    }
+    /// like `self / rhs`, but returns a [`MismatchedNumberFieldsError`] instead of panicking
+    /// when `self` and `rhs` belong to different number fields; panics if `rhs` is zero
+    pub fn try_div(&self, rhs: &Self) -> Result<Self, MismatchedNumberFieldsError> {
+        if !self.has_matching_field(rhs) {
+            return Err(self.mismatch_error(rhs));
+        }
+        let rhs_recip = rhs.checked_recip().expect("can't divide by zero");
+        self.try_mul(&rhs_recip)
+    }
+}
+
+impl Neg for NumberFieldElement {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self {
+            coefficients: self.coefficients.into_iter().map(|v| -v).collect(),
+            field: self.field,
+        }
+    }
+}
+
+impl Neg for &'_ NumberFieldElement {
+    type Output = NumberFieldElement;
+    fn neg(self) -> NumberFieldElement {
+        -self.clone()
+    }
+}
+
+macro_rules! impl_binary_op_using_try_fn {
+    ($op:ident, $fn:ident, $try_fn:ident) => {
+        impl $op<&'_ NumberFieldElement> for &'_ NumberFieldElement {
+            type Output = NumberFieldElement;
+            fn $fn(self, rhs: &NumberFieldElement) -> NumberFieldElement {
+                self.$try_fn(rhs).unwrap()
+            }
+        }
+        impl $op for NumberFieldElement {
+            type Output = NumberFieldElement;
+            fn $fn(self, rhs: Self) -> NumberFieldElement {
+                (&self).$fn(&rhs)
+            }
+        }
+        impl $op<&'_ NumberFieldElement> for NumberFieldElement {
+            type Output = NumberFieldElement;
+            fn $fn(self, rhs: &NumberFieldElement) -> NumberFieldElement {
+                (&self).$fn(rhs)
+            }
+        }
+        impl $op<NumberFieldElement> for &'_ NumberFieldElement {
+            type Output = NumberFieldElement;
+            fn $fn(self, rhs: NumberFieldElement) -> NumberFieldElement {
+                self.$fn(&rhs)
+            }
+        }
+    };
+}
+
+impl_binary_op_using_try_fn!(Add, add, try_add);
+impl_binary_op_using_try_fn!(Sub, sub, try_sub);
+impl_binary_op_using_try_fn!(Mul, mul, try_mul);
+impl_binary_op_using_try_fn!(Div, div, try_div);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn r(n: i64, d: i64) -> Ratio<BigInt> {
+        Ratio::new(n.into(), d.into())
+    }
+
+    fn sqrt2_field() -> Arc<NumberField> {
+        // sqrt(2) is the positive root of X^2 - 2
+        let sqrt2 = RealAlgebraicNumber::from(2).checked_pow(r(1, 2)).unwrap();
+        NumberField::new(sqrt2)
+    }
+
+    #[test]
+    fn test_degree() {
+        assert_eq!(sqrt2_field().degree(), 2);
+    }
+
+    #[test]
+    fn test_add_sub() {
+        let field = sqrt2_field();
+        let alpha = NumberFieldElement::primitive_element(field.clone());
+        let one = NumberFieldElement::from_rational(r(1, 1), field.clone());
+        let sum = &alpha + &one;
+        assert_eq!(sum.coefficients(), &[r(1, 1), r(1, 1)]);
+        let difference = &sum - &one;
+        assert_eq!(difference.coefficients(), alpha.coefficients());
+    }
+
+    #[test]
+    fn test_mul_reduces_using_minimal_polynomial() {
+        let field = sqrt2_field();
+        let alpha = NumberFieldElement::primitive_element(field.clone());
+        // alpha^2 == 2
+        let squared = &alpha * &alpha;
+        assert_eq!(squared.coefficients(), &[r(2, 1), r(0, 1)]);
+    }
+
+    #[test]
+    fn test_div_and_recip() {
+        let field = sqrt2_field();
+        let alpha = NumberFieldElement::primitive_element(field.clone());
+        // 1 / alpha == alpha / 2
+        let recip = alpha.checked_recip().unwrap();
+        assert_eq!(recip.coefficients(), &[r(0, 1), r(1, 2)]);
+        let quotient = &alpha / &alpha;
+        assert_eq!(quotient.coefficients(), &[r(1, 1), r(0, 1)]);
+    }
+
+    #[test]
+    fn test_mismatched_fields() {
+        let field1 = sqrt2_field();
+        let three = RealAlgebraicNumber::from(3).checked_pow(r(1, 2)).unwrap();
+        let field2 = NumberField::new(three);
+        let a = NumberFieldElement::primitive_element(field1);
+        let b = NumberFieldElement::primitive_element(field2);
+        assert!(a.try_add(&b).is_err());
+    }
+
+    #[test]
+    fn test_round_trip_through_real_algebraic_number() {
+        let field = sqrt2_field();
+        let alpha = NumberFieldElement::primitive_element(field.clone());
+        let squared_plus_one = &(&alpha * &alpha) + &NumberFieldElement::from_rational(r(1, 1), field.clone());
+        assert_eq!(
+            squared_plus_one.to_real_algebraic_number(),
+            RealAlgebraicNumber::from(3)
+        );
+        let recovered =
+            NumberFieldElement::checked_from_real_algebraic_number(&field.primitive_element().clone(), field)
+                .unwrap();
+        assert_eq!(recovered.coefficients(), alpha.coefficients());
+    }
+}
@@ -0,0 +1,283 @@
+// SPDX-License-Identifier: LGPL-2.1-or-later
+// See Notices.txt for copyright information
+
+//! finite-precision `p`-adic integers, backed by [`ModularInteger`] with a
+//! prime-power modulus, along with Newton/Hensel lifting for finding
+//! `p`-adic roots of integer polynomials; the natural complement to
+//! [`crate::algebraic_numbers::RealAlgebraicNumber`]'s real roots
+
+use crate::{mod_int::ModularInteger, polynomial::Polynomial};
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::Zero;
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// a `p`-adic integer known to a finite number of `p`-adic digits
+///
+/// represented as its residue modulo `prime.pow(precision)`; arithmetic
+/// between two [`PAdicInteger`]s known to different precisions produces a
+/// result known only to the smaller of the two precisions, since that's all
+/// the information the inputs actually carry
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PAdicInteger {
+    prime: BigInt,
+    precision: u32,
+    residue: ModularInteger<BigInt, BigInt>,
+}
+
+impl PAdicInteger {
+    /// constructs the `p`-adic integer congruent to `value` modulo
+    /// `prime.pow(precision)`
+    pub fn new(value: BigInt, prime: BigInt, precision: u32) -> Self {
+        assert!(precision >= 1, "p-adic precision must be at least 1 digit");
+        let modulus = prime.pow(precision);
+        PAdicInteger {
+            residue: ModularInteger::new(value, modulus),
+            prime,
+            precision,
+        }
+    }
+    pub fn prime(&self) -> &BigInt {
+        &self.prime
+    }
+    /// the number of known `p`-adic digits
+    pub fn precision(&self) -> u32 {
+        self.precision
+    }
+    /// the canonical representative of `self`, in `0..prime.pow(precision)`
+    pub fn value(&self) -> &BigInt {
+        self.residue.value()
+    }
+    /// `prime.pow(precision)`
+    pub fn modulus(&self) -> &BigInt {
+        self.residue.modulus()
+    }
+    /// truncates `self` to `precision` known digits, discarding the
+    /// higher-order digits; `precision` must not be greater than
+    /// `self.precision()`, since digits beyond what's already known can't
+    /// be recovered
+    #[must_use]
+    pub fn with_precision(&self, precision: u32) -> Self {
+        assert!(
+            precision <= self.precision,
+            "can't increase a p-adic integer's precision without more information"
+        );
+        Self::new(self.residue.value().clone(), self.prime.clone(), precision)
+    }
+    fn require_matching_prime(&self, rhs: &Self) {
+        assert_eq!(
+            self.prime, rhs.prime,
+            "p-adic integers must have the same prime to be combined"
+        );
+    }
+    /// truncates `self` and `rhs` to whichever has the smaller precision
+    fn to_common_precision(&self, rhs: &Self) -> (Self, Self) {
+        self.require_matching_prime(rhs);
+        let precision = self.precision.min(rhs.precision);
+        (self.with_precision(precision), rhs.with_precision(precision))
+    }
+    /// the multiplicative inverse of `self`, or `None` if `self` isn't a
+    /// `p`-adic unit (i.e. `prime` divides `self.value()`)
+    #[must_use]
+    pub fn checked_recip(&self) -> Option<Self> {
+        Some(PAdicInteger {
+            residue: self.residue.checked_inverse()?,
+            prime: self.prime.clone(),
+            precision: self.precision,
+        })
+    }
+    /// lifts `approx_root`, a simple root of `f` modulo `prime` (that is,
+    /// `f(approx_root) == 0 (mod prime)` and
+    /// `f.derivative()(approx_root) != 0 (mod prime)`), to a root of `f`
+    /// known to `precision` `p`-adic digits, using Newton's method; this is
+    /// Hensel's lemma, and each iteration below doubles the number of
+    /// correct digits
+    ///
+    /// returns `None` if `approx_root` isn't actually a simple root of `f`
+    /// modulo `prime`
+    pub fn hensel_lift_root(
+        f: &Polynomial<BigInt>,
+        prime: &BigInt,
+        approx_root: &BigInt,
+        precision: u32,
+    ) -> Option<PAdicInteger> {
+        assert!(precision >= 1, "p-adic precision must be at least 1 digit");
+        let derivative = f.derivative();
+        let mut root = approx_root.mod_floor(prime);
+        if !f.eval(&root).mod_floor(prime).is_zero() {
+            return None;
+        }
+        let mut current_precision = 1u32;
+        while current_precision < precision {
+            let next_precision = (current_precision * 2).min(precision);
+            let modulus = prime.pow(next_precision);
+            let f_value = f.eval(&root).mod_floor(&modulus);
+            let f_derivative_value = derivative.eval(&root).mod_floor(&modulus);
+            let inverse_derivative =
+                ModularInteger::new(f_derivative_value, modulus.clone()).checked_inverse()?;
+            let correction = ModularInteger::new(f_value, modulus.clone()) * inverse_derivative;
+            root = (root - correction.value()).mod_floor(&modulus);
+            current_precision = next_precision;
+        }
+        Some(PAdicInteger::new(root, prime.clone(), precision))
+    }
+    /// finds every simple root of `f` modulo `prime` by brute-force search
+    /// over `0..prime`, then lifts each one to `precision` digits using
+    /// [`Self::hensel_lift_root`]; intended for small `prime`, and doesn't
+    /// find roots that are repeated modulo `prime`
+    pub fn find_roots(f: &Polynomial<BigInt>, prime: &BigInt, precision: u32) -> Vec<PAdicInteger> {
+        let derivative = f.derivative();
+        let mut candidate = BigInt::zero();
+        let mut roots = Vec::new();
+        while &candidate < prime {
+            if f.eval(&candidate).mod_floor(prime).is_zero()
+                && !derivative.eval(&candidate).mod_floor(prime).is_zero()
+            {
+                if let Some(root) = Self::hensel_lift_root(f, prime, &candidate, precision) {
+                    roots.push(root);
+                }
+            }
+            candidate += 1i32;
+        }
+        roots
+    }
+}
+
+impl Add for &'_ PAdicInteger {
+    type Output = PAdicInteger;
+    fn add(self, rhs: &PAdicInteger) -> PAdicInteger {
+        let (lhs, rhs) = self.to_common_precision(rhs);
+        PAdicInteger {
+            precision: lhs.precision,
+            prime: lhs.prime.clone(),
+            residue: lhs.residue + rhs.residue,
+        }
+    }
+}
+
+impl Add for PAdicInteger {
+    type Output = PAdicInteger;
+    fn add(self, rhs: PAdicInteger) -> PAdicInteger {
+        &self + &rhs
+    }
+}
+
+impl Sub for &'_ PAdicInteger {
+    type Output = PAdicInteger;
+    fn sub(self, rhs: &PAdicInteger) -> PAdicInteger {
+        let (lhs, rhs) = self.to_common_precision(rhs);
+        PAdicInteger {
+            precision: lhs.precision,
+            prime: lhs.prime.clone(),
+            residue: lhs.residue - rhs.residue,
+        }
+    }
+}
+
+impl Sub for PAdicInteger {
+    type Output = PAdicInteger;
+    fn sub(self, rhs: PAdicInteger) -> PAdicInteger {
+        &self - &rhs
+    }
+}
+
+impl Mul for &'_ PAdicInteger {
+    type Output = PAdicInteger;
+    fn mul(self, rhs: &PAdicInteger) -> PAdicInteger {
+        let (lhs, rhs) = self.to_common_precision(rhs);
+        PAdicInteger {
+            precision: lhs.precision,
+            prime: lhs.prime.clone(),
+            residue: lhs.residue * rhs.residue,
+        }
+    }
+}
+
+impl Mul for PAdicInteger {
+    type Output = PAdicInteger;
+    fn mul(self, rhs: PAdicInteger) -> PAdicInteger {
+        &self * &rhs
+    }
+}
+
+impl Neg for &'_ PAdicInteger {
+    type Output = PAdicInteger;
+    fn neg(self) -> PAdicInteger {
+        let mut residue = self.residue.clone();
+        residue.neg_assign();
+        PAdicInteger {
+            residue,
+            prime: self.prime.clone(),
+            precision: self.precision,
+        }
+    }
+}
+
+impl Neg for PAdicInteger {
+    type Output = PAdicInteger;
+    fn neg(self) -> PAdicInteger {
+        -&self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = PAdicInteger::new(BigInt::from(7), BigInt::from(5), 3); // 7 mod 125
+        let b = PAdicInteger::new(BigInt::from(4), BigInt::from(5), 2); // 4 mod 25
+        // combining differing precisions truncates to the smaller one
+        let sum = &a + &b;
+        assert_eq!(sum.precision(), 2);
+        assert_eq!(*sum.value(), BigInt::from(11) % BigInt::from(25));
+        let product = a.clone() * b.clone();
+        assert_eq!(product.precision(), 2);
+        assert_eq!(*product.value(), (BigInt::from(7) * BigInt::from(4)) % BigInt::from(25));
+        let difference = a - b;
+        assert_eq!(*difference.value(), BigInt::from(3));
+    }
+
+    #[test]
+    fn test_checked_recip() {
+        // 3 is a unit mod 5, since gcd(3, 5) == 1
+        let unit = PAdicInteger::new(BigInt::from(3), BigInt::from(5), 2);
+        let inverse = unit.checked_recip().expect("3 is a unit mod 5^2");
+        assert_eq!((unit * inverse).value(), &BigInt::from(1));
+        // 5 is not a unit mod 5
+        let non_unit = PAdicInteger::new(BigInt::from(5), BigInt::from(5), 2);
+        assert!(non_unit.checked_recip().is_none());
+    }
+
+    #[test]
+    fn test_hensel_lift_root() {
+        // X^2 - 2 has a root mod 7 (3^2 == 9 == 2 (mod 7)); lift it to a
+        // 5-digit-precision 7-adic square root of 2
+        let f = Polynomial::from(vec![BigInt::from(-2), BigInt::from(0), BigInt::from(1)]);
+        let prime = BigInt::from(7);
+        let root = PAdicInteger::hensel_lift_root(&f, &prime, &BigInt::from(3), 5)
+            .expect("2 is a quadratic residue mod 7");
+        assert_eq!(root.precision(), 5);
+        let value = root.value();
+        let modulus = root.modulus();
+        let residual = (value * value - BigInt::from(2)).mod_floor(modulus);
+        assert!(residual.is_zero());
+    }
+
+    #[test]
+    fn test_find_roots() {
+        // X^2 - 2 has two roots mod 7: 3 and 4 (== -3)
+        let f = Polynomial::from(vec![BigInt::from(-2), BigInt::from(0), BigInt::from(1)]);
+        let prime = BigInt::from(7);
+        let mut roots = PAdicInteger::find_roots(&f, &prime, 4);
+        roots.sort_by(|a, b| a.value().cmp(b.value()));
+        assert_eq!(roots.len(), 2);
+        for root in &roots {
+            let value = root.value();
+            let modulus = root.modulus();
+            let residual = (value * value - BigInt::from(2)).mod_floor(modulus);
+            assert!(residual.is_zero());
+        }
+    }
+}
@@ -1,8 +1,11 @@
 // SPDX-License-Identifier: LGPL-2.1-or-later
 // See Notices.txt for copyright information
-use crate::traits::{
-    AlwaysExactDivAssign, CharacteristicZero, ExactDiv, ExactDivAssign, GCDAndLCM,
-    RingCharacteristic, GCD,
+use crate::{
+    traits::{
+        AlwaysExactDivAssign, CharacteristicZero, ExactDiv, ExactDivAssign, GCDAndLCM,
+        RingCharacteristic, GCD,
+    },
+    util::Sign,
 };
 use num_bigint::BigInt;
 use num_integer::Integer;
@@ -14,17 +17,35 @@ use std::{
     fmt, hash,
     iter::FromIterator,
     mem,
-    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    ops::{Add, AddAssign, Bound, Mul, MulAssign, Neg, RangeBounds, Sub, SubAssign},
     slice, vec,
 };
 
 mod add_sub;
+mod berlekamp;
+mod berlekamp_massey;
+mod complex_root_isolation;
 mod distinct_degree_factorization;
 mod div_rem;
 mod factorization_over_integers;
 mod gcd;
+mod gcd_free_basis;
+mod hard_case_generators;
+mod interpolation;
+mod interval_eval;
 mod mul;
+mod root_isolation;
 mod same_degree_factorization;
+mod simple_ring;
+mod sparse;
+
+pub use berlekamp_massey::berlekamp_massey;
+pub use factorization_over_integers::{FactorRecombinationAlgorithm, FactorizationOptions};
+pub use gcd_free_basis::{gcd_free_basis, GcdFreeBasis};
+pub use interpolation::DuplicateAbscissaError;
+pub use root_isolation::RootIsolationAlgorithm;
+pub use simple_ring::{SimpleRing, SimpleRingCoefficient};
+pub use sparse::SparsePolynomial;
 
 pub trait PolynomialCoefficientElement:
     PolynomialCoefficient<Divisor = DivisorIsOne>
@@ -837,6 +858,38 @@ impl<T: PolynomialCoefficient> FromIterator<T> for Polynomial<T> {
     }
 }
 
+/// builds a polynomial from `(index, coefficient)` pairs, treating each pair
+/// as adding `coefficient * X^index`; pairs are summed where indexes
+/// collide, and indexes that are never mentioned default to a zero
+/// coefficient
+impl<T: PolynomialCoefficient> FromIterator<(usize, T)> for Polynomial<T> {
+    fn from_iter<I: IntoIterator<Item = (usize, T)>>(iter: I) -> Self {
+        let mut coefficients: Vec<Option<T>> = Vec::new();
+        for (index, coefficient) in iter {
+            if index >= coefficients.len() {
+                coefficients.resize_with(index + 1, || None);
+            }
+            coefficients[index] = Some(match coefficients[index].take() {
+                Some(existing) => existing + coefficient,
+                None => coefficient,
+            });
+        }
+        let zero_template = coefficients.iter().flatten().next().cloned();
+        let coefficients: Vec<T> = coefficients
+            .into_iter()
+            .map(|coefficient| match coefficient {
+                Some(coefficient) => coefficient,
+                None => T::make_zero_coefficient_from_coefficient(Cow::Borrowed(
+                    zero_template
+                        .as_ref()
+                        .expect("a gap can only exist once some coefficient has been added"),
+                )),
+            })
+            .collect();
+        Polynomial::from(coefficients)
+    }
+}
+
 impl<T: PolynomialCoefficient> Default for Polynomial<T> {
     fn default() -> Self {
         Polynomial {
@@ -1142,6 +1195,79 @@ impl<T: PolynomialCoefficient> Into<(Vec<T::Element>, T::Divisor)> for Polynomia
     }
 }
 
+/// where to evaluate a Sturm sequence's sign variations, for
+/// [`Polynomial::count_real_roots_in`]
+#[derive(Copy, Clone)]
+enum RootCountBound<'a, T> {
+    NegativeInfinity,
+    /// evaluate the limit approaching `.0` from above (`true`) or below
+    /// (`false`)
+    Value(&'a T, bool),
+    PositiveInfinity,
+}
+
+/// the sign of `polynomial` in the limit as it is evaluated approaching
+/// `at` from the given side; unlike evaluating directly at `at`, this is
+/// well-defined even when `polynomial` has a root at `at`, since it looks
+/// at successive derivatives until it finds one that doesn't also vanish
+/// there
+fn one_sided_sign<T>(polynomial: &Polynomial<T>, at: &T, approach_from_above: bool) -> Option<Sign>
+where
+    T: PolynomialCoefficient + PartialOrd + Zero,
+{
+    let mut polynomial = Cow::Borrowed(polynomial);
+    let mut derivative_order_is_odd = false;
+    loop {
+        if let Some(sign) = Sign::new(&polynomial.eval(at)) {
+            return Some(if !approach_from_above && derivative_order_is_odd {
+                -sign
+            } else {
+                sign
+            });
+        }
+        if polynomial.is_empty() {
+            return None;
+        }
+        polynomial = Cow::Owned(polynomial.derivative());
+        derivative_order_is_odd = !derivative_order_is_odd;
+    }
+}
+
+/// counts the sign variations in `sturm_sequence` at `at`, per Sturm's
+/// theorem; terms that evaluate to zero (in the limit, when `at` is a
+/// value approached from one side) are skipped rather than treated as a
+/// sign change
+fn sign_variations<T>(sturm_sequence: &[Polynomial<T>], at: RootCountBound<T>) -> usize
+where
+    T: PolynomialCoefficient + PartialOrd + Zero,
+{
+    let mut sign_variations = 0;
+    let mut last_sign = None;
+    for polynomial in sturm_sequence {
+        let sign = match at {
+            RootCountBound::PositiveInfinity => Sign::new(&polynomial.highest_power_coefficient()),
+            RootCountBound::NegativeInfinity => {
+                let sign = Sign::new(&polynomial.highest_power_coefficient());
+                if polynomial.degree().unwrap_or(0).is_odd() {
+                    sign.map(Neg::neg)
+                } else {
+                    sign
+                }
+            }
+            RootCountBound::Value(at, approach_from_above) => {
+                one_sided_sign(polynomial, at, approach_from_above)
+            }
+        };
+        if let Some(sign) = sign {
+            if last_sign.map_or(false, |last_sign| last_sign != sign) {
+                sign_variations += 1;
+            }
+            last_sign = Some(sign);
+        }
+    }
+    sign_variations
+}
+
 impl<T: PolynomialCoefficient> Polynomial<T> {
     pub fn make_monomial(coefficient: T, variable_exponent: usize) -> Self {
         if T::is_coefficient_zero(&coefficient) {
@@ -1187,6 +1313,67 @@ impl<T: PolynomialCoefficient> Polynomial<T> {
             .map(|element| T::make_coefficient(Cow::Owned(element), Cow::Borrowed(divisor)))
             .collect()
     }
+    /// substitutes `constant_term + scale * X` for `X`; if `r` is a root of
+    /// `self`, then `(r - constant_term) / scale` is a root of the result
+    ///
+    /// this is the transform root-isolation algorithms use in their inner
+    /// loop to move a candidate search interval onto `(0, \u{221e})` or
+    /// similar, but it's just as useful directly, e.g. to change variables
+    pub fn shift_scale(&self, constant_term: &T, scale: &T) -> Polynomial<T> {
+        let linear = Polynomial::from(vec![constant_term.clone(), scale.clone()]);
+        let mut result = Polynomial::zero();
+        for coefficient in self.iter().collect::<Vec<_>>().into_iter().rev() {
+            result = result * &linear + Polynomial::from(vec![coefficient]);
+        }
+        result
+    }
+    /// the polynomial whose roots are `scale` times `self`'s roots;
+    /// computed as `coefficient[i] * scale.pow(degree - i)`, so unlike
+    /// [`Self::shift_scale`] it needs no division, even conceptually
+    pub fn scale_roots(&self, scale: &T) -> Polynomial<T> {
+        let mut coefficients: Vec<T> = self.iter().collect();
+        let mut power = None::<T>;
+        for coefficient in coefficients.iter_mut().rev() {
+            if let Some(power) = &power {
+                *coefficient = coefficient.clone() * power.clone();
+            }
+            power = Some(match power {
+                Some(power) => power * scale.clone(),
+                None => scale.clone(),
+            });
+        }
+        Polynomial::from(coefficients)
+    }
+    /// sets the coefficient of `X^index` to `value`, growing `self` (padding
+    /// with zero coefficients) if `index` is beyond the current degree
+    ///
+    /// there's no `coefficient_mut` returning `&mut T`: coefficients aren't
+    /// generally stored as `T` internally (see [`PolynomialCoefficient`]'s
+    /// `Element`/`Divisor` split, used e.g. to share one denominator across
+    /// all of a polynomial's coefficients), so a single coefficient can't be
+    /// mutated in place without touching the others
+    pub fn set_coefficient(&mut self, index: usize, value: T)
+    where
+        T: Zero,
+    {
+        let mut coefficients = mem::take(self).into_coefficients();
+        if index >= coefficients.len() {
+            coefficients.resize_with(index + 1, T::zero);
+        }
+        coefficients[index] = value;
+        *self = coefficients.into();
+    }
+    /// applies `f` to every coefficient in turn, then rebuilds `self` from
+    /// the results; a convenience for transforms that would otherwise need
+    /// to be written out as "destructure into a `Vec`, transform, rebuild"
+    /// at every call site
+    pub fn for_each_coefficient_mut<F: FnMut(&mut T)>(&mut self, mut f: F) {
+        let mut coefficients = mem::take(self).into_coefficients();
+        for coefficient in &mut coefficients {
+            f(coefficient);
+        }
+        *self = coefficients.into();
+    }
     pub fn split_out_divisor(self) -> (Vec<T::Element>, T::Divisor) {
         self.into()
     }
@@ -1205,6 +1392,13 @@ impl<T: PolynomialCoefficient> Polynomial<T> {
     pub fn degree(&self) -> Option<usize> {
         self.len().checked_sub(1)
     }
+    /// reserves capacity for at least `degree + 1` coefficients, so that
+    /// accumulating up to that degree (e.g. via repeated [`AddAssign`]) does
+    /// not reallocate; a no-op if `self` already has enough capacity
+    pub fn reserve_degree(&mut self, degree: usize) {
+        let additional = (degree + 1).saturating_sub(self.elements.len());
+        self.elements.reserve(additional);
+    }
     fn normalize(&mut self) {
         while let Some(last) = self.elements.last() {
             if !T::is_element_zero(last) {
@@ -1414,6 +1608,32 @@ impl<T: PolynomialCoefficient> Polynomial<T> {
             },
         )
     }
+    /// counts the distinct real roots of `self` that lie in `range`,
+    /// using Sturm's theorem; `range` may be bounded, half-open, or fully
+    /// unbounded (`..`), matching the usual [`RangeBounds`] impls (`a..b`,
+    /// `a..=b`, `a..`, `..b`, `..=b`, `..`)
+    pub fn count_real_roots_in<R>(&self, range: R) -> usize
+    where
+        T: GCD<Output = T> + PartialOrd + Zero + for<'a> ExactDiv<&'a T, Output = T>,
+        R: RangeBounds<T>,
+    {
+        let sturm_sequence = self.to_primitive_sturm_sequence();
+        // an included bound must count a root sitting exactly on it, so
+        // it's evaluated as the limit from the far side of that root (from
+        // below at the start, from above at the end); an excluded bound
+        // must not count such a root, so it's evaluated from the near side
+        let start_bound = match range.start_bound() {
+            Bound::Unbounded => RootCountBound::NegativeInfinity,
+            Bound::Included(v) => RootCountBound::Value(v, false),
+            Bound::Excluded(v) => RootCountBound::Value(v, true),
+        };
+        let end_bound = match range.end_bound() {
+            Bound::Unbounded => RootCountBound::PositiveInfinity,
+            Bound::Included(v) => RootCountBound::Value(v, true),
+            Bound::Excluded(v) => RootCountBound::Value(v, false),
+        };
+        sign_variations(&sturm_sequence, start_bound) - sign_variations(&sturm_sequence, end_bound)
+    }
     fn convert_to_derivative(&mut self) {
         if self.is_empty() {
             return;
@@ -1445,6 +1665,36 @@ impl<T: PolynomialCoefficient> Polynomial<T> {
         }
         .into_normalized()
     }
+    /// computes the `order`th derivative of `self`, i.e. `self.derivative()`
+    /// applied `order` times
+    pub fn nth_derivative(&self, order: usize) -> Self {
+        let mut retval = self.clone();
+        for _ in 0..order {
+            if retval.is_empty() {
+                break;
+            }
+            retval = retval.into_derivative();
+        }
+        retval
+    }
+    /// computes an antiderivative of `self`: the unique polynomial with
+    /// zero constant term whose derivative is `self`
+    pub fn antiderivative(&self) -> Self
+    where
+        T: PolynomialDivSupported + FromPrimitive,
+    {
+        if self.is_empty() {
+            return Self::zero();
+        }
+        let zero = T::make_zero_coefficient_from_element(Cow::Borrowed(&self.elements[0]));
+        std::iter::once(zero)
+            .chain(self.iter().enumerate().map(|(index, coefficient)| {
+                let divisor = T::from_usize(index + 1)
+                    .expect("can't convert antiderivative term index to coefficient");
+                coefficient.exact_div(divisor)
+            }))
+            .collect()
+    }
     fn eval_helper<
         V: for<'a> Mul<&'a V, Output = V> + Add<T, Output = V>,
         I: DoubleEndedIterator + Iterator<Item = T>,
@@ -1541,6 +1791,64 @@ impl<T: PolynomialCoefficient> Polynomial<T> {
     {
         self.nonzero_manhattan_norm().unwrap_or_else(T::zero)
     }
+    /// the `l1` norm, the sum of the absolute values of the coefficients;
+    /// same as [`Self::manhattan_norm`]
+    #[must_use]
+    pub fn l1_norm(&self) -> T
+    where
+        T: PolynomialCoefficientAbsSupported + Zero,
+    {
+        self.manhattan_norm()
+    }
+    /// the `l2` norm, the square root of the sum of the squares of the
+    /// coefficients; returned as `f64` since the exact value is
+    /// irrational in general, even for integer or rational coefficients
+    #[must_use]
+    pub fn l2_norm(&self) -> f64
+    where
+        T: PolynomialCoefficientAbsSupported + ToPrimitive,
+    {
+        self.iter()
+            .map(|coefficient| {
+                let value = T::coefficient_abs(coefficient)
+                    .to_f64()
+                    .expect("coefficient too big to convert to f64");
+                value * value
+            })
+            .sum::<f64>()
+            .sqrt()
+    }
+    /// an upper bound on the Mahler measure of `self`, using Landau's
+    /// inequality: the Mahler measure of a polynomial is at most its `l2`
+    /// norm; used to bound the size of the coefficients of a polynomial's
+    /// factors when choosing a lifting precision for modular factorization
+    /// algorithms
+    #[must_use]
+    pub fn mahler_measure_upper_bound(&self) -> f64
+    where
+        T: PolynomialCoefficientAbsSupported + ToPrimitive,
+    {
+        self.l2_norm()
+    }
+}
+
+impl Polynomial<BigInt> {
+    /// equivalent to `self.scale_roots(&BigInt::from(2).pow(log2_scale))`,
+    /// but computed with a shift per coefficient instead of a full
+    /// multiplication, for the common case of scaling by a power of two
+    #[must_use]
+    pub fn scale_roots_by_power_of_two(&self, log2_scale: u32) -> Polynomial<BigInt> {
+        let degree = match self.degree() {
+            None => return Polynomial::zero(),
+            Some(degree) => degree,
+        };
+        let coefficients: Vec<BigInt> = self
+            .iter()
+            .enumerate()
+            .map(|(index, coefficient)| coefficient << (log2_scale * (degree - index) as u32))
+            .collect();
+        Polynomial::from(coefficients)
+    }
 }
 
 #[derive(Clone, Eq, Hash, PartialEq, Debug)]
@@ -1622,6 +1930,15 @@ impl<T: PolynomialDivSupported + PolynomialReducingFactorSupported> Polynomial<T
     pub fn is_square_free(&self) -> bool {
         GCD::gcd(self, &self.derivative()).degree().unwrap_or(0) == 0
     }
+    /// `self` divided by `gcd(self, self.derivative())`; has the same
+    /// roots as `self`, each with multiplicity one, but is much cheaper to
+    /// compute than the full
+    /// [`Self::square_free_factorization_using_yuns_algorithm`] when only
+    /// the combined square-free part is needed, not the individual factors
+    pub fn squarefree_part(&self) -> Self {
+        let gcd = GCD::gcd(self, &self.derivative());
+        self / &gcd
+    }
 }
 
 impl<'a, T: PolynomialCoefficient> IntoIterator for &'a Polynomial<T> {
@@ -1675,6 +1992,78 @@ impl<T: fmt::Display + PolynomialCoefficient> fmt::Display for Polynomial<T> {
     }
 }
 
+const SUPERSCRIPT_DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+
+fn power_to_superscript(mut power: usize) -> String {
+    if power == 0 {
+        return SUPERSCRIPT_DIGITS[0].to_string();
+    }
+    let mut digits = Vec::new();
+    while power != 0 {
+        digits.push(SUPERSCRIPT_DIGITS[power % 10]);
+        power /= 10;
+    }
+    digits.iter().rev().collect()
+}
+
+impl<T: fmt::Display + PolynomialCoefficient> Polynomial<T> {
+    /// renders `self` as a LaTeX math expression (without surrounding `$`
+    /// delimiters), so it can be pasted directly into a paper or notebook
+    pub fn to_latex(&self) -> String {
+        if self.is_empty() {
+            return "0".to_string();
+        }
+        let variable_name = get_variable_name(T::NESTING_DEPTH);
+        let left_paren = if T::NESTING_DEPTH != 0 { "(" } else { "" };
+        let right_paren = if T::NESTING_DEPTH != 0 { ")" } else { "" };
+        let mut retval = String::new();
+        for (power, coefficient) in self.iter().enumerate() {
+            match power {
+                0 => retval += &format!("{}", coefficient),
+                1 => retval += &format!(
+                    " + {}{}{}{}",
+                    left_paren, coefficient, right_paren, variable_name
+                ),
+                _ => retval += &format!(
+                    " + {}{}{}{}^{{{}}}",
+                    left_paren, coefficient, right_paren, variable_name, power
+                ),
+            }
+        }
+        retval
+    }
+    /// renders `self` using unicode superscript characters for exponents
+    /// (e.g. `1 + 2*X + 3*X²`) instead of the ASCII `^` notation used by
+    /// [`fmt::Display`]
+    pub fn to_unicode_string(&self) -> String {
+        if self.is_empty() {
+            return "0".to_string();
+        }
+        let variable_name = get_variable_name(T::NESTING_DEPTH);
+        let left_paren = if T::NESTING_DEPTH != 0 { "(" } else { "" };
+        let right_paren = if T::NESTING_DEPTH != 0 { ")" } else { "" };
+        let mut retval = String::new();
+        for (power, coefficient) in self.iter().enumerate() {
+            match power {
+                0 => retval += &format!("{}", coefficient),
+                1 => retval += &format!(
+                    " + {}{}{}*{}",
+                    left_paren, coefficient, right_paren, variable_name
+                ),
+                _ => retval += &format!(
+                    " + {}{}{}*{}{}",
+                    left_paren,
+                    coefficient,
+                    right_paren,
+                    variable_name,
+                    power_to_superscript(power)
+                ),
+            }
+        }
+        retval
+    }
+}
+
 macro_rules! impl_from_primitive_fn {
     ($f:ident, $t:ident) => {
         fn $f(v: $t) -> Option<Self> {
@@ -1766,6 +2155,91 @@ mod tests {
         assert_eq!(poly.eval(&10), 4321);
     }
 
+    #[test]
+    fn test_norms() {
+        // 3 - 4*X + 5*X^2
+        let poly = Polynomial::from(vec![3i64, -4, 5]);
+        assert_eq!(poly.l1_norm(), 12);
+        assert_eq!(poly.max_norm(), 5);
+        assert!((poly.l2_norm() - (50.0f64).sqrt()).abs() < 1e-9);
+        assert!((poly.mahler_measure_upper_bound() - (50.0f64).sqrt()).abs() < 1e-9);
+        let zero = Polynomial::<i64>::from(vec![]);
+        assert_eq!(zero.l1_norm(), 0);
+        assert_eq!(zero.l2_norm(), 0.0);
+    }
+
+    #[test]
+    fn test_from_iterator_indexed() {
+        let poly: Polynomial<i64> = vec![(2, 3i64), (0, 1i64)].into_iter().collect();
+        assert_eq!(poly, Polynomial::from(vec![1, 0, 3]));
+        // overlapping indexes are summed
+        let poly: Polynomial<i64> = vec![(1, 2i64), (1, 3i64)].into_iter().collect();
+        assert_eq!(poly, Polynomial::from(vec![0, 5]));
+        let poly: Polynomial<i64> = Vec::<(usize, i64)>::new().into_iter().collect();
+        assert_eq!(poly, Polynomial::from(vec![]));
+    }
+
+    #[test]
+    fn test_set_coefficient() {
+        let mut poly = Polynomial::from(vec![1i64, 2, 3]);
+        poly.set_coefficient(1, 20);
+        assert_eq!(poly, Polynomial::from(vec![1, 20, 3]));
+        poly.set_coefficient(4, 7);
+        assert_eq!(poly, Polynomial::from(vec![1, 20, 3, 0, 7]));
+    }
+
+    #[test]
+    fn test_for_each_coefficient_mut() {
+        let mut poly = Polynomial::from(vec![1i64, 2, 3]);
+        poly.for_each_coefficient_mut(|coefficient| *coefficient *= 10);
+        assert_eq!(poly, Polynomial::from(vec![10, 20, 30]));
+    }
+
+    #[test]
+    fn test_reserve_degree() {
+        let mut poly = Polynomial::from(vec![1i64, 2, 3]);
+        poly.reserve_degree(9);
+        assert!(poly.elements.capacity() >= 10);
+        // reserving a degree that's already covered doesn't panic or shrink
+        poly.reserve_degree(0);
+        assert!(poly.elements.capacity() >= 10);
+        assert_eq!(poly, Polynomial::from(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn test_shift_scale() {
+        // (X - 1)^2 == X^2 - 2*X + 1
+        let poly = Polynomial::from(vec![1i64, -2, 1]);
+        // substituting X + 1 for X gives back X^2
+        let shifted = poly.shift_scale(&1, &1);
+        assert_eq!(shifted, Polynomial::from(vec![0, 0, 1]));
+        // substituting 2*X for X in X^2 - 4 gives 4*X^2 - 4
+        let poly = Polynomial::from(vec![-4i64, 0, 1]);
+        let scaled = poly.shift_scale(&0, &2);
+        assert_eq!(scaled, Polynomial::from(vec![-4, 0, 4]));
+    }
+
+    #[test]
+    fn test_scale_roots() {
+        // roots of X^2 - 2 are +-sqrt(2); doubling them gives roots
+        // +-2*sqrt(2), which are roots of X^2 - 8
+        let poly = Polynomial::from(vec![-2i64, 0, 1]);
+        assert_eq!(poly.scale_roots(&2), Polynomial::from(vec![-8, 0, 1]));
+    }
+
+    #[test]
+    fn test_scale_roots_by_power_of_two() {
+        let poly = Polynomial::from(vec![BigInt::from(-2), BigInt::from(0), BigInt::from(1)]);
+        assert_eq!(
+            poly.scale_roots_by_power_of_two(2),
+            poly.scale_roots(&BigInt::from(4))
+        );
+        assert_eq!(
+            Polynomial::<BigInt>::zero().scale_roots_by_power_of_two(3),
+            Polynomial::zero()
+        );
+    }
+
     #[test]
     fn test_display() {
         let mut poly = Polynomial::<i32>::from(vec![]);
@@ -1811,6 +2285,22 @@ mod tests {
         assert_eq!(format!("{}", poly), "0 + (0)*B + (0 + (0)*A + (0)*A^2 + (0 + (0)*Z + (0)*Z^2 + (0)*Z^3 + (0 + (0)*Y + (0)*Y^2 + (0)*Y^3 + (0)*Y^4 + (0 + 0*X + 0*X^2 + 0*X^3 + 0*X^4 + 0*X^5 + 1*X^6)*Y^5)*Z^4)*A^3)*B^2");
     }
 
+    #[test]
+    fn test_to_latex() {
+        let poly = Polynomial::<i32>::from(vec![]);
+        assert_eq!(poly.to_latex(), "0");
+        let poly = Polynomial::from(vec![1, 2, 3, 4]);
+        assert_eq!(poly.to_latex(), "1 + 2X + 3X^{2} + 4X^{3}");
+    }
+
+    #[test]
+    fn test_to_unicode_string() {
+        let poly = Polynomial::<i32>::from(vec![]);
+        assert_eq!(poly.to_unicode_string(), "0");
+        let poly = Polynomial::from(vec![1, 2, 3, 4]);
+        assert_eq!(poly.to_unicode_string(), "1 + 2*X + 3*X² + 4*X³");
+    }
+
     #[test]
     fn test_split_out_divisor() {
         let mut poly: Polynomial<Ratio<i32>> = (&[] as &[_]).into();
@@ -1911,6 +2401,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_count_real_roots_in() {
+        // (X - 1) * (X - 2) * (X - 3), roots at 1, 2, 3
+        let poly: Polynomial<Ratio<i64>> =
+            vec![(-6).into(), 11.into(), (-6).into(), 1.into()].into();
+        assert_eq!(poly.count_real_roots_in(..), 3);
+        assert_eq!(poly.count_real_roots_in(Ratio::new(0, 1)..Ratio::new(4, 1)), 3);
+        assert_eq!(poly.count_real_roots_in(Ratio::new(0, 1)..Ratio::new(2, 1)), 1);
+        assert_eq!(
+            poly.count_real_roots_in(Ratio::new(0, 1)..=Ratio::new(2, 1)),
+            2
+        );
+        assert_eq!(
+            poly.count_real_roots_in(Ratio::new(1, 1)..=Ratio::new(3, 1)),
+            3
+        );
+        assert_eq!(
+            poly.count_real_roots_in(Ratio::new(3, 2)..Ratio::new(5, 2)),
+            1
+        );
+        assert_eq!(poly.count_real_roots_in(Ratio::new(4, 1)..), 0);
+        assert_eq!(poly.count_real_roots_in(..Ratio::new(1, 1)), 0);
+        assert_eq!(poly.count_real_roots_in(Ratio::new(2, 1)..=Ratio::new(2, 1)), 1);
+        assert_eq!(poly.count_real_roots_in(Ratio::new(5, 2)..=Ratio::new(5, 2)), 0);
+    }
+
     #[test]
     fn test_primitive_part() {
         assert_eq!(
@@ -2101,6 +2617,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_squarefree_part() {
+        fn r(n: i64, d: i64) -> Ratio<BigInt> {
+            Ratio::new(n.into(), d.into())
+        }
+        // (X - 1)^2 * (X - 2)
+        let poly: Polynomial<Ratio<BigInt>> =
+            vec![r(-2, 1), r(5, 1), r(-4, 1), r(1, 1)].into();
+        let expected: Polynomial<Ratio<BigInt>> = vec![r(2, 1), r(-3, 1), r(1, 1)].into();
+        assert_eq!(poly.squarefree_part(), expected);
+        // already square-free
+        let square_free: Polynomial<Ratio<BigInt>> = vec![r(-2, 1), r(-3, 1), r(1, 1)].into();
+        assert_eq!(square_free.squarefree_part(), square_free);
+        // (X - 1/2)^3
+        let poly: Polynomial<Ratio<BigInt>> = vec![r(-1, 8), r(3, 4), r(-3, 2), r(1, 1)].into();
+        let expected: Polynomial<Ratio<BigInt>> = vec![r(-1, 2), r(1, 1)].into();
+        assert_eq!(poly.squarefree_part(), expected);
+    }
+
     #[test]
     fn test_make_monomial() {
         assert_eq!(
@@ -2113,4 +2648,35 @@ mod tests {
         );
         assert_eq!(Polynomial::zero(), Polynomial::make_monomial(0, 5));
     }
+
+    #[test]
+    fn test_nth_derivative() {
+        // p = 1 + 2*X + 3*X^2 + 4*X^3
+        let poly = Polynomial::from(vec![1, 2, 3, 4]);
+        assert_eq!(poly.nth_derivative(0), poly);
+        assert_eq!(poly.nth_derivative(1), poly.derivative());
+        assert_eq!(poly.nth_derivative(2), Polynomial::from(vec![6, 24]));
+        assert_eq!(poly.nth_derivative(3), Polynomial::from(vec![24]));
+        assert_eq!(poly.nth_derivative(4), Polynomial::zero());
+        assert_eq!(poly.nth_derivative(100), Polynomial::zero());
+    }
+
+    #[test]
+    fn test_antiderivative() {
+        let r = |n: i64, d: i64| Ratio::<BigInt>::new(n.into(), d.into());
+        let ri = |v: i64| Ratio::<BigInt>::from_integer(v.into());
+        // p = 1 + 2*X + 3*X^2, antiderivative is X + X^2 + X^3
+        let poly: Polynomial<Ratio<BigInt>> = vec![ri(1), ri(2), ri(3)].into();
+        assert_eq!(
+            poly.antiderivative(),
+            vec![ri(0), ri(1), ri(1), ri(1)].into()
+        );
+        assert_eq!(Polynomial::<Ratio<BigInt>>::zero().antiderivative(), Polynomial::zero());
+        // fractional coefficients from dividing by the new exponent
+        let poly: Polynomial<Ratio<BigInt>> = vec![ri(0), ri(1)].into();
+        assert_eq!(poly.antiderivative(), vec![ri(0), ri(0), r(1, 2)].into());
+        // antiderivative undoes derivative up to the (lost) constant term
+        let poly: Polynomial<Ratio<BigInt>> = vec![ri(5), ri(1), ri(1), ri(1)].into();
+        assert_eq!(poly.derivative().antiderivative(), poly - Polynomial::from(ri(5)));
+    }
 }
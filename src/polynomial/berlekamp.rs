@@ -0,0 +1,186 @@
+// SPDX-License-Identifier: LGPL-2.1-or-later
+// See Notices.txt for copyright information
+
+//! Berlekamp's matrix-nullspace algorithm for factoring a square-free
+//! monic polynomial over `GF(p)`, for any prime `p` including `p == 2`;
+//! unlike [`Polynomial::same_degree_factorization`](super::Polynomial::same_degree_factorization),
+//! this doesn't rely on `p` being odd, so it's the only option currently
+//! available for factoring over `GF(2)`
+
+use crate::{
+    mod_int::{ModularInteger, ModularMatrix, ModularReducePow, PrimeModulus},
+    polynomial::Polynomial,
+    traits::{ExtendedGCD, GCD},
+};
+use num_integer::Integer;
+use num_traits::CheckedRem;
+use std::{fmt, hash::Hash};
+
+/// below this many distinct residues, [`ModularMatrix::rank`]'s
+/// `O(degree^3)` Gaussian elimination is cheap enough that it's worth
+/// using [`Polynomial::factor_using_berlekamp_algorithm`] as the backend
+/// for [`Polynomial::factor_square_free_modular_polynomial`] instead of
+/// distinct-degree plus same-degree factorization; larger primes make the
+/// random splitting used by same-degree factorization the better choice,
+/// since its cost doesn't grow with `p`
+pub(crate) const MAX_CHARACTERISTIC_FOR_BERLEKAMP_ALGORITHM: u64 = 32;
+
+impl<V, M> Polynomial<ModularInteger<V, M>>
+where
+    V: ModularReducePow<usize> + Integer + GCD<Output = V> + ExtendedGCD + fmt::Debug + Hash,
+    M: PrimeModulus<V> + fmt::Debug + Hash,
+{
+    /// factors a square-free monic polynomial over `GF(p)` using
+    /// Berlekamp's algorithm: build the matrix of the Frobenius map
+    /// `x -> x^p mod self` in the power basis, find a basis for the
+    /// null space of that matrix minus the identity (the "Berlekamp
+    /// subalgebra"), and split `self` by taking the GCD of `self` with
+    /// each basis polynomial minus every constant in `GF(p)`
+    pub(crate) fn factor_using_berlekamp_algorithm(self) -> Vec<Polynomial<ModularInteger<V, M>>> {
+        let degree = match self.degree() {
+            None | Some(0) => return vec![self],
+            Some(degree) => degree,
+        };
+        let nonzero_highest_power_coefficient = self
+            .nonzero_highest_power_coefficient()
+            .expect("known to be non-zero");
+        assert!(
+            nonzero_highest_power_coefficient.value().is_one(),
+            "factor_using_berlekamp_algorithm requires a monic polynomial"
+        );
+        let modulus = nonzero_highest_power_coefficient.modulus().clone();
+        let characteristic = modulus.to_modulus().into_owned();
+        let one = ModularInteger::new(V::one(), modulus.clone());
+        let zero = ModularInteger::new(V::zero(), modulus.clone());
+        let x = Polynomial::make_monomial(one.clone(), 1);
+        let x_pow_characteristic = x.powmod(characteristic.clone(), &self);
+
+        // column i (for i in 0..degree) holds the coefficients of
+        // x^(i * characteristic) mod self in the power basis, so that a
+        // coefficient vector c is in the kernel of (q - identity) exactly
+        // when the polynomial c(x) satisfies c(x)^characteristic == c(x)
+        // mod self, i.e. c(x) is in the Berlekamp subalgebra
+        let mut current = Polynomial::from(one.clone());
+        let mut elements = vec![zero.clone(); degree * degree];
+        for i in 0..degree {
+            for j in 0..degree {
+                if let Some(coefficient) = current.nonzero_coefficient(j) {
+                    elements[j * degree + i] = coefficient;
+                }
+            }
+            current = (&current * &x_pow_characteristic)
+                .checked_rem(&self)
+                .expect("dividing by self, which is known to be non-zero");
+        }
+        let mut q_minus_identity = ModularMatrix::new(degree, degree, modulus.clone(), elements);
+        for i in 0..degree {
+            let diagonal_entry = q_minus_identity.get(i, i) - &one;
+            *q_minus_identity.get_mut(i, i) = diagonal_entry;
+        }
+
+        let basis = q_minus_identity.kernel_basis();
+        let factor_count = basis.len();
+        if factor_count <= 1 {
+            return vec![self];
+        }
+
+        let mut residues = Vec::new();
+        let mut residue = V::zero();
+        loop {
+            residues.push(ModularInteger::new(residue.clone(), modulus.clone()));
+            residue = residue + V::one();
+            if residue == characteristic {
+                break;
+            }
+        }
+
+        let mut factors = vec![self];
+        for basis_vector in &basis {
+            if factors.len() == factor_count {
+                break;
+            }
+            let basis_polynomial: Polynomial<_> = basis_vector.iter().cloned().collect();
+            if basis_polynomial.degree().unwrap_or(0) == 0 {
+                // constant polynomials are always in the null space but
+                // can't split anything
+                continue;
+            }
+            let mut next_factors = Vec::with_capacity(factors.len());
+            for factor in factors {
+                let pieces: Vec<_> = residues
+                    .iter()
+                    .map(|residue| factor.gcd(&(&basis_polynomial - residue)))
+                    .filter(|gcd| gcd.degree().unwrap_or(0) > 0)
+                    .collect();
+                if pieces.len() > 1 {
+                    next_factors.extend(pieces);
+                } else {
+                    next_factors.push(factor);
+                }
+            }
+            factors = next_factors;
+        }
+        factors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mod_int::KnownPrime;
+
+    fn make_poly(
+        poly: &[i32],
+        modulus: KnownPrime<i32>,
+    ) -> Polynomial<ModularInteger<i32, KnownPrime<i32>>> {
+        poly.iter()
+            .map(|&coefficient| ModularInteger::new(coefficient, modulus))
+            .collect()
+    }
+
+    fn test_case(poly: &[i32], expected_factors: &[&[i32]], modulus: KnownPrime<i32>) {
+        let poly = make_poly(poly, modulus);
+        let expected_factors: std::collections::HashSet<_> = expected_factors
+            .iter()
+            .map(|poly| make_poly(*poly, modulus))
+            .collect();
+        println!("poly: {}", poly);
+        println!("expected_factors:");
+        for factor in &expected_factors {
+            println!("    {}", factor);
+        }
+        let factors = poly.factor_using_berlekamp_algorithm();
+        let factors: std::collections::HashSet<_> = factors.into_iter().collect();
+        println!("factors:");
+        for factor in &factors {
+            println!("    {}", factor);
+        }
+        assert!(expected_factors == factors);
+    }
+
+    #[test]
+    fn test_factor_using_berlekamp_algorithm_mod_2() {
+        // x^4 + x + 1 is irreducible over GF(2)
+        test_case(&[1, 1, 0, 0, 1], &[&[1, 1, 0, 0, 1]], KnownPrime::new_unsafe(2));
+        // (x + 1) * (x^2 + x + 1) * (x^3 + x + 1) over GF(2)
+        test_case(
+            &[1, 1, 0, 0, 1, 0, 1],
+            &[&[1, 1], &[1, 1, 1], &[1, 1, 0, 1]],
+            KnownPrime::new_unsafe(2),
+        );
+    }
+
+    #[test]
+    fn test_factor_using_berlekamp_algorithm_mod_5() {
+        test_case(
+            &[4, 0, 0, 0, 1],
+            &[&[4, 1], &[3, 1], &[2, 1], &[1, 1]],
+            KnownPrime::new_unsafe(5),
+        );
+        test_case(
+            &[2, 2, 3, 1, 1],
+            &[&[1, 1, 1], &[2, 0, 1]],
+            KnownPrime::new_unsafe(5),
+        );
+    }
+}
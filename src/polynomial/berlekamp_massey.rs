@@ -0,0 +1,105 @@
+// SPDX-License-Identifier: LGPL-2.1-or-later
+// See Notices.txt for copyright information
+
+use crate::{
+    mod_int::{ModularInteger, ModularReducePow, PrimeModulus},
+    polynomial::Polynomial,
+    traits::{ExtendedGCD, GCD},
+};
+use num_integer::Integer;
+use std::{fmt, hash::Hash};
+
+/// finds the minimal linear recurrence relation satisfied by `sequence`
+/// using the Berlekamp-Massey algorithm
+///
+/// the returned polynomial `c` has constant term one and satisfies
+/// `sequence[i] == -sum(c.coefficient(j) * sequence[i - j] for j in 1..c.len())`
+/// for all `i` in `c.len() - 1 .. sequence.len()`
+///
+/// # Panics
+///
+/// panics if `sequence` is empty
+pub fn berlekamp_massey<V, M>(
+    sequence: &[ModularInteger<V, M>],
+) -> Polynomial<ModularInteger<V, M>>
+where
+    V: ModularReducePow<usize> + Integer + GCD<Output = V> + ExtendedGCD + fmt::Debug + Hash,
+    M: PrimeModulus<V> + fmt::Debug + Hash + Clone,
+{
+    let modulus = sequence
+        .first()
+        .expect("berlekamp_massey requires a non-empty sequence")
+        .modulus()
+        .clone();
+    let zero = ModularInteger::new(V::zero(), modulus.clone());
+    let one = ModularInteger::new(V::one(), modulus);
+    let mut current_poly = Polynomial::from(vec![one.clone()]);
+    let mut previous_poly = current_poly.clone();
+    let mut previous_discrepancy = one;
+    let mut linear_complexity = 0usize;
+    let mut shift = 1usize;
+    for i in 0..sequence.len() {
+        let mut discrepancy = sequence[i].clone();
+        for j in 1..=linear_complexity {
+            let coefficient = current_poly.nonzero_coefficient(j).unwrap_or_else(|| zero.clone());
+            discrepancy += coefficient * &sequence[i - j];
+        }
+        if discrepancy.value().is_zero() {
+            shift += 1;
+            continue;
+        }
+        let scale = discrepancy
+            .try_div(&previous_discrepancy)
+            .expect("moduli always match");
+        let correction = Polynomial::make_monomial(scale, shift) * &previous_poly;
+        if 2 * linear_complexity <= i {
+            let old_current_poly = current_poly.clone();
+            current_poly = &current_poly - &correction;
+            linear_complexity = i + 1 - linear_complexity;
+            previous_poly = old_current_poly;
+            previous_discrepancy = discrepancy;
+            shift = 1;
+        } else {
+            current_poly = &current_poly - &correction;
+            shift += 1;
+        }
+    }
+    current_poly
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mod_int::KnownPrime;
+
+    fn make_sequence(values: &[i64], modulus: KnownPrime<i64>) -> Vec<ModularInteger<i64, KnownPrime<i64>>> {
+        values
+            .iter()
+            .map(|&value| ModularInteger::new(value, modulus))
+            .collect()
+    }
+
+    #[test]
+    fn test_berlekamp_massey_fibonacci() {
+        // Fibonacci numbers satisfy F(n) = F(n - 1) + F(n - 2), so the
+        // characteristic polynomial is 1 - x - x^2
+        let modulus = KnownPrime::new_unsafe(1_000_000_007i64);
+        let sequence = make_sequence(&[0, 1, 1, 2, 3, 5, 8, 13, 21, 34], modulus);
+        let poly = berlekamp_massey(&sequence);
+        assert_eq!(
+            poly.iter().map(|v| *v.value()).collect::<Vec<_>>(),
+            vec![1, 1_000_000_006, 1_000_000_006]
+        );
+    }
+
+    #[test]
+    fn test_berlekamp_massey_constant() {
+        let modulus = KnownPrime::new_unsafe(13i64);
+        let sequence = make_sequence(&[5, 5, 5, 5, 5], modulus);
+        let poly = berlekamp_massey(&sequence);
+        assert_eq!(
+            poly.iter().map(|v| *v.value()).collect::<Vec<_>>(),
+            vec![1, 12]
+        );
+    }
+}
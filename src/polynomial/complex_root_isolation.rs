@@ -0,0 +1,298 @@
+// SPDX-License-Identifier: LGPL-2.1-or-later
+// See Notices.txt for copyright information
+
+//! isolating a polynomial's complex (including non-real) roots into
+//! disjoint rectangles, by quadtree-subdividing a Cauchy-bounded square
+//! and excluding sub-rectangles that a Taylor-remainder bound proves are
+//! root-free, in the style of Weyl's exclusion algorithm
+
+use crate::{
+    interval_arithmetic::{ComplexDyadicInterval, DyadicFractionInterval},
+    polynomial::{root_isolation::cauchy_root_bound, Polynomial},
+};
+use num_bigint::BigInt;
+use num_rational::Ratio;
+use num_traits::{One, Signed, Zero};
+use std::ops::{Add, Mul};
+
+/// a Gaussian rational, used as the evaluation point/result when
+/// evaluating a real-coefficient [`Polynomial`] at a complex argument via
+/// [`Polynomial::eval_generic`]
+#[derive(Clone, Debug, PartialEq)]
+struct GaussianRational {
+    re: Ratio<BigInt>,
+    im: Ratio<BigInt>,
+}
+
+impl GaussianRational {
+    fn new(re: Ratio<BigInt>, im: Ratio<BigInt>) -> Self {
+        Self { re, im }
+    }
+    /// a rational upper bound on `self`'s magnitude; not exact, since an
+    /// exact magnitude would generally be irrational
+    fn magnitude_upper_bound(&self) -> Ratio<BigInt> {
+        self.re.abs() + self.im.abs()
+    }
+    /// a rational lower bound on `self`'s magnitude; not exact, since an
+    /// exact magnitude would generally be irrational
+    fn magnitude_lower_bound(&self) -> Ratio<BigInt> {
+        self.re.abs().max(self.im.abs())
+    }
+}
+
+impl Zero for GaussianRational {
+    fn zero() -> Self {
+        Self::new(Ratio::zero(), Ratio::zero())
+    }
+    fn is_zero(&self) -> bool {
+        self.re.is_zero() && self.im.is_zero()
+    }
+}
+
+impl Mul<&'_ GaussianRational> for GaussianRational {
+    type Output = GaussianRational;
+    fn mul(self, rhs: &GaussianRational) -> GaussianRational {
+        GaussianRational::new(
+            &self.re * &rhs.re - &self.im * &rhs.im,
+            &self.re * &rhs.im + &self.im * &rhs.re,
+        )
+    }
+}
+
+impl Add<GaussianRational> for GaussianRational {
+    type Output = GaussianRational;
+    fn add(self, rhs: GaussianRational) -> GaussianRational {
+        GaussianRational::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl Add<BigInt> for GaussianRational {
+    type Output = GaussianRational;
+    fn add(self, rhs: BigInt) -> GaussianRational {
+        GaussianRational::new(self.re + Ratio::from(rhs), self.im)
+    }
+}
+
+/// a rational number that's at least as big as `sqrt(2)`, used to bound a
+/// square's half-diagonal length in terms of its half-width without ever
+/// needing an irrational square root
+const SQRT_2_UPPER_BOUND_NUMER: i64 = 3;
+const SQRT_2_UPPER_BOUND_DENOM: i64 = 2;
+
+/// `true` if a Taylor-remainder bound proves `polynomial` has no root
+/// anywhere in the square centered at `center` with the given
+/// `half_width`; never incorrectly excludes a square that does contain a
+/// root, but may fail to exclude a root-free square that isn't yet small
+/// enough for the (deliberately loose) bound to separate it from zero
+fn no_root_in_square(
+    polynomial: &Polynomial<BigInt>,
+    center: &GaussianRational,
+    half_width: &Ratio<BigInt>,
+) -> bool {
+    let half_diagonal = half_width
+        * Ratio::new(
+            BigInt::from(SQRT_2_UPPER_BOUND_NUMER),
+            BigInt::from(SQRT_2_UPPER_BOUND_DENOM),
+        );
+    let center_value_bound = polynomial
+        .eval_generic(center, GaussianRational::zero())
+        .magnitude_lower_bound();
+    let mut derivative = polynomial.derivative();
+    let mut term_bound = Ratio::one();
+    let mut remainder_bound = Ratio::zero();
+    let mut order = BigInt::one();
+    while !derivative.is_empty() {
+        term_bound = &term_bound * &half_diagonal / Ratio::from(order.clone());
+        let derivative_value_bound = derivative
+            .eval_generic(center, GaussianRational::zero())
+            .magnitude_upper_bound();
+        remainder_bound += derivative_value_bound * &term_bound;
+        derivative = derivative.derivative();
+        order += 1;
+    }
+    center_value_bound > remainder_bound
+}
+
+/// splits `square` into its 4 quadrants, each with `log2_denom` one
+/// greater than `square`'s
+fn bisect_into_quadrants(square: &ComplexDyadicInterval) -> [ComplexDyadicInterval; 4] {
+    let (re_lower, re_upper) = square.real_part().bisect();
+    let (im_lower, im_upper) = square.imaginary_part().bisect();
+    [
+        ComplexDyadicInterval::new(re_lower.clone(), im_lower.clone()),
+        ComplexDyadicInterval::new(re_lower, im_upper.clone()),
+        ComplexDyadicInterval::new(re_upper.clone(), im_lower),
+        ComplexDyadicInterval::new(re_upper, im_upper),
+    ]
+}
+
+fn square_center(square: &ComplexDyadicInterval) -> GaussianRational {
+    GaussianRational::new(
+        square.real_part().midpoint().lower_bound(),
+        square.imaginary_part().midpoint().lower_bound(),
+    )
+}
+
+/// `square`'s 4 corners, as exact Gaussian rationals
+fn square_corners(square: &ComplexDyadicInterval) -> [GaussianRational; 4] {
+    let (re_lower, re_upper) = (
+        square.real_part().lower_bound(),
+        square.real_part().upper_bound(),
+    );
+    let (im_lower, im_upper) = (
+        square.imaginary_part().lower_bound(),
+        square.imaginary_part().upper_bound(),
+    );
+    [
+        GaussianRational::new(re_lower.clone(), im_lower.clone()),
+        GaussianRational::new(re_lower, im_upper.clone()),
+        GaussianRational::new(re_upper.clone(), im_lower),
+        GaussianRational::new(re_upper, im_upper),
+    ]
+}
+
+/// isolates the `degree` distinct complex roots of the square-free
+/// `polynomial` (which must have exactly `degree` roots, counted without
+/// multiplicity) into disjoint squares, each tighter than `precision` bits;
+/// `polynomial` must have no rational roots (as is guaranteed for an
+/// irreducible factor of degree at least 2), so that a real axis never
+/// stays pinned exactly on a root forever; a root can still land exactly
+/// on a square's corner (e.g. `i`'s real part sits on the real axis, which
+/// is always a bisection boundary), which is checked for directly since
+/// [`no_root_in_square`] can never exclude a square containing one
+fn isolate_square_free_complex_roots(
+    polynomial: &Polynomial<BigInt>,
+    degree: usize,
+    precision: usize,
+) -> Vec<ComplexDyadicInterval> {
+    if degree == 0 {
+        return Vec::new();
+    }
+    let bound = cauchy_root_bound(polynomial);
+    let initial_square = ComplexDyadicInterval::new(
+        DyadicFractionInterval::from_int_range(-&bound, bound.clone(), 0),
+        DyadicFractionInterval::from_int_range(-&bound, bound, 0),
+    );
+    let mut candidates = vec![initial_square];
+    let mut found_roots = Vec::new();
+    loop {
+        let mut exact_roots_this_round = Vec::new();
+        for square in &candidates {
+            for corner in square_corners(square) {
+                if !exact_roots_this_round.contains(&corner)
+                    && polynomial
+                        .eval_generic(&corner, GaussianRational::zero())
+                        .is_zero()
+                {
+                    exact_roots_this_round.push(corner);
+                }
+            }
+        }
+        for root in exact_roots_this_round {
+            candidates.retain(|square| !square_corners(square).contains(&root));
+            found_roots.push(ComplexDyadicInterval::new(
+                DyadicFractionInterval::from_ratio(root.re, precision),
+                DyadicFractionInterval::from_ratio(root.im, precision),
+            ));
+        }
+        candidates.retain(|square| {
+            !no_root_in_square(polynomial, &square_center(square), &square.real_part().radius())
+        });
+        if found_roots.len() + candidates.len() == degree
+            && candidates
+                .iter()
+                .all(|square| square.real_part().is_tighter_than(precision))
+        {
+            found_roots.extend(candidates);
+            return found_roots;
+        }
+        candidates = candidates
+            .iter()
+            .flat_map(bisect_into_quadrants)
+            .collect();
+    }
+}
+
+impl Polynomial<BigInt> {
+    /// isolates all of `self`'s complex roots (including non-real ones)
+    /// into disjoint rectangles, each tighter than `precision` bits; a
+    /// root of multiplicity `m` (found via irreducible factorization)
+    /// appears `m` times in the returned `Vec`
+    pub fn isolate_complex_roots(&self, precision: usize) -> Vec<ComplexDyadicInterval> {
+        let mut roots = Vec::new();
+        for factor in self.factor().polynomial_factors {
+            let degree = factor.polynomial.degree().unwrap_or(0);
+            let factor_roots = if degree == 1 {
+                // a linear factor's root is exactly rational, so it can be
+                // computed directly instead of being handed to the
+                // exclusion-test-based search below, which relies on
+                // `polynomial` having no rational roots
+                let root = -Ratio::new(
+                    factor.polynomial.coefficient(0),
+                    factor.polynomial.highest_power_coefficient(),
+                );
+                vec![ComplexDyadicInterval::new(
+                    DyadicFractionInterval::from_ratio(root, precision),
+                    DyadicFractionInterval::from_ratio(Ratio::zero(), precision),
+                )]
+            } else {
+                isolate_square_free_complex_roots(&factor.polynomial, degree, precision)
+            };
+            for root in factor_roots {
+                roots.extend(std::iter::repeat(root).take(factor.power));
+            }
+        }
+        roots
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn p(coefficients: &[i64]) -> Polynomial<BigInt> {
+        Polynomial::from(coefficients.iter().map(|&v| BigInt::from(v)).collect::<Vec<_>>())
+    }
+
+    fn contains(root: &ComplexDyadicInterval, re: (i64, i64), im: (i64, i64)) -> bool {
+        let re_expected = Ratio::new(BigInt::from(re.0), BigInt::from(re.1));
+        let im_expected = Ratio::new(BigInt::from(im.0), BigInt::from(im.1));
+        root.real_part().lower_bound() <= re_expected
+            && re_expected <= root.real_part().upper_bound()
+            && root.imaginary_part().lower_bound() <= im_expected
+            && im_expected <= root.imaginary_part().upper_bound()
+    }
+
+    #[test]
+    fn test_isolate_complex_roots_real() {
+        // (X - 1) * (X - 2)
+        let roots = p(&[2, -3, 1]).isolate_complex_roots(8);
+        assert_eq!(roots.len(), 2);
+        assert!(roots.iter().any(|r| contains(r, (1, 1), (0, 1))));
+        assert!(roots.iter().any(|r| contains(r, (2, 1), (0, 1))));
+    }
+
+    #[test]
+    fn test_isolate_complex_roots_non_real() {
+        // X^2 + 1, roots at +/- i
+        let roots = p(&[1, 0, 1]).isolate_complex_roots(8);
+        assert_eq!(roots.len(), 2);
+        assert!(roots.iter().any(|r| contains(r, (0, 1), (1, 1))));
+        assert!(roots.iter().any(|r| contains(r, (0, 1), (-1, 1))));
+    }
+
+    #[test]
+    fn test_isolate_complex_roots_multiplicity() {
+        // (X - 1)^2
+        let roots = p(&[1, -2, 1]).isolate_complex_roots(8);
+        assert_eq!(roots.len(), 2);
+        for root in &roots {
+            assert!(contains(root, (1, 1), (0, 1)));
+        }
+    }
+
+    #[test]
+    fn test_isolate_complex_roots_zero_polynomial() {
+        assert!(Polynomial::<BigInt>::zero().isolate_complex_roots(8).is_empty());
+    }
+}
@@ -40,6 +40,60 @@ where
         }
         unreachable!("failed to find all factors")
     }
+    /// tests if `self` is irreducible over `GF(p)`, using distinct-degree
+    /// factorization: `self` is irreducible exactly when it's square-free
+    /// and distinct-degree factorization finds a single non-trivial
+    /// factor whose degree is `self`'s degree
+    pub(crate) fn is_irreducible(&self) -> bool {
+        let degree = match self.degree() {
+            None | Some(0) => return false,
+            Some(degree) => degree,
+        };
+        if !self.is_square_free() {
+            return false;
+        }
+        self.clone()
+            .distinct_degree_factorization()
+            .into_iter()
+            .enumerate()
+            .any(|(factor_degree, factor)| factor_degree == degree && factor.degree() == Some(degree))
+    }
+    /// every root of `self` in `GF(p)`, each returned once even if it's a
+    /// repeated root of `self`; found by taking `retval[1]` from
+    /// [`Self::distinct_degree_factorization`] (the product of `self`'s
+    /// distinct linear factors, since that's exactly what a gcd with
+    /// `x^p - x` picks out), then splitting that product into its
+    /// individual linear factors with
+    /// [`Self::factor_using_berlekamp_algorithm`]
+    ///
+    /// extension-field root enumeration (i.e. `roots_in_gf(q)` for
+    /// non-prime `q`) isn't implemented yet, since [`GaloisFieldElement`]
+    /// doesn't implement [`PolynomialCoefficient`] and so can't be used as
+    /// a polynomial's coefficient type
+    ///
+    /// [`GaloisFieldElement`]: crate::mod_int::GaloisFieldElement
+    /// [`PolynomialCoefficient`]: crate::polynomial::PolynomialCoefficient
+    pub fn roots_mod_p(&self) -> Vec<ModularInteger<V, M>> {
+        let linear_factors = match self.clone().distinct_degree_factorization().into_iter().nth(1)
+        {
+            Some(linear_factors) if linear_factors.degree().unwrap_or(0) > 0 => linear_factors,
+            _ => return Vec::new(),
+        };
+        linear_factors
+            .factor_using_berlekamp_algorithm()
+            .into_iter()
+            .map(|factor| {
+                assert_eq!(
+                    factor.degree(),
+                    Some(1),
+                    "distinct-degree factorization guarantees only linear factors here"
+                );
+                -factor
+                    .nonzero_coefficient(0)
+                    .expect("a degree-1 polynomial always has a coefficient at index 0")
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -106,4 +160,55 @@ mod tests {
             KnownPrime::new_unsafe(7),
         );
     }
+
+    #[test]
+    fn test_is_irreducible() {
+        fn make_poly(
+            poly: &[i32],
+            modulus: KnownPrime<i32>,
+        ) -> Polynomial<ModularInteger<i32, KnownPrime<i32>>> {
+            poly.iter()
+                .map(|&coefficient| ModularInteger::new(coefficient, modulus))
+                .collect()
+        }
+        let modulus = KnownPrime::new_unsafe(7);
+        // constants and zero aren't irreducible
+        assert!(!make_poly(&[], modulus).is_irreducible());
+        assert!(!make_poly(&[3], modulus).is_irreducible());
+        // x^2 + 1 is irreducible over GF(7) since -1 isn't a quadratic residue mod 7
+        assert!(make_poly(&[1, 0, 1], modulus).is_irreducible());
+        // x^2 - 1 == (x - 1) * (x + 1) over GF(7)
+        assert!(!make_poly(&[6, 0, 1], modulus).is_irreducible());
+        // (x + 1)^2 isn't square-free, so it's not irreducible
+        assert!(!make_poly(&[1, 2, 1], modulus).is_irreducible());
+    }
+
+    #[test]
+    fn test_roots_mod_p() {
+        fn make_poly(
+            poly: &[i32],
+            modulus: KnownPrime<i32>,
+        ) -> Polynomial<ModularInteger<i32, KnownPrime<i32>>> {
+            poly.iter()
+                .map(|&coefficient| ModularInteger::new(coefficient, modulus))
+                .collect()
+        }
+        fn sorted_values(
+            mut roots: Vec<ModularInteger<i32, KnownPrime<i32>>>,
+        ) -> Vec<i32> {
+            roots.sort_by_key(|root| *root.value());
+            roots.into_iter().map(|root| *root.value()).collect()
+        }
+        let modulus = KnownPrime::new_unsafe(7);
+        // x^2 + 1 is irreducible over GF(7), so it has no roots
+        assert_eq!(make_poly(&[1, 0, 1], modulus).roots_mod_p(), &[]);
+        // x^2 - 1 == (x - 1) * (x + 1) over GF(7), roots at 1 and 6
+        assert_eq!(sorted_values(make_poly(&[6, 0, 1], modulus).roots_mod_p()), &[1, 6]);
+        // (x - 2)^2 has a repeated root at 2, which is only reported once
+        assert_eq!(sorted_values(make_poly(&[4, 3, 1], modulus).roots_mod_p()), &[2]);
+        // a constant has no roots
+        assert_eq!(make_poly(&[3], modulus).roots_mod_p(), &[]);
+        // the zero polynomial has no roots
+        assert_eq!(Polynomial::<ModularInteger<i32, KnownPrime<i32>>>::zero().roots_mod_p(), &[]);
+    }
 }
@@ -115,6 +115,22 @@ impl<T: PolynomialCoefficient> Polynomial<T> {
         self.checked_pseudo_div_rem(rhs)
             .expect("polynomial division by zero")
     }
+    /// computes the quotient of pseudo-division, along with the power of
+    /// `rhs`'s leading coefficient that `self` was multiplied by
+    pub fn pseudo_div(self, rhs: &Self) -> (Polynomial<T>, T) {
+        let PseudoDivRem {
+            quotient, factor, ..
+        } = self.pseudo_div_rem(rhs);
+        (quotient, factor)
+    }
+    /// computes the remainder of pseudo-division, along with the power
+    /// of `rhs`'s leading coefficient that `self` was multiplied by
+    pub fn pseudo_rem(self, rhs: &Self) -> (Polynomial<T>, T) {
+        let PseudoDivRem {
+            remainder, factor, ..
+        } = self.pseudo_div_rem(rhs);
+        (remainder, factor)
+    }
     pub fn exact_pseudo_div(self, rhs: &Self) -> (Polynomial<T>, T) {
         let PseudoDivRem {
             quotient,
@@ -136,6 +152,28 @@ impl<T: PolynomialCoefficient> Polynomial<T> {
             None
         }
     }
+    /// divides `self` by the monic linear polynomial `X - a` using
+    /// synthetic division, returning the quotient and remainder in a
+    /// single pass; since the divisor is monic, this needs no division at
+    /// all, unlike [`Self::pseudo_div_rem`], so it works directly for any
+    /// `T`, not just `T: PolynomialDivSupported`
+    ///
+    /// equivalent to (but faster than) `self.div_rem(&Polynomial::from(vec![-a.clone(), one]))`
+    pub fn div_rem_by_linear(&self, a: &T) -> (Polynomial<T>, T) {
+        let mut coefficients: Vec<T> = self.iter().collect();
+        coefficients.reverse();
+        let len = coefficients.len();
+        let mut quotient = Vec::with_capacity(len.saturating_sub(1));
+        let mut remainder = T::make_zero_coefficient_from_coefficient(Cow::Borrowed(a));
+        for (index, coefficient) in coefficients.into_iter().enumerate() {
+            remainder = coefficient + a.clone() * remainder;
+            if index + 1 < len {
+                quotient.push(remainder.clone());
+            }
+        }
+        quotient.reverse();
+        (Polynomial::from(quotient), remainder)
+    }
 }
 
 impl<T: PolynomialCoefficient + for<'a> ExactDiv<&'a T, Output = T>> Polynomial<T> {
@@ -540,6 +578,44 @@ mod tests {
         let _ = Polynomial::<Ratio<i128>>::from(ri(1)) / Polynomial::zero();
     }
 
+    #[test]
+    fn test_div_rem_by_linear() {
+        // (X - 1) * (X - 2) * (X - 3) == X^3 - 6*X^2 + 11*X - 6
+        let poly: Polynomial<Ratio<i128>> = vec![ri(-6), ri(11), ri(-6), ri(1)].into();
+        let (quotient, remainder) = poly.div_rem_by_linear(&ri(1));
+        assert_eq!(quotient, vec![ri(6), ri(-5), ri(1)].into());
+        assert_eq!(remainder, ri(0));
+
+        // dividing by a non-root leaves a nonzero remainder
+        let (quotient, remainder) = poly.div_rem_by_linear(&ri(5));
+        assert_eq!(quotient, vec![ri(6), ri(-1), ri(1)].into());
+        assert_eq!(remainder, ri(24));
+
+        // constant polynomial
+        let poly: Polynomial<Ratio<i128>> = ri(7).into();
+        let (quotient, remainder) = poly.div_rem_by_linear(&ri(3));
+        assert_eq!(quotient, Zero::zero());
+        assert_eq!(remainder, ri(7));
+
+        // zero polynomial
+        let poly: Polynomial<Ratio<i128>> = Zero::zero();
+        let (quotient, remainder) = poly.div_rem_by_linear(&ri(3));
+        assert_eq!(quotient, Zero::zero());
+        assert_eq!(remainder, ri(0));
+    }
+
+    #[test]
+    fn test_div_rem_by_linear_modular() {
+        use crate::mod_int::{KnownPrime, ModularInteger};
+        let modulus = KnownPrime::new_unsafe(7i32);
+        let m = |v: i32| ModularInteger::new(v, modulus);
+        // (X - 2) * (X - 3) == X^2 - 5*X + 6 == X^2 + 2*X + 6 mod 7
+        let poly = Polynomial::from(vec![m(6), m(2), m(1)]);
+        let (quotient, remainder) = poly.div_rem_by_linear(&m(2));
+        assert_eq!(quotient, vec![m(4), m(1)].into());
+        assert_eq!(remainder, m(0));
+    }
+
     #[test]
     fn test_pseudo_div_rem() {
         let test = |dividend: Polynomial<Ratio<i128>>,
@@ -573,7 +649,8 @@ mod tests {
                 remainder,
                 factor,
             } = dividend.clone().pseudo_div_rem(&divisor);
-            let (exact_quotient, exact_factor) = match dividend.checked_exact_pseudo_div(&divisor) {
+            let (exact_quotient, exact_factor) = match dividend.clone().checked_exact_pseudo_div(&divisor)
+            {
                 None => (None, None),
                 Some((a, b)) => (Some(a), Some(b)),
             };
@@ -587,6 +664,11 @@ mod tests {
             assert_eq!(remainder, expected_remainder);
             assert_eq!(exact_quotient, expected_exact_quotient);
             assert_eq!(exact_factor, expected_exact_factor);
+            assert_eq!(
+                dividend.clone().pseudo_div(&divisor),
+                (quotient, factor.clone())
+            );
+            assert_eq!(dividend.clone().pseudo_rem(&divisor), (remainder, factor));
         };
         test(
             vec![r(1, 2), r(5, 2), r(5, 2)].into(),
@@ -2,7 +2,9 @@
 // See Notices.txt for copyright information
 
 use crate::{
-    mod_int::{KnownOddPrime, ModularInteger, Modulus},
+    array2d::Array2DOwned,
+    lattice::lll_reduce,
+    mod_int::{KnownOddPrime, KnownPrime, ModularInteger, Modulus},
     polynomial::{
         Polynomial, PolynomialCoefficient, PolynomialDivSupported, PolynomialFactor,
         PolynomialFactors, PolynomialReducingFactorSupported,
@@ -16,7 +18,7 @@ use crate::{
 use num_bigint::BigInt;
 use num_integer::Integer;
 use num_rational::Ratio;
-use num_traits::{One, Signed};
+use num_traits::{One, Signed, Zero};
 use rand::{Rng, SeedableRng};
 use rand_pcg::Pcg64Mcg;
 use std::{
@@ -399,10 +401,215 @@ impl fmt::Display for ExactInexactInt {
     }
 }
 
+/// brings a modular coefficient (known to be in `0..modulus`) into the
+/// centered range `(-modulus / 2, modulus / 2]`
+fn center_modular_coefficient(coefficient: BigInt, modulus: &BigInt, half_modulus: &BigInt) -> BigInt {
+    assert!(!coefficient.is_negative());
+    assert!(&coefficient < modulus);
+    if &coefficient > half_modulus {
+        coefficient - modulus
+    } else {
+        coefficient
+    }
+}
+
+/// the first `count` power sums `p_1, ..., p_count` of the roots of the
+/// monic polynomial `factor`, computed from `factor`'s coefficients via
+/// Newton's identities; unlike a polynomial's coefficients, power sums are
+/// additive over the union of two polynomials' root multisets -- that
+/// linearity is what [`combine_factors_using_lattice`] relies on to turn
+/// factor recombination into a lattice-reduction problem
+fn power_sums(
+    factor: &Polynomial<ModularInteger<BigInt, BigInt>>,
+    count: usize,
+) -> Vec<ModularInteger<BigInt, BigInt>> {
+    let degree = factor.degree().expect("factor known to be non-zero");
+    let modulus = factor
+        .nonzero_coefficient(degree)
+        .expect("leading coefficient known to be present")
+        .modulus()
+        .clone();
+    // elementary symmetric functions of factor's roots, from its
+    // (monic) coefficients: e_i == (-1)^i * coefficient(degree - i)
+    let elementary = |i: usize| -> ModularInteger<BigInt, BigInt> {
+        let coefficient = factor
+            .nonzero_coefficient(degree - i)
+            .expect("coefficient known to be present");
+        if i % 2 == 0 {
+            coefficient
+        } else {
+            -coefficient
+        }
+    };
+    let mut power_sums: Vec<ModularInteger<BigInt, BigInt>> = Vec::with_capacity(count);
+    for k in 1..=count {
+        let mut sum = ModularInteger::new(BigInt::zero(), modulus.clone());
+        for i in 1..k.min(degree + 1) {
+            let term = elementary(i) * power_sums[k - i - 1].clone();
+            sum += if i % 2 == 1 { term } else { -term };
+        }
+        if k <= degree {
+            let term = elementary(k) * ModularInteger::new(BigInt::from(k), modulus.clone());
+            sum += if k % 2 == 1 { term } else { -term };
+        }
+        power_sums.push(sum);
+    }
+    power_sums
+}
+
+/// below this many modular factors, the exponential subset search below
+/// already finishes essentially instantly, so it's not worth the cost of
+/// building and LLL-reducing a knapsack lattice; the lattice only earns its
+/// keep once the number of subsets to search becomes impractically large
+const MIN_FACTOR_COUNT_FOR_LATTICE_RECOMBINATION: usize = 16;
+
+/// van Hoeij's LLL-based replacement for the exponential subset search:
+/// combines whichever of `modular_factors` (working modulo `modulus`) it
+/// can confidently identify as multiplying together to true integer
+/// factors of `*input_polynomial`, pushing each one found onto `factors`,
+/// dividing it out of `*input_polynomial`, and removing its constituent
+/// modular factors from `modular_factors`; leaves both untouched once it
+/// can no longer make progress, so any remaining modular factors still
+/// need to be resolved by the caller's own (exponential) subset search
+///
+/// the knapsack lattice has one basis vector per modular factor, tagging
+/// its centered power sums with a unit vector so that a short vector in
+/// the reduced basis with 0/1 entries in the unit-vector coordinates
+/// identifies a subset of factors whose combined (and therefore small)
+/// power sums match; that's checked against the input polynomial by
+/// actually multiplying the subset's factors together and trying an
+/// exact division, exactly like the subset search does
+fn combine_factors_using_lattice(
+    modular_factors: &mut Vec<Polynomial<ModularInteger<BigInt, BigInt>>>,
+    modulus: &BigInt,
+    half_modulus: &BigInt,
+    input_polynomial: &mut Polynomial<BigInt>,
+    factors: &mut Vec<Polynomial<BigInt>>,
+) {
+    loop {
+        let factor_count = modular_factors.len();
+        let degree = input_polynomial.degree().unwrap_or(0);
+        if factor_count < MIN_FACTOR_COUNT_FOR_LATTICE_RECOMBINATION || degree == 0 {
+            return;
+        }
+        let power_sum_count = factor_count.min(degree);
+        let power_sum_vectors: Vec<_> = modular_factors
+            .iter()
+            .map(|factor| power_sums(factor, power_sum_count))
+            .collect();
+        let size = factor_count + power_sum_count;
+        let mut basis = Array2DOwned::new(size, size, BigInt::zero());
+        for (i, sums) in power_sum_vectors.iter().enumerate() {
+            basis[(i, i)] = BigInt::one();
+            for (j, sum) in sums.iter().enumerate() {
+                basis[(i, factor_count + j)] =
+                    center_modular_coefficient(sum.value().clone(), modulus, half_modulus);
+            }
+        }
+        for j in 0..power_sum_count {
+            basis[(factor_count + j, factor_count + j)] = modulus.clone();
+        }
+        let reduced = lll_reduce(basis);
+        let mut combined = false;
+        'columns: for column in 0..size {
+            let mut subset = Vec::new();
+            for row in 0..factor_count {
+                let entry = &reduced[(column, row)];
+                if entry.is_zero() {
+                    continue;
+                } else if entry.is_one() || (-entry.clone()).is_one() {
+                    subset.push(row);
+                } else {
+                    continue 'columns;
+                }
+            }
+            if subset.is_empty() {
+                continue;
+            }
+            let mut potential_factor = Polynomial::from(ModularInteger::new(
+                input_polynomial.highest_power_coefficient(),
+                modulus.clone(),
+            ));
+            for &index in &subset {
+                potential_factor *= &modular_factors[index];
+            }
+            let mut potential_factor: Polynomial<_> = potential_factor
+                .into_iter()
+                .map(Into::into)
+                .map(|(coefficient, _modulus): (BigInt, BigInt)| {
+                    center_modular_coefficient(coefficient, modulus, half_modulus)
+                })
+                .collect();
+            potential_factor.primitive_part_assign();
+            if let Some((mut quotient, _)) = input_polynomial
+                .clone()
+                .checked_exact_pseudo_div(&potential_factor)
+            {
+                factors.push(potential_factor);
+                quotient.primitive_part_assign();
+                *input_polynomial = quotient;
+                for &index in subset.iter().rev() {
+                    modular_factors.remove(index);
+                }
+                combined = true;
+                break;
+            }
+        }
+        if !combined {
+            return;
+        }
+    }
+}
+
+/// which algorithm [`Polynomial::factor_with_options`] uses to recombine
+/// modular factors into full-degree integer factors, once Hensel lifting
+/// has raised the modulus high enough
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum FactorRecombinationAlgorithm {
+    /// combine modular factors using the LLL-lattice-based approach (in
+    /// the style of van Hoeij's algorithm), falling back to exhaustive
+    /// subset search only for whatever it can't resolve; dramatically
+    /// faster than `Zassenhaus` once there are more than a handful of
+    /// modular factors
+    VanHoeij,
+    /// skip the lattice step and go straight to the classic Zassenhaus
+    /// exhaustive subset search; the lattice reduction has its own
+    /// overhead, so this can win when there are only a few modular
+    /// factors to begin with
+    Zassenhaus,
+}
+
+/// tuning knobs for [`Polynomial::factor_with_options`]; [`Polynomial::factor`]
+/// and [`Polynomial::factor_with_rng`] use [`FactorizationOptions::default`]
+#[derive(Clone, Debug)]
+pub struct FactorizationOptions {
+    /// how modular factors get recombined into full-degree factors
+    pub recombination_algorithm: FactorRecombinationAlgorithm,
+    /// how many candidate primes to try before giving up on finding one
+    /// that both keeps the full degree and reduces `self` to a
+    /// square-free polynomial mod that prime
+    pub max_modular_attempts: usize,
+    /// seeds the RNG used for randomized same-degree factorization steps;
+    /// two calls with the same polynomial and the same seed produce the
+    /// same factors in the same order
+    pub rng_seed: u64,
+}
+
+impl Default for FactorizationOptions {
+    fn default() -> Self {
+        Self {
+            recombination_algorithm: FactorRecombinationAlgorithm::VanHoeij,
+            max_modular_attempts: 4096,
+            rng_seed: 0,
+        }
+    }
+}
+
 impl Polynomial<BigInt> {
     fn factor_square_free_polynomial_with_rng<R: Rng + ?Sized>(
         &self,
         rng: &mut R,
+        options: &FactorizationOptions,
     ) -> Vec<Polynomial<BigInt>> {
         let degree = match self.degree() {
             None | Some(0) | Some(1) => return vec![self.clone()],
@@ -410,6 +617,7 @@ impl Polynomial<BigInt> {
         };
         let content = self.content();
         let mut prime = 2;
+        let mut modular_attempts = 0;
         let (modular_polynomial, modulus) = loop {
             prime =
                 next_prime_i32(prime).expect("polynomial too big to factor using this algorithm");
@@ -423,6 +631,11 @@ impl Polynomial<BigInt> {
                 // highest power coefficient would be zero, reducing the degree
                 continue;
             }
+            modular_attempts += 1;
+            assert!(
+                modular_attempts <= options.max_modular_attempts,
+                "exceeded max_modular_attempts without finding a prime that keeps self square-free"
+            );
             let modulus = KnownOddPrime::new_odd_prime_unsafe(prime);
             let converted_polynomial: Polynomial<_> = self
                 .elements
@@ -436,19 +649,41 @@ impl Polynomial<BigInt> {
         };
         // println!("modulus: {}", modulus);
         // println!("modular_polynomial: {}", modular_polynomial);
-        let modular_factors: Vec<_> = modular_polynomial
-            .distinct_degree_factorization()
+        // Berlekamp's algorithm pays a fixed O(degree^3) matrix cost
+        // independent of the modulus, so it beats distinct-degree plus
+        // same-degree factorization's random splitting once the modulus
+        // gets small -- and it's the only option of the two that works
+        // at all when the modulus is 2, since same-degree factorization
+        // requires an odd modulus
+        let raw_modular_factors: Vec<_> =
+            if u64::from(prime.unsigned_abs()) < super::berlekamp::MAX_CHARACTERISTIC_FOR_BERLEKAMP_ALGORITHM {
+                let nonzero_highest_power_coefficient = modular_polynomial
+                    .nonzero_highest_power_coefficient()
+                    .expect("known to be non-zero");
+                let mut monic_polynomial = modular_polynomial.clone();
+                monic_polynomial /= &nonzero_highest_power_coefficient;
+                let mut factors = vec![Polynomial::from(nonzero_highest_power_coefficient)];
+                factors.extend(monic_polynomial.factor_using_berlekamp_algorithm());
+                factors
+            } else {
+                modular_polynomial
+                    .distinct_degree_factorization()
+                    .into_iter()
+                    .enumerate()
+                    .flat_map(|(factor_degree, poly)| {
+                        if poly.is_one() {
+                            vec![]
+                        } else if factor_degree == 0 {
+                            vec![poly]
+                        } else {
+                            poly.same_degree_factorization(factor_degree, rng)
+                        }
+                    })
+                    .collect()
+            };
+        let modular_factors: Vec<_> = raw_modular_factors
             .into_iter()
-            .enumerate()
-            .flat_map(|(factor_degree, poly)| {
-                if poly.is_one() {
-                    vec![]
-                } else if factor_degree == 0 {
-                    vec![poly]
-                } else {
-                    poly.same_degree_factorization(factor_degree, rng)
-                }
-            })
+            .filter(|factor| !factor.is_one())
             .map(|factor| FactorTreeNode::Leaf(FactorTreeLeafNode { factor }))
             .collect();
 
@@ -565,8 +800,19 @@ impl Polynomial<BigInt> {
 
         let mut input_polynomial = self.clone();
 
-        // FIXME: replace exponential subset search with LLL reduction
+        if options.recombination_algorithm == FactorRecombinationAlgorithm::VanHoeij {
+            combine_factors_using_lattice(
+                &mut modular_factors,
+                &modulus,
+                &half_modulus,
+                &mut input_polynomial,
+                &mut factors,
+            );
+        }
 
+        // whatever combine_factors_using_lattice couldn't resolve (should
+        // rarely be more than a handful of factors) falls back to
+        // exhaustively searching subsets, same as before it existed
         let mut subset_size = 0;
         let mut found_factors = false;
         loop {
@@ -593,14 +839,8 @@ impl Polynomial<BigInt> {
                     let mut potential_factor: Polynomial<_> = potential_factor
                         .into_iter()
                         .map(Into::into)
-                        .map(|(coefficient, _modulus)| {
-                            assert!(!coefficient.is_negative());
-                            assert!(coefficient < modulus);
-                            if coefficient > half_modulus {
-                                coefficient - &modulus
-                            } else {
-                                coefficient
-                            }
+                        .map(|(coefficient, _modulus): (BigInt, BigInt)| {
+                            center_modular_coefficient(coefficient, &modulus, &half_modulus)
                         })
                         .collect();
                     // println!("potential_factor: {}", potential_factor);
@@ -632,7 +872,13 @@ impl Polynomial<BigInt> {
         factors.push(input_polynomial);
         factors
     }
-    pub fn factor_with_rng<R: Rng + ?Sized>(&self, rng: &mut R) -> PolynomialFactors<BigInt> {
+    /// splits `self` into its content and a set of pairwise-coprime,
+    /// square-free integer polynomials whose product (after being raised to
+    /// the appropriate powers) is `self` divided by its content; shared by
+    /// [`Self::factor_with_rng`] and the `rayon`-parallel factoring below,
+    /// since each of the resulting square-free polynomials can then be
+    /// fully factored independently of the others
+    fn square_free_integer_factors(&self) -> (BigInt, Vec<Polynomial<BigInt>>) {
         let content = self.content();
         let rational_polynomial: Polynomial<_> = self
             .iter()
@@ -640,13 +886,27 @@ impl Polynomial<BigInt> {
             .collect();
         let square_free_factors = rational_polynomial
             .square_free_factorization_using_yuns_algorithm()
-            .polynomial_factors;
+            .polynomial_factors
+            .into_iter()
+            .map(|factor| Polynomial::<BigInt>::from(factor.split_out_divisor().0))
+            .collect();
+        (content, square_free_factors)
+    }
+    pub fn factor_with_rng<R: Rng + ?Sized>(&self, rng: &mut R) -> PolynomialFactors<BigInt> {
+        self.factor_with_rng_and_options(rng, &FactorizationOptions::default())
+    }
+    fn factor_with_rng_and_options<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        options: &FactorizationOptions,
+    ) -> PolynomialFactors<BigInt> {
+        let (content, square_free_factors) = self.square_free_integer_factors();
         let mut polynomial_factors = Vec::with_capacity(self.len());
         for (index, square_free_factor) in square_free_factors.into_iter().enumerate() {
             let power = index + 1;
             polynomial_factors.extend(
-                Polynomial::<BigInt>::from(square_free_factor.split_out_divisor().0)
-                    .factor_square_free_polynomial_with_rng(rng)
+                square_free_factor
+                    .factor_square_free_polynomial_with_rng(rng, options)
                     .into_iter()
                     .filter(|polynomial| !polynomial.is_one())
                     .map(|polynomial| PolynomialFactor { polynomial, power }),
@@ -657,9 +917,91 @@ impl Polynomial<BigInt> {
             polynomial_factors,
         }
     }
+    /// same as [`Self::factor_with_rng_and_options`], but factors the
+    /// square-free pieces from [`Self::square_free_integer_factors`] on
+    /// separate `rayon` threads instead of one after another, since they
+    /// don't depend on each other's results; each piece gets its own
+    /// deterministically-seeded RNG rather than sharing one, since a single
+    /// RNG can't be driven from multiple threads at once
+    #[cfg(feature = "rayon")]
+    fn factor_parallel(&self, options: &FactorizationOptions) -> PolynomialFactors<BigInt> {
+        use rayon::prelude::*;
+        let (content, square_free_factors) = self.square_free_integer_factors();
+        let polynomial_factors = square_free_factors
+            .into_par_iter()
+            .enumerate()
+            .flat_map_iter(|(index, square_free_factor)| {
+                let power = index + 1;
+                let mut rng = Pcg64Mcg::seed_from_u64(options.rng_seed.wrapping_add(index as u64));
+                square_free_factor
+                    .factor_square_free_polynomial_with_rng(&mut rng, options)
+                    .into_iter()
+                    .filter(|polynomial| !polynomial.is_one())
+                    .map(move |polynomial| PolynomialFactor { polynomial, power })
+            })
+            .collect();
+        PolynomialFactors {
+            constant_factor: content,
+            polynomial_factors,
+        }
+    }
+    /// same as [`Self::factor`], but tunable through `options` -- see
+    /// [`FactorizationOptions`] for what can be adjusted
+    pub fn factor_with_options(&self, options: &FactorizationOptions) -> PolynomialFactors<BigInt> {
+        #[cfg(feature = "rayon")]
+        {
+            self.factor_parallel(options)
+        }
+        #[cfg(not(feature = "rayon"))]
+        {
+            let mut rng = Pcg64Mcg::seed_from_u64(options.rng_seed);
+            self.factor_with_rng_and_options(&mut rng, options)
+        }
+    }
     pub fn factor(&self) -> PolynomialFactors<BigInt> {
-        let mut rng = Pcg64Mcg::seed_from_u64(0);
-        self.factor_with_rng(&mut rng)
+        self.factor_with_options(&FactorizationOptions::default())
+    }
+    /// tests if `self` is irreducible over `Q` (equivalently, over `Z`
+    /// after dividing out the content)
+    ///
+    /// tries reducing `self` modulo a handful of small primes first,
+    /// since a polynomial that reduces (without losing degree) to an
+    /// irreducible polynomial over some `GF(p)` must itself be
+    /// irreducible, which is usually much cheaper than a full
+    /// factorization; falls back to `factor` if none of those primes are
+    /// conclusive
+    pub fn is_irreducible(&self) -> bool {
+        match self.degree() {
+            None | Some(0) => return false,
+            Some(_) => {}
+        }
+        let highest_power_coefficient = self
+            .elements
+            .iter()
+            .last()
+            .expect("known to be non-empty");
+        let mut prime = 2;
+        for _ in 0..20 {
+            prime = match next_prime_i32(prime) {
+                Some(prime) => prime,
+                None => break,
+            };
+            if highest_power_coefficient.is_multiple_of(&prime.into()) {
+                // highest power coefficient would be zero, reducing the degree
+                continue;
+            }
+            let modulus = KnownPrime::new_unsafe(prime);
+            let converted_polynomial: Polynomial<_> = self
+                .elements
+                .iter()
+                .map(|coefficient| ModularInteger::<i32, _>::from_bigint(coefficient, modulus))
+                .collect();
+            if converted_polynomial.is_irreducible() {
+                return true;
+            }
+        }
+        let polynomial_factors = self.factor().polynomial_factors;
+        polynomial_factors.len() == 1 && polynomial_factors[0].power == 1
     }
 }
 
@@ -686,7 +1028,8 @@ mod tests {
         for factor in &expected_factors {
             println!("    {}", factor);
         }
-        let factors = poly.factor_square_free_polynomial_with_rng(&mut rng);
+        let factors =
+            poly.factor_square_free_polynomial_with_rng(&mut rng, &FactorizationOptions::default());
         let factors: HashSet<_> = factors.into_iter().collect();
         println!("factors:");
         for factor in &factors {
@@ -893,4 +1236,95 @@ mod tests {
             ],
         });
     }
+
+    #[test]
+    fn test_factor_with_options() {
+        // (X - 1) * (X - 2) * (X - 3) * (X + 4)
+        let poly = p(vec![-24, 38, -13, -2, 1]);
+        let expected: HashSet<_> = vec![
+            PolynomialFactor {
+                polynomial: p(vec![-1, 1]),
+                power: 1,
+            },
+            PolynomialFactor {
+                polynomial: p(vec![-2, 1]),
+                power: 1,
+            },
+            PolynomialFactor {
+                polynomial: p(vec![-3, 1]),
+                power: 1,
+            },
+            PolynomialFactor {
+                polynomial: p(vec![4, 1]),
+                power: 1,
+            },
+        ]
+        .into_iter()
+        .collect();
+        for recombination_algorithm in &[
+            FactorRecombinationAlgorithm::VanHoeij,
+            FactorRecombinationAlgorithm::Zassenhaus,
+        ] {
+            let options = FactorizationOptions {
+                recombination_algorithm: *recombination_algorithm,
+                rng_seed: 1,
+                ..FactorizationOptions::default()
+            };
+            let PolynomialFactors {
+                constant_factor,
+                polynomial_factors,
+            } = poly.factor_with_options(&options);
+            assert_eq!(constant_factor, BigInt::one());
+            let factors: HashSet<_> = polynomial_factors.into_iter().collect();
+            assert!(expected == factors, "recombination_algorithm: {:?}", recombination_algorithm);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "exceeded max_modular_attempts")]
+    fn test_factor_with_options_max_modular_attempts() {
+        // X^2 + 1 is square-free modulo every odd prime, so a limit of
+        // zero attempts should always be exceeded before one is found
+        let poly = p(vec![1, 0, 1]);
+        let options = FactorizationOptions {
+            max_modular_attempts: 0,
+            ..FactorizationOptions::default()
+        };
+        poly.factor_with_options(&options);
+    }
+
+    #[test]
+    fn test_is_irreducible() {
+        assert!(!p(vec![]).is_irreducible());
+        assert!(!p(vec![5]).is_irreducible());
+        // x^2 + x + 1 has no rational roots, so it's irreducible over Q
+        assert!(p(vec![1, 1, 1]).is_irreducible());
+        // x^2 - 1 == (x - 1) * (x + 1)
+        assert!(!p(vec![-1, 0, 1]).is_irreducible());
+        // (x - 1)^2 isn't square-free, so it isn't irreducible
+        assert!(!p(vec![1, -2, 1]).is_irreducible());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_factor_parallel_matches_sequential() {
+        // (X - 1)^3 * (X + 5) * (X^2 + X + 1), chosen to have more than one
+        // square-free factor so factor_parallel actually fans out across
+        // rayon threads instead of degenerating to a single work item
+        let poly = p(vec![-1, 1]).pow(3u32) * p(vec![5, 1]) * p(vec![1, 1, 1]);
+        let options = FactorizationOptions::default();
+        let mut rng = Pcg64Mcg::seed_from_u64(options.rng_seed);
+        let sequential = poly.factor_with_rng_and_options(&mut rng, &options);
+        let parallel = poly.factor_parallel(&options);
+        let as_set = |factors: PolynomialFactors<BigInt>| {
+            (
+                factors.constant_factor,
+                factors
+                    .polynomial_factors
+                    .into_iter()
+                    .collect::<HashSet<_>>(),
+            )
+        };
+        assert_eq!(as_set(sequential), as_set(parallel));
+    }
 }
@@ -1,17 +1,21 @@
 // SPDX-License-Identifier: LGPL-2.1-or-later
 // See Notices.txt for copyright information
 use crate::{
+    mod_int::{KnownPrime, ModularInteger},
     polynomial::{
         Polynomial, PolynomialCoefficient, PolynomialDivSupported,
         PolynomialReducingFactorSupported, PseudoDivRem,
     },
+    rns::{RnsBasis, RnsInteger},
     traits::{
         ExactDiv, ExactDivAssign, ExtendedGCD, ExtendedGCDAndLCM, ExtendedGCDResult, GCDAndLCM, GCD,
     },
+    util::next_prime_i32,
 };
-use num_integer::Integer;
+use num_bigint::BigInt;
+use num_integer::{Integer, Roots};
 use num_traits::Zero;
-use std::{borrow::Cow, mem};
+use std::{borrow::Cow, mem, sync::Arc};
 
 /// computes factor * base.pow(exponent_positive_part - exponent_negative_part)
 fn exact_mul_by_signed_power<T: PolynomialCoefficient + for<'a> ExactDiv<&'a T, Output = T>>(
@@ -185,11 +189,256 @@ impl<T: PolynomialCoefficient + for<'a> ExactDiv<&'a T, Output = T>> Polynomial<
     }
 }
 
+/// polynomials of degree at least this large use [`Polynomial::modular_gcd`] by default
+/// in [`Polynomial::gcd_over_integers`]: word-size-prime arithmetic scales much better
+/// than [`Polynomial::subresultant_gcd`]'s big-integer arithmetic once the inputs get
+/// large, but isn't worth the overhead of running the CRT machinery for small inputs
+pub(crate) const MIN_DEGREE_FOR_MODULAR_GCD: usize = 32;
+
+/// a bound on `4 * (lc_gcd * gcd(self, rhs)).max_norm()^2`, used to decide how many
+/// primes are needed before reconstructing a multi-modular GCD candidate is worthwhile
+fn modular_gcd_needed_modulus(dividend: &Polynomial<BigInt>, gcd_degree: usize, lc_gcd: &BigInt) -> BigInt {
+    let max_norm = dividend.max_norm();
+    let highest_power_coefficient = dividend.highest_power_coefficient();
+    let bound_squared = ((&max_norm * &max_norm) * (&highest_power_coefficient * &highest_power_coefficient)
+        << (gcd_degree * 2))
+        * BigInt::from(gcd_degree + 1)
+        * (lc_gcd * lc_gcd);
+    Roots::sqrt(&(bound_squared * 4i32)) + 1i32
+}
+
+impl Polynomial<BigInt> {
+    /// computes `gcd(self, rhs)` using a multi-modular algorithm: compute the GCD
+    /// modulo several word-size primes, reconstruct the candidate integer polynomial
+    /// from those residues using the Chinese remainder theorem, and confirm the
+    /// candidate by trial division; primes that make the modular GCD come out with
+    /// too high a degree (bad luck rather than a bad prime) are detected and skipped
+    ///
+    /// unlike [`subresultant_gcd`](Polynomial::subresultant_gcd), all the arithmetic
+    /// done per-prime happens in a word-size field, so this doesn't suffer from the
+    /// same intermediate coefficient blow-up on large inputs; both `self` and `rhs`
+    /// must be non-zero
+    pub fn modular_gcd(&self, rhs: &Self) -> Polynomial<BigInt> {
+        assert!(!self.is_zero(), "modular_gcd requires a non-zero left-hand side");
+        assert!(!rhs.is_zero(), "modular_gcd requires a non-zero right-hand side");
+        let lc_a = self.highest_power_coefficient();
+        let lc_b = rhs.highest_power_coefficient();
+        let lc_gcd = GCD::gcd(&lc_a, &lc_b);
+        let smaller_operand = if self.degree() <= rhs.degree() { self } else { rhs };
+
+        let mut prime = 2;
+        let mut best_degree = None;
+        let mut accepted_primes: Vec<i32> = Vec::new();
+        let mut accepted_coefficients: Vec<Vec<u64>> = Vec::new();
+        loop {
+            prime = next_prime_i32(prime).expect("ran out of word-size primes to try");
+            if lc_a.is_multiple_of(&prime.into()) || lc_b.is_multiple_of(&prime.into()) {
+                // a or b would lose degree mod prime
+                continue;
+            }
+            let modulus = KnownPrime::new_unsafe(prime);
+            let convert = |poly: &Polynomial<BigInt>| -> Polynomial<ModularInteger<i32, KnownPrime<i32>>> {
+                poly.iter()
+                    .map(|coefficient| ModularInteger::<i32, _>::from_bigint(&coefficient, modulus))
+                    .collect()
+            };
+            let gcd_mod_p = GCD::gcd(&convert(self), &convert(rhs));
+            let degree_p = match gcd_mod_p.degree() {
+                None => continue, // can't happen since both operands are non-zero mod prime
+                Some(v) => v,
+            };
+            match best_degree {
+                Some(best) if degree_p > best => continue, // unlucky prime, discard
+                Some(best) if degree_p == best => {}
+                _ => {
+                    // either the first prime, or one revealing a smaller true degree
+                    // than every prime seen so far -- restart from scratch
+                    best_degree = Some(degree_p);
+                    accepted_primes.clear();
+                    accepted_coefficients.clear();
+                }
+            }
+            let lc_gcd_mod_p = ModularInteger::from_bigint(&lc_gcd, modulus);
+            // scale the monic modular GCD so its leading coefficient matches
+            // lc_gcd, which is what the true integer GCD's leading coefficient
+            // (once scaled by lc_gcd / lc(gcd)) is congruent to mod every prime
+            let scaled = gcd_mod_p * lc_gcd_mod_p;
+            accepted_primes.push(prime);
+            accepted_coefficients.push(
+                (0..=degree_p)
+                    .map(|i| {
+                        scaled
+                            .nonzero_coefficient(i)
+                            .map_or(0, |c| *c.value() as u64)
+                    })
+                    .collect(),
+            );
+
+            let modulus_product: BigInt = accepted_primes.iter().map(|&p| BigInt::from(p)).product();
+            if modulus_product <= modular_gcd_needed_modulus(smaller_operand, degree_p, &lc_gcd) {
+                continue;
+            }
+
+            let basis = Arc::new(RnsBasis::new(
+                accepted_primes.iter().map(|&p| p as u64).collect(),
+            ));
+            let half_product = &modulus_product / 2;
+            let candidate: Polynomial<BigInt> = (0..=degree_p)
+                .map(|i| {
+                    let residues: Vec<u64> = accepted_coefficients
+                        .iter()
+                        .map(|coefficients| coefficients[i])
+                        .collect();
+                    let value = RnsInteger::new(residues, basis.clone()).to_bigint();
+                    if value > half_product {
+                        value - &modulus_product
+                    } else {
+                        value
+                    }
+                })
+                .collect();
+            let candidate = candidate.into_primitive_part();
+
+            if self.clone().checked_exact_pseudo_div(&candidate).is_some()
+                && rhs.clone().checked_exact_pseudo_div(&candidate).is_some()
+            {
+                return candidate.into_reduced();
+            }
+            // the candidate wasn't a common divisor after all -- an unlucky
+            // combination of otherwise-good primes; try more primes
+        }
+    }
+    /// computes `gcd(self, rhs)`, automatically choosing between
+    /// [`subresultant_gcd`](Polynomial::subresultant_gcd) and [`modular_gcd`](Polynomial::modular_gcd)
+    /// based on the degree of the inputs
+    pub fn gcd_over_integers(&self, rhs: &Self) -> Polynomial<BigInt> {
+        let lhs = self.to_reduced();
+        let rhs = rhs.to_reduced();
+        if lhs.is_zero() {
+            return rhs;
+        }
+        if rhs.is_zero() {
+            return lhs;
+        }
+        let degree = lhs.degree().unwrap_or(0).max(rhs.degree().unwrap_or(0));
+        if degree >= MIN_DEGREE_FOR_MODULAR_GCD {
+            lhs.modular_gcd(&rhs)
+        } else {
+            lhs.subresultant_gcd(rhs).into_reduced()
+        }
+    }
+}
+
+/// polynomials of degree at least this large use [`Polynomial::modular_resultant`]
+/// by default in [`Polynomial::resultant_over_integers`], for the same reason
+/// [`MIN_DEGREE_FOR_MODULAR_GCD`] gates [`Polynomial::modular_gcd`]
+pub(crate) const MIN_DEGREE_FOR_MODULAR_RESULTANT: usize = 32;
+
+/// a bound on `2 * resultant(lhs, rhs).abs()`, computed via Hadamard's
+/// inequality applied to the resultant's Sylvester-matrix determinant:
+/// each of `rhs.degree()` rows built from `lhs`'s coefficients (and each
+/// of `lhs.degree()` rows built from `rhs`'s) contributes its Euclidean
+/// norm as a factor of the bound on the determinant
+fn resultant_needed_modulus(lhs: &Polynomial<BigInt>, rhs: &Polynomial<BigInt>) -> BigInt {
+    fn sum_of_squares(poly: &Polynomial<BigInt>) -> BigInt {
+        poly.iter().fold(BigInt::zero(), |acc, c| acc + &c * &c)
+    }
+    let n = rhs.degree().expect("rhs is known to be non-zero");
+    let m = lhs.degree().expect("lhs is known to be non-zero");
+    let bound_squared = sum_of_squares(lhs).pow(n as u32) * sum_of_squares(rhs).pow(m as u32);
+    (Roots::sqrt(&bound_squared) + 1i32) * 2i32
+}
+
+impl Polynomial<BigInt> {
+    /// computes `resultant(self, rhs)` using a multi-modular algorithm:
+    /// compute the resultant modulo several word-size primes, then
+    /// reconstruct the integer result with the Chinese remainder theorem
+    /// once enough primes have been combined to exceed a Hadamard bound
+    /// on the resultant's magnitude
+    ///
+    /// unlike [`resultant`](Polynomial::resultant), all the per-prime
+    /// arithmetic happens in a word-size field, so this doesn't suffer
+    /// from the intermediate coefficient blow-up that subresultant-based
+    /// computation has on large inputs; both `self` and `rhs` must be
+    /// non-zero
+    pub fn modular_resultant(&self, rhs: &Self) -> BigInt {
+        assert!(
+            !self.is_zero(),
+            "modular_resultant requires a non-zero left-hand side"
+        );
+        assert!(
+            !rhs.is_zero(),
+            "modular_resultant requires a non-zero right-hand side"
+        );
+        let lc_a = self.highest_power_coefficient();
+        let lc_b = rhs.highest_power_coefficient();
+        let needed_modulus = resultant_needed_modulus(self, rhs);
+
+        let mut prime = 2;
+        let mut accepted_primes: Vec<i32> = Vec::new();
+        let mut accepted_residues: Vec<u64> = Vec::new();
+        loop {
+            prime = next_prime_i32(prime).expect("ran out of word-size primes to try");
+            if lc_a.is_multiple_of(&prime.into()) || lc_b.is_multiple_of(&prime.into()) {
+                // self or rhs would lose degree mod prime, which would make
+                // the resultant mod prime come out wrong
+                continue;
+            }
+            let modulus = KnownPrime::new_unsafe(prime);
+            let convert = |poly: &Polynomial<BigInt>| -> Polynomial<ModularInteger<i32, KnownPrime<i32>>> {
+                poly.iter()
+                    .map(|coefficient| ModularInteger::<i32, _>::from_bigint(&coefficient, modulus))
+                    .collect()
+            };
+            let resultant_mod_p = convert(self)
+                .nonzero_resultant(convert(rhs))
+                .unwrap_or_else(|| ModularInteger::from_bigint(&BigInt::zero(), modulus));
+            accepted_primes.push(prime);
+            accepted_residues.push(*resultant_mod_p.value() as u64);
+
+            let modulus_product: BigInt = accepted_primes.iter().map(|&p| BigInt::from(p)).product();
+            if modulus_product <= needed_modulus {
+                continue;
+            }
+
+            let basis = Arc::new(RnsBasis::new(
+                accepted_primes.iter().map(|&p| p as u64).collect(),
+            ));
+            let value = RnsInteger::new(accepted_residues, basis).to_bigint();
+            let half_product = &modulus_product / 2;
+            return if value > half_product {
+                value - modulus_product
+            } else {
+                value
+            };
+        }
+    }
+    /// computes `resultant(self, rhs)`, automatically choosing between
+    /// [`resultant`](Polynomial::resultant) and
+    /// [`modular_resultant`](Polynomial::modular_resultant) based on the
+    /// degree of the inputs
+    pub fn resultant_over_integers(&self, rhs: &Self) -> BigInt {
+        if self.is_zero() || rhs.is_zero() {
+            return BigInt::zero();
+        }
+        let degree = self.degree().unwrap_or(0).max(rhs.degree().unwrap_or(0));
+        if degree >= MIN_DEGREE_FOR_MODULAR_RESULTANT {
+            self.modular_resultant(rhs)
+        } else {
+            self.clone().resultant(rhs.clone())
+        }
+    }
+}
+
 impl<T> GCD for Polynomial<T>
 where
     T: PolynomialCoefficient + PolynomialDivSupported + PolynomialReducingFactorSupported,
 {
     type Output = Self;
+    /// computes the GCD using [`subresultant_gcd`](Polynomial::subresultant_gcd) on the
+    /// content-free (for integer coefficients) or monic (for coefficients over a field)
+    /// versions of `self` and `rhs`, which keeps the coefficients of the intermediate
+    /// remainders from growing much faster than the coefficients of the final result
     fn gcd(&self, rhs: &Self) -> Self {
         self.to_reduced()
             .subresultant_gcd(rhs.to_reduced())
@@ -1405,4 +1654,108 @@ mod tests {
         println!("resultant = {}", resultant);
         assert!(expected == resultant);
     }
+
+    #[test]
+    fn test_gcd_integer_coefficients() {
+        // `Polynomial<BigInt>` doesn't implement `PolynomialDivSupported`
+        // since plain integers can't always divide exactly, so `GCD::gcd`
+        // isn't available; `subresultant_gcd` (plus reducing to primitive
+        // part afterwards) is the way to compute a GCD directly over `Z`
+        // without needing to go through `Ratio<BigInt>`
+        fn p(coefficients: &[i64]) -> Polynomial<BigInt> {
+            coefficients.iter().map(|&v| BigInt::from(v)).collect()
+        }
+        // (x^2 + 1) * (2*x^3 + 3*x + 6)
+        let a = p(&[6, 3, 6, 5, 0, 2]);
+        // (x^2 + 1) * (5*x^2 - 3)
+        let b = p(&[-3, 0, 2, 0, 5]);
+        let expected_gcd = p(&[1, 0, 1]);
+        println!("a = {}", a);
+        println!("b = {}", b);
+        println!("expected_gcd = {}", expected_gcd);
+        let gcd = a.subresultant_gcd(b).into_reduced();
+        println!("gcd = {}", gcd);
+        assert!(expected_gcd == gcd);
+    }
+
+    #[test]
+    fn test_modular_gcd() {
+        fn p(coefficients: &[i64]) -> Polynomial<BigInt> {
+            coefficients.iter().map(|&v| BigInt::from(v)).collect()
+        }
+        // a high-degree common factor so the inputs are well above
+        // MIN_DEGREE_FOR_MODULAR_GCD, plus two coprime cofactors
+        let mut common_factor = vec![0i64; 34];
+        common_factor[0] = 1;
+        common_factor[1] = 1;
+        common_factor[33] = 1;
+        let common_factor = p(&common_factor);
+        let a = common_factor.clone() * p(&[2, 1]);
+        let b = common_factor.clone() * p(&[3, 1]);
+        println!("a = {}", a);
+        println!("b = {}", b);
+        let expected_gcd = common_factor.into_reduced();
+        println!("expected_gcd = {}", expected_gcd);
+        let gcd = a.modular_gcd(&b);
+        println!("modular_gcd = {}", gcd);
+        assert!(expected_gcd == gcd);
+        assert!(gcd == a.gcd_over_integers(&b));
+    }
+
+    #[test]
+    fn test_gcd_over_integers() {
+        fn p(coefficients: &[i64]) -> Polynomial<BigInt> {
+            coefficients.iter().map(|&v| BigInt::from(v)).collect()
+        }
+        // below MIN_DEGREE_FOR_MODULAR_GCD, so this exercises the
+        // subresultant_gcd path instead of modular_gcd
+        let a = p(&[6, 3, 6, 5, 0, 2]); // (x^2 + 1) * (2*x^3 + 3*x + 6)
+        let b = p(&[-3, 0, 2, 0, 5]); // (x^2 + 1) * (5*x^2 - 3)
+        let expected_gcd = p(&[1, 0, 1]);
+        let gcd = a.gcd_over_integers(&b);
+        println!("gcd = {}", gcd);
+        assert!(expected_gcd == gcd);
+        // zero on either side just returns the reduced other operand
+        assert!(a.gcd_over_integers(&Polynomial::zero()) == a.to_reduced());
+        assert!(Polynomial::zero().gcd_over_integers(&b) == b.to_reduced());
+    }
+
+    #[test]
+    fn test_modular_resultant() {
+        fn p(coefficients: &[i64]) -> Polynomial<BigInt> {
+            coefficients.iter().map(|&v| BigInt::from(v)).collect()
+        }
+        // a and b are both above MIN_DEGREE_FOR_MODULAR_RESULTANT
+        let mut a_coefficients = vec![0i64; 33];
+        a_coefficients[0] = -1;
+        a_coefficients[1] = 1;
+        a_coefficients[32] = 1;
+        let a = p(&a_coefficients);
+        let mut b_coefficients = vec![0i64; 33];
+        b_coefficients[0] = 1;
+        b_coefficients[1] = 2;
+        b_coefficients[32] = 1;
+        let b = p(&b_coefficients);
+        let expected = a.clone().resultant(b.clone());
+        println!("expected = {}", expected);
+        let resultant = a.modular_resultant(&b);
+        println!("modular_resultant = {}", resultant);
+        assert!(expected == resultant);
+        assert!(resultant == a.resultant_over_integers(&b));
+    }
+
+    #[test]
+    fn test_resultant_over_integers() {
+        fn p(coefficients: &[i64]) -> Polynomial<BigInt> {
+            coefficients.iter().map(|&v| BigInt::from(v)).collect()
+        }
+        // below MIN_DEGREE_FOR_MODULAR_RESULTANT, so this exercises the
+        // subresultant-based resultant path instead of modular_resultant
+        let a = p(&[-1, 0, 1]); // x^2 - 1
+        let b = p(&[-4, 0, 1]); // x^2 - 4
+        assert_eq!(a.resultant_over_integers(&b), BigInt::from(9));
+        // zero on either side gives a zero resultant
+        assert!(a.resultant_over_integers(&Polynomial::zero()).is_zero());
+        assert!(Polynomial::zero().resultant_over_integers(&b).is_zero());
+    }
 }
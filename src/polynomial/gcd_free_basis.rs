@@ -0,0 +1,193 @@
+// SPDX-License-Identifier: LGPL-2.1-or-later
+// See Notices.txt for copyright information
+
+//! refining a set of polynomials into a pairwise-coprime (GCD-free) basis,
+//! and re-expressing each input in terms of that basis
+
+use crate::{
+    polynomial::{
+        Polynomial, PolynomialCoefficient, PolynomialDivSupported, PolynomialFactor,
+        PolynomialFactors, PolynomialReducingFactorSupported,
+    },
+    traits::{ExactDiv, GCD},
+};
+
+/// a basis element that hasn't finished being refined yet: a reduced
+/// polynomial together with which of the original inputs it divides,
+/// with an input's index appearing once for every power of this element
+/// that divides it
+struct Atom<T: PolynomialCoefficient> {
+    polynomial: Polynomial<T>,
+    owners: Vec<usize>,
+}
+
+/// the result of [`gcd_free_basis`]
+#[derive(Clone, Eq, Hash, PartialEq, Debug)]
+pub struct GcdFreeBasis<T: PolynomialCoefficient> {
+    /// the pairwise-coprime, non-unit basis elements
+    pub basis: Vec<Polynomial<T>>,
+    /// `factorizations[i]` expresses the `i`th polynomial passed to
+    /// [`gcd_free_basis`] as `constant_factor * prod(factor.polynomial.pow(factor.power))`,
+    /// where every `factor.polynomial` also appears somewhere in `basis`
+    pub factorizations: Vec<PolynomialFactors<T>>,
+}
+
+/// refines `polynomials` into a set of pairwise-coprime basis elements
+/// and expresses each input as a product of powers of those elements
+///
+/// works by repeatedly replacing any two elements that share a
+/// non-trivial GCD `g` with up to three coprime-or-smaller pieces: `g`
+/// itself (recording that both original elements are divisible by it),
+/// and each original element divided by `g` (dropped if that leaves only
+/// a unit); since every such split strictly reduces the combined degree
+/// of the elements involved, this always terminates
+///
+/// combining the squarefree parts of several polynomials, or simplifying
+/// a product of algebraic numbers' minimal polynomials, are both easier
+/// once everything's expressed over a common pairwise-coprime basis
+/// instead of over possibly-overlapping factors
+pub fn gcd_free_basis<T>(polynomials: &[Polynomial<T>]) -> GcdFreeBasis<T>
+where
+    T: PolynomialDivSupported + PolynomialReducingFactorSupported + num_traits::Zero,
+{
+    let mut factorizations: Vec<PolynomialFactors<T>> = Vec::with_capacity(polynomials.len());
+    let mut to_insert: Vec<Atom<T>> = Vec::new();
+    for (index, polynomial) in polynomials.iter().enumerate() {
+        factorizations.push(PolynomialFactors {
+            constant_factor: polynomial.reducing_factor(),
+            polynomial_factors: Vec::new(),
+        });
+        let reduced = polynomial.to_reduced();
+        if reduced.degree().unwrap_or(0) != 0 {
+            to_insert.push(Atom {
+                polynomial: reduced,
+                owners: vec![index],
+            });
+        }
+    }
+    let mut basis: Vec<Atom<T>> = Vec::new();
+    while let Some(atom) = to_insert.pop() {
+        let conflict = basis.iter().enumerate().find_map(|(index, basis_atom)| {
+            let common = basis_atom.polynomial.gcd(&atom.polynomial);
+            if common.degree().unwrap_or(0) == 0 {
+                None
+            } else {
+                Some((index, common))
+            }
+        });
+        let Some((index, common)) = conflict else {
+            basis.push(atom);
+            continue;
+        };
+        let basis_atom = basis.remove(index);
+        let basis_rest = (&basis_atom.polynomial).exact_div(&common).into_reduced();
+        let atom_rest = (&atom.polynomial).exact_div(&common).into_reduced();
+        let mut common_owners = basis_atom.owners.clone();
+        common_owners.extend(atom.owners.iter().copied());
+        to_insert.push(Atom {
+            polynomial: common,
+            owners: common_owners,
+        });
+        if basis_rest.degree().unwrap_or(0) != 0 {
+            to_insert.push(Atom {
+                polynomial: basis_rest,
+                owners: basis_atom.owners,
+            });
+        }
+        if atom_rest.degree().unwrap_or(0) != 0 {
+            to_insert.push(Atom {
+                polynomial: atom_rest,
+                owners: atom.owners,
+            });
+        }
+    }
+    let mut basis_polynomials = Vec::with_capacity(basis.len());
+    for atom in basis {
+        basis_polynomials.push(atom.polynomial.clone());
+        let mut owners = atom.owners;
+        owners.sort_unstable();
+        let mut owners = owners.into_iter().peekable();
+        while let Some(owner) = owners.next() {
+            let mut power = 1;
+            while owners.peek() == Some(&owner) {
+                owners.next();
+                power += 1;
+            }
+            factorizations[owner].polynomial_factors.push(PolynomialFactor {
+                polynomial: atom.polynomial.clone(),
+                power,
+            });
+        }
+    }
+    GcdFreeBasis {
+        basis: basis_polynomials,
+        factorizations,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_bigint::BigInt;
+    use num_rational::Ratio;
+
+    fn r(n: i64, d: i64) -> Ratio<BigInt> {
+        Ratio::new(n.into(), d.into())
+    }
+
+    fn p(coefficients: &[i64]) -> Polynomial<Ratio<BigInt>> {
+        coefficients.iter().map(|&v| r(v, 1)).collect()
+    }
+
+    fn reconstruct(factors: &PolynomialFactors<Ratio<BigInt>>) -> Polynomial<Ratio<BigInt>> {
+        factors.polynomial_factors.iter().fold(
+            Polynomial::from(factors.constant_factor.clone()),
+            |acc, factor| acc * num_traits::Pow::pow(factor.polynomial.clone(), factor.power),
+        )
+    }
+
+    #[test]
+    fn test_gcd_free_basis() {
+        // a = (X - 1) * (X - 2), b = (X - 2) * (X - 3), c = X - 4
+        let a = p(&[2, -3, 1]);
+        let b = p(&[6, -5, 1]);
+        let c = p(&[-4, 1]);
+        let inputs = vec![a.clone(), b.clone(), c.clone()];
+        let result = gcd_free_basis(&inputs);
+        // every pair of basis elements must be coprime
+        for (i, x) in result.basis.iter().enumerate() {
+            for y in &result.basis[i + 1..] {
+                assert_eq!(x.gcd(y).degree().unwrap_or(0), 0);
+            }
+        }
+        assert_eq!(result.factorizations.len(), inputs.len());
+        for (input, factors) in inputs.iter().zip(&result.factorizations) {
+            assert_eq!(&reconstruct(factors), input);
+        }
+    }
+
+    #[test]
+    fn test_gcd_free_basis_already_coprime() {
+        let a = p(&[-1, 1]);
+        let b = p(&[-2, 1]);
+        let inputs = vec![a.clone(), b.clone()];
+        let result = gcd_free_basis(&inputs);
+        assert_eq!(result.basis.len(), 2);
+        for (input, factors) in inputs.iter().zip(&result.factorizations) {
+            assert_eq!(&reconstruct(factors), input);
+        }
+    }
+
+    #[test]
+    fn test_gcd_free_basis_repeated_factor() {
+        // a = (X - 1)^2, b = X - 1
+        let a = p(&[1, -2, 1]);
+        let b = p(&[-1, 1]);
+        let inputs = vec![a.clone(), b.clone()];
+        let result = gcd_free_basis(&inputs);
+        assert_eq!(result.basis.len(), 1);
+        for (input, factors) in inputs.iter().zip(&result.factorizations) {
+            assert_eq!(&reconstruct(factors), input);
+        }
+    }
+}
@@ -0,0 +1,139 @@
+// SPDX-License-Identifier: LGPL-2.1-or-later
+// See Notices.txt for copyright information
+
+//! standard hard-case polynomial generators -- Swinnerton-Dyer polynomials,
+//! Mignotte polynomials, and products of cyclotomic polynomials -- for
+//! stress-testing factorization and root isolation
+
+use crate::{algebraic_numbers::RealAlgebraicNumber, polynomial::Polynomial, util::next_prime_u32};
+use num_bigint::BigInt;
+use num_traits::One;
+
+impl Polynomial<BigInt> {
+    /// the `n`th cyclotomic polynomial `\Phi_n(X)`, computed recursively as
+    /// `(X^n - 1)` divided by `\Phi_d(X)` for every proper divisor `d` of `n`
+    pub fn cyclotomic(n: u64) -> Polynomial<BigInt> {
+        assert_ne!(n, 0, "cyclotomic polynomial is undefined for n == 0");
+        let mut quotient =
+            Polynomial::make_monomial(BigInt::one(), n as usize) - Polynomial::<BigInt>::one();
+        for d in 1..n {
+            if n % d == 0 {
+                quotient = quotient.div_rem(&Polynomial::cyclotomic(d)).0;
+            }
+        }
+        quotient
+    }
+
+    /// the product of the cyclotomic polynomials `\Phi_n(X)` for each `n` in
+    /// `indices`; a standard stress test for factorization since the result
+    /// is squarefree but has as many irreducible factors as `indices` has
+    /// elements
+    pub fn product_of_cyclotomics(indices: &[u64]) -> Polynomial<BigInt> {
+        indices
+            .iter()
+            .map(|&n| Polynomial::cyclotomic(n))
+            .fold(Polynomial::<BigInt>::one(), |product, factor| {
+                product * factor
+            })
+    }
+
+    /// the Mignotte polynomial `X^degree - 2 * (a * X - 1)^2`; a standard
+    /// stress test for factorization since it has two irreducible factors
+    /// whose coefficients are exponentially larger than `a`, defeating naive
+    /// factor-recombination bounds
+    pub fn mignotte(degree: usize, a: &BigInt) -> Polynomial<BigInt> {
+        let linear = Polynomial::from(vec![-BigInt::one(), a.clone()]);
+        let two_linear_squared = (linear.clone() * linear) * BigInt::from(2);
+        Polynomial::make_monomial(BigInt::one(), degree) - two_linear_squared
+    }
+
+    /// the minimal polynomial of `sqrt(p_1) + sqrt(p_2) + ... + sqrt(p_n)`,
+    /// where `p_1, ..., p_n` are the first `prime_count` primes; a standard
+    /// stress test for root isolation since it has degree `2 ^ prime_count`
+    /// with all of its roots clustered close together
+    pub fn swinnerton_dyer(prime_count: usize) -> Polynomial<BigInt> {
+        let mut sum = RealAlgebraicNumber::from(BigInt::from(0));
+        let mut prime = 2u32;
+        for _ in 0..prime_count {
+            let sqrt_prime = RealAlgebraicNumber::from(BigInt::from(prime))
+                .checked_pow((1i32, 2i32))
+                .expect("square root of a positive prime always exists");
+            sum += sqrt_prime;
+            prime = next_prime_u32(prime).expect("ran out of word-size primes");
+        }
+        sum.minimal_polynomial().clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cyclotomic() {
+        // \Phi_1(X) = X - 1
+        assert_eq!(
+            Polynomial::cyclotomic(1),
+            Polynomial::from(vec![BigInt::from(-1), BigInt::from(1)])
+        );
+        // \Phi_2(X) = X + 1
+        assert_eq!(
+            Polynomial::cyclotomic(2),
+            Polynomial::from(vec![BigInt::from(1), BigInt::from(1)])
+        );
+        // \Phi_3(X) = X^2 + X + 1
+        assert_eq!(
+            Polynomial::cyclotomic(3),
+            Polynomial::from(vec![BigInt::from(1), BigInt::from(1), BigInt::from(1)])
+        );
+        // \Phi_4(X) = X^2 + 1
+        assert_eq!(
+            Polynomial::cyclotomic(4),
+            Polynomial::from(vec![BigInt::from(1), BigInt::from(0), BigInt::from(1)])
+        );
+        // \Phi_6(X) = X^2 - X + 1
+        assert_eq!(
+            Polynomial::cyclotomic(6),
+            Polynomial::from(vec![BigInt::from(1), BigInt::from(-1), BigInt::from(1)])
+        );
+    }
+
+    #[test]
+    fn test_product_of_cyclotomics() {
+        let product = Polynomial::product_of_cyclotomics(&[1, 2, 3]);
+        let expected = Polynomial::cyclotomic(1) * Polynomial::cyclotomic(2) * Polynomial::cyclotomic(3);
+        assert_eq!(product, expected);
+        assert_eq!(product.degree(), Some(4));
+    }
+
+    #[test]
+    fn test_mignotte() {
+        // X^5 - 2 * (X - 1)^2 == X^5 - 2*X^2 + 4*X - 2
+        let poly = Polynomial::mignotte(5, &BigInt::from(1));
+        assert_eq!(
+            poly,
+            Polynomial::from(vec![
+                BigInt::from(-2),
+                BigInt::from(4),
+                BigInt::from(-2),
+                BigInt::from(0),
+                BigInt::from(0),
+                BigInt::from(1),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_swinnerton_dyer() {
+        // the minimal polynomial of sqrt(2), degree 2^1 == 2
+        let degree_1 = Polynomial::swinnerton_dyer(1);
+        assert_eq!(degree_1.degree(), Some(2));
+        assert_eq!(
+            degree_1,
+            Polynomial::from(vec![BigInt::from(-2), BigInt::from(0), BigInt::from(1)])
+        );
+        // the minimal polynomial of sqrt(2) + sqrt(3), degree 2^2 == 4
+        let degree_2 = Polynomial::swinnerton_dyer(2);
+        assert_eq!(degree_2.degree(), Some(4));
+    }
+}
@@ -0,0 +1,117 @@
+// SPDX-License-Identifier: LGPL-2.1-or-later
+// See Notices.txt for copyright information
+
+//! simple Lagrange interpolation, meant for small numbers of points where
+//! the ergonomics of a single function call matter more than the
+//! asymptotic speed a subproduct-tree-based implementation would give
+
+use crate::{
+    polynomial::{Polynomial, PolynomialCoefficient, PolynomialDivSupported},
+    traits::ExactDiv,
+};
+use num_traits::Zero;
+use std::{borrow::Cow, fmt};
+
+/// the error returned by [`Polynomial::interpolate_lagrange`] when two of
+/// the input points share the same `x` coordinate, making interpolation
+/// through all of them impossible
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DuplicateAbscissaError<T> {
+    pub x: T,
+}
+
+impl<T: fmt::Debug> fmt::Display for DuplicateAbscissaError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "duplicate abscissa in interpolation points: {:?}", self.x)
+    }
+}
+
+impl<T: fmt::Debug> std::error::Error for DuplicateAbscissaError<T> {}
+
+impl<T: PolynomialCoefficient + PolynomialDivSupported> Polynomial<T> {
+    /// computes the unique polynomial of degree less than `points.len()`
+    /// that passes through every `(x, y)` pair in `points`, using
+    /// Lagrange's interpolation formula
+    ///
+    /// meant for small numbers of points where ergonomics matter more than
+    /// speed: this does `O(points.len()^2)` coefficient operations, unlike
+    /// a subproduct-tree-based implementation, which would do better than
+    /// that asymptotically
+    ///
+    /// # Errors
+    ///
+    /// returns `Err` if any two points share the same `x` coordinate
+    pub fn interpolate_lagrange(
+        points: &[(T, T)],
+    ) -> Result<Polynomial<T>, DuplicateAbscissaError<T>> {
+        for i in 0..points.len() {
+            for (x_j, _) in &points[..i] {
+                if points[i].0 == *x_j {
+                    return Err(DuplicateAbscissaError { x: x_j.clone() });
+                }
+            }
+        }
+        let mut retval = Polynomial::zero();
+        for (i, (x_i, y_i)) in points.iter().enumerate() {
+            let one = T::make_one_coefficient_from_coefficient(Cow::Borrowed(x_i));
+            let mut numerator = Polynomial::from(one.clone());
+            let mut denominator = one.clone();
+            for (x_j, _) in points.iter().enumerate().filter_map(|(j, point)| {
+                if i == j {
+                    None
+                } else {
+                    Some(point)
+                }
+            }) {
+                numerator = numerator * Polynomial::from(vec![-x_j.clone(), one.clone()]);
+                denominator = denominator * (x_i.clone() - x_j.clone());
+            }
+            retval = retval + numerator.exact_div(denominator) * y_i.clone();
+        }
+        Ok(retval)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mod_int::{KnownPrime, ModularInteger};
+    use num_bigint::BigInt;
+    use num_rational::Ratio;
+
+    #[test]
+    fn test_interpolate_lagrange_rational() {
+        let r = |n: i64, d: i64| Ratio::<BigInt>::new(n.into(), d.into());
+        let ri = |v: i64| Ratio::<BigInt>::from_integer(v.into());
+        // y = x^2 + 1
+        let points = vec![(ri(0), ri(1)), (ri(1), ri(2)), (ri(2), ri(5))];
+        let poly = Polynomial::interpolate_lagrange(&points).unwrap();
+        assert_eq!(poly, vec![ri(1), ri(0), ri(1)].into());
+
+        // fractional slope: y = x / 2
+        let points = vec![(ri(0), ri(0)), (ri(2), ri(1))];
+        let poly = Polynomial::interpolate_lagrange(&points).unwrap();
+        assert_eq!(poly, vec![ri(0), r(1, 2)].into());
+    }
+
+    #[test]
+    fn test_interpolate_lagrange_modular() {
+        let modulus = KnownPrime::new_unsafe(7i32);
+        let m = |v: i32| ModularInteger::new(v, modulus);
+        // y = 2x + 3 mod 7
+        let points = vec![(m(0), m(3)), (m(1), m(5)), (m(2), m(0))];
+        let poly = Polynomial::interpolate_lagrange(&points).unwrap();
+        assert_eq!(poly, vec![m(3), m(2)].into());
+    }
+
+    #[test]
+    fn test_interpolate_lagrange_duplicate_abscissa() {
+        let r = |n: i64, d: i64| Ratio::<BigInt>::new(n.into(), d.into());
+        let ri = |v: i64| Ratio::<BigInt>::from_integer(v.into());
+        let points = vec![(ri(0), ri(1)), (ri(0), r(1, 2))];
+        assert_eq!(
+            Polynomial::interpolate_lagrange(&points),
+            Err(DuplicateAbscissaError { x: ri(0) })
+        );
+    }
+}
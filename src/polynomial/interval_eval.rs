@@ -0,0 +1,67 @@
+// SPDX-License-Identifier: LGPL-2.1-or-later
+// See Notices.txt for copyright information
+
+//! evaluating a polynomial at a [`DyadicFractionInterval`], bridging the
+//! polynomial and interval-arithmetic modules
+
+use crate::{interval_arithmetic::DyadicFractionInterval, polynomial::Polynomial};
+use num_bigint::BigInt;
+use num_rational::Ratio;
+
+impl Polynomial<BigInt> {
+    /// evaluates `self` at `x` using Horner's method performed directly in
+    /// interval arithmetic: every intermediate multiplication and addition
+    /// is rounded outward to `x`'s `log2_denom` as it's computed, rather
+    /// than letting the intermediate precision grow without bound
+    pub fn eval_interval(&self, x: &DyadicFractionInterval) -> DyadicFractionInterval {
+        self.eval_generic(x, DyadicFractionInterval::zero(x.log2_denom()))
+    }
+}
+
+impl Polynomial<Ratio<BigInt>> {
+    /// see [`Polynomial::<BigInt>::eval_interval`]
+    pub fn eval_interval(&self, x: &DyadicFractionInterval) -> DyadicFractionInterval {
+        self.eval_generic(x, DyadicFractionInterval::zero(x.log2_denom()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eval_interval_integer_coefficients() {
+        // (X - 1) * (X - 2) == X^2 - 3*X + 2
+        let poly = Polynomial::from(vec![BigInt::from(2), BigInt::from(-3), BigInt::from(1)]);
+        let x = DyadicFractionInterval::from_int(BigInt::from(5), 4);
+        let result = poly.eval_interval(&x);
+        let (lower, upper) = result.into_ratio_range();
+        assert_eq!(lower, Ratio::from_integer(BigInt::from(12)));
+        assert_eq!(upper, Ratio::from_integer(BigInt::from(12)));
+    }
+
+    #[test]
+    fn test_eval_interval_rational_coefficients() {
+        // X/2 + 1
+        let poly = Polynomial::from(vec![
+            Ratio::from_integer(BigInt::from(1)),
+            Ratio::new(BigInt::from(1), BigInt::from(2)),
+        ]);
+        let x = DyadicFractionInterval::from_ratio(Ratio::new(BigInt::from(1), BigInt::from(3)), 8);
+        let result = poly.eval_interval(&x);
+        let (lower, upper) = result.into_ratio_range();
+        let expected = Ratio::new(BigInt::from(7), BigInt::from(6));
+        assert!(lower <= expected && expected <= upper);
+    }
+
+    #[test]
+    fn test_eval_interval_widens_over_a_wide_interval() {
+        // X^2
+        let poly = Polynomial::from(vec![BigInt::from(0), BigInt::from(0), BigInt::from(1)]);
+        let x = DyadicFractionInterval::from_int_range(BigInt::from(-1), BigInt::from(2), 4);
+        let result = poly.eval_interval(&x);
+        let (lower, upper) = result.into_ratio_range();
+        assert!(lower <= Ratio::from_integer(BigInt::from(0)));
+        assert!(upper >= Ratio::from_integer(BigInt::from(4)));
+    }
+}
@@ -0,0 +1,296 @@
+// SPDX-License-Identifier: LGPL-2.1-or-later
+// See Notices.txt for copyright information
+
+//! isolating the real roots of an integer polynomial into disjoint
+//! intervals, with a choice of the classic Sturm-sequence bisection or a
+//! Descartes'-rule-of-signs bisection in the style of the
+//! Vincent-Akritas-Strzebonski (VAS) continued-fraction method
+
+use crate::{
+    interval_arithmetic::DyadicFractionInterval,
+    polynomial::Polynomial,
+    util::Sign,
+};
+use num_bigint::BigInt;
+use num_rational::Ratio;
+use num_traits::{One, Signed, Zero};
+use std::borrow::Cow;
+
+/// which algorithm [`Polynomial::isolate_real_roots`] uses to separate a
+/// polynomial's real roots into disjoint intervals
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum RootIsolationAlgorithm {
+    /// bisect the search interval, using a Sturm sequence to count how
+    /// many roots remain on each side; reliable, but the Sturm sequence
+    /// can get large for high-degree sparse polynomials
+    Sturm,
+    /// bisect the search interval, using Descartes' rule of signs (via
+    /// the same shift-and-reverse transform used by the
+    /// Vincent-Akritas-Strzebonski continued-fraction method) to count
+    /// how many roots remain on each side; avoids computing a full Sturm
+    /// sequence, so it tends to be faster on high-degree sparse
+    /// polynomials
+    ContinuedFraction,
+}
+
+/// `1 +` the largest ratio of a non-leading coefficient's absolute value
+/// to the leading coefficient's absolute value; every real root of
+/// `polynomial` has absolute value strictly less than the returned bound
+pub(super) fn cauchy_root_bound(polynomial: &Polynomial<BigInt>) -> BigInt {
+    let leading = Ratio::from(polynomial.highest_power_coefficient().abs());
+    let max_ratio = (0..polynomial.len() - 1)
+        .map(|index| Ratio::from(polynomial.coefficient(index).abs()) / &leading)
+        .fold(Ratio::zero(), |max_ratio, ratio| max_ratio.max(ratio));
+    (max_ratio + Ratio::one()).ceil().to_integer()
+}
+
+/// the sign of `polynomial` in the limit as it's evaluated approaching
+/// `at` from the given side; unlike evaluating directly at `at`, this is
+/// well-defined even when `polynomial` has a root at `at`, since it looks
+/// at successive derivatives until it finds one that doesn't also vanish
+/// there
+fn one_sided_sign(
+    polynomial: &Polynomial<BigInt>,
+    at: &Ratio<BigInt>,
+    approach_from_above: bool,
+) -> Option<Sign> {
+    let mut polynomial = Cow::Borrowed(polynomial);
+    let mut derivative_order_is_odd = false;
+    loop {
+        if let Some(sign) = Sign::new(&polynomial.eval_generic(at, Ratio::zero())) {
+            return Some(if !approach_from_above && derivative_order_is_odd {
+                -sign
+            } else {
+                sign
+            });
+        }
+        if polynomial.is_empty() {
+            return None;
+        }
+        polynomial = Cow::Owned(polynomial.derivative());
+        derivative_order_is_odd = !derivative_order_is_odd;
+    }
+}
+
+/// the number of sign variations in `sturm_sequence`, evaluated in the
+/// limit approaching `at` from the given side, per Sturm's theorem
+fn sturm_sign_variations(
+    sturm_sequence: &[Polynomial<BigInt>],
+    at: &Ratio<BigInt>,
+    approach_from_above: bool,
+) -> usize {
+    let mut sign_variations = 0;
+    let mut last_sign = None;
+    for polynomial in sturm_sequence {
+        if let Some(sign) = one_sided_sign(polynomial, at, approach_from_above) {
+            if last_sign.map_or(false, |last_sign| last_sign != sign) {
+                sign_variations += 1;
+            }
+            last_sign = Some(sign);
+        }
+    }
+    sign_variations
+}
+
+/// the exact number of distinct real roots of the polynomial with Sturm
+/// sequence `sturm_sequence` that lie strictly between `lower_bound` and
+/// `upper_bound`
+fn sturm_root_count(
+    sturm_sequence: &[Polynomial<BigInt>],
+    lower_bound: &Ratio<BigInt>,
+    upper_bound: &Ratio<BigInt>,
+) -> usize {
+    sturm_sign_variations(sturm_sequence, lower_bound, true)
+        - sturm_sign_variations(sturm_sequence, upper_bound, false)
+}
+
+/// reverses the order of `polynomial`'s coefficients, so that (ignoring
+/// the case where `polynomial` has a root at zero, which changes the
+/// degree) `reverse_coefficients(p)(x) == x.pow(p.degree()) * p(1 / x)`
+fn reverse_coefficients(polynomial: &Polynomial<Ratio<BigInt>>) -> Polynomial<Ratio<BigInt>> {
+    let mut coefficients = polynomial.clone().into_coefficients();
+    coefficients.reverse();
+    Polynomial::from(coefficients)
+}
+
+/// the number of sign variations in `polynomial`'s coefficients, per
+/// Descartes' rule of signs; zero coefficients are skipped rather than
+/// treated as a sign change
+fn descartes_sign_variations(polynomial: &Polynomial<Ratio<BigInt>>) -> usize {
+    let mut sign_variations = 0;
+    let mut last_sign = None;
+    for coefficient in polynomial.clone().into_coefficients() {
+        if let Some(sign) = Sign::new(&coefficient) {
+            if last_sign.map_or(false, |last_sign| last_sign != sign) {
+                sign_variations += 1;
+            }
+            last_sign = Some(sign);
+        }
+    }
+    sign_variations
+}
+
+/// an upper bound (exact when it's `0` or `1`) on the number of distinct
+/// real roots of `polynomial` that lie strictly between `lower_bound` and
+/// `upper_bound`, found by mapping `(lower_bound, upper_bound)` onto
+/// `(0, \u{221e})` and counting sign variations of the transformed
+/// polynomial's coefficients
+fn descartes_root_bound(
+    polynomial: &Polynomial<Ratio<BigInt>>,
+    lower_bound: &Ratio<BigInt>,
+    upper_bound: &Ratio<BigInt>,
+) -> usize {
+    let scaled = polynomial.shift_scale(lower_bound, &(upper_bound - lower_bound));
+    let transformed = reverse_coefficients(&scaled).shift_scale(&Ratio::one(), &Ratio::one());
+    descartes_sign_variations(&transformed)
+}
+
+/// bisects `interval`, using `root_count_in` to decide whether it's done
+/// (no roots, or a single root known tightly enough), or needs to be split
+/// further; the two halves, once split, no longer depend on each other, so
+/// [`isolate_real_roots_by_bisection`]'s `rayon` path recurses into them on
+/// separate threads
+fn isolate_interval_by_bisection(
+    interval: DyadicFractionInterval,
+    polynomial: &Polynomial<BigInt>,
+    precision: usize,
+    root_count_in: &(impl Fn(&Ratio<BigInt>, &Ratio<BigInt>) -> usize + Sync),
+) -> Vec<DyadicFractionInterval> {
+    let (lower_bound, upper_bound) = interval.to_ratio_range();
+    let root_count = root_count_in(&lower_bound, &upper_bound);
+    if root_count == 0 {
+        return Vec::new();
+    }
+    if root_count == 1 && interval.is_tighter_than(precision) {
+        return vec![interval];
+    }
+    let (lower_half, upper_half) = interval.bisect();
+    let midpoint = lower_half.upper_bound();
+    let mut roots = if polynomial.eval_generic(&midpoint, Ratio::zero()).is_zero() {
+        vec![DyadicFractionInterval::from_ratio(midpoint, precision)]
+    } else {
+        Vec::new()
+    };
+    #[cfg(feature = "rayon")]
+    let (mut lower_roots, mut upper_roots) = rayon::join(
+        || isolate_interval_by_bisection(lower_half, polynomial, precision, root_count_in),
+        || isolate_interval_by_bisection(upper_half, polynomial, precision, root_count_in),
+    );
+    #[cfg(not(feature = "rayon"))]
+    let (mut lower_roots, mut upper_roots) = (
+        isolate_interval_by_bisection(lower_half, polynomial, precision, root_count_in),
+        isolate_interval_by_bisection(upper_half, polynomial, precision, root_count_in),
+    );
+    roots.append(&mut lower_roots);
+    roots.append(&mut upper_roots);
+    roots
+}
+
+/// repeatedly bisects the interval `[-bound, bound]`, where `bound` is a
+/// [`cauchy_root_bound`] for `polynomial`, using `root_count_in` to
+/// decide which parts to discard, keep, or split further, until every
+/// remaining root is isolated in its own interval that's tighter than
+/// `precision` bits; splits the work across `rayon` threads behind the
+/// `rayon` feature, since separate intervals never affect each other's
+/// answer
+fn isolate_real_roots_by_bisection(
+    polynomial: &Polynomial<BigInt>,
+    precision: usize,
+    root_count_in: impl Fn(&Ratio<BigInt>, &Ratio<BigInt>) -> usize + Sync,
+) -> Vec<DyadicFractionInterval> {
+    let bound = cauchy_root_bound(polynomial);
+    let initial_interval = DyadicFractionInterval::from_int_range(-&bound, bound, 0);
+    let mut roots =
+        isolate_interval_by_bisection(initial_interval, polynomial, precision, &root_count_in);
+    roots.sort_by(|a, b| a.lower_bound().cmp(&b.lower_bound()));
+    roots
+}
+
+impl Polynomial<BigInt> {
+    /// isolates `self`'s distinct real roots into disjoint intervals,
+    /// each tighter than `precision` bits, using `algorithm` to decide
+    /// how many roots remain in a candidate interval; returns an empty
+    /// `Vec` for the zero polynomial, since every real number is a root
+    pub fn isolate_real_roots(
+        &self,
+        precision: usize,
+        algorithm: RootIsolationAlgorithm,
+    ) -> Vec<DyadicFractionInterval> {
+        if self.is_empty() {
+            return Vec::new();
+        }
+        match algorithm {
+            RootIsolationAlgorithm::Sturm => {
+                let sturm_sequence = self.to_primitive_sturm_sequence();
+                isolate_real_roots_by_bisection(self, precision, |lower_bound, upper_bound| {
+                    sturm_root_count(&sturm_sequence, lower_bound, upper_bound)
+                })
+            }
+            RootIsolationAlgorithm::ContinuedFraction => {
+                let rational_self = Polynomial::from(
+                    self.clone()
+                        .into_coefficients()
+                        .into_iter()
+                        .map(Ratio::from)
+                        .collect::<Vec<_>>(),
+                );
+                isolate_real_roots_by_bisection(self, precision, |lower_bound, upper_bound| {
+                    descartes_root_bound(&rational_self, lower_bound, upper_bound)
+                })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn p(coefficients: &[i64]) -> Polynomial<BigInt> {
+        Polynomial::from(coefficients.iter().map(|&v| BigInt::from(v)).collect::<Vec<_>>())
+    }
+
+    fn check_isolate_real_roots(polynomial: &Polynomial<BigInt>, expected_roots: &[(i64, i64)]) {
+        for &algorithm in &[
+            RootIsolationAlgorithm::Sturm,
+            RootIsolationAlgorithm::ContinuedFraction,
+        ] {
+            let roots = polynomial.isolate_real_roots(8, algorithm);
+            assert_eq!(roots.len(), expected_roots.len(), "algorithm: {:?}", algorithm);
+            for (root, &(numer, denom)) in roots.iter().zip(expected_roots) {
+                let expected = Ratio::new(BigInt::from(numer), BigInt::from(denom));
+                assert!(
+                    root.lower_bound() <= expected && expected <= root.upper_bound(),
+                    "algorithm: {:?}, root: {:?}, expected: {}",
+                    algorithm,
+                    root,
+                    expected
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_isolate_real_roots() {
+        // (X - 1) * (X - 2) * (X - 3)
+        check_isolate_real_roots(&p(&[-6, 11, -6, 1]), &[(1, 1), (2, 1), (3, 1)]);
+        // X^2 - 2, roots at +/- sqrt(2) ~= +/- 1.41421356
+        check_isolate_real_roots(&p(&[-2, 0, 1]), &[(-707, 500), (707, 500)]);
+        // no real roots
+        check_isolate_real_roots(&p(&[1, 0, 1]), &[]);
+        // zero polynomial
+        check_isolate_real_roots(&Polynomial::zero(), &[]);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_isolate_real_roots_parallel() {
+        // same cases as test_isolate_real_roots, run again to make sure the
+        // rayon-parallel recursion in isolate_interval_by_bisection agrees
+        // with the known-correct results
+        check_isolate_real_roots(&p(&[-6, 11, -6, 1]), &[(1, 1), (2, 1), (3, 1)]);
+        check_isolate_real_roots(&p(&[-2, 0, 1]), &[(-707, 500), (707, 500)]);
+        check_isolate_real_roots(&p(&[1, 0, 1]), &[]);
+        check_isolate_real_roots(&Polynomial::zero(), &[]);
+    }
+}
@@ -0,0 +1,314 @@
+// SPDX-License-Identifier: LGPL-2.1-or-later
+// See Notices.txt for copyright information
+
+//! a low-effort adapter for using coefficients from a user-defined ring,
+//! for callers who don't want to implement the full
+//! [`PolynomialCoefficient`] trait (with the coefficient/element/divisor
+//! machinery it uses internally, e.g. to let a whole polynomial's
+//! coefficients share a single denominator) themselves
+
+use crate::polynomial::{DivisorIsOne, PolynomialCoefficient};
+use num_traits::{One, Zero};
+use std::{
+    borrow::Cow,
+    fmt, hash, mem,
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+/// a commutative ring simple enough to be wrapped in
+/// [`SimpleRingCoefficient`] and used directly as
+/// [`Polynomial`] coefficients, without implementing
+/// [`PolynomialCoefficient`]'s element/divisor machinery
+pub trait SimpleRing:
+    Clone
+    + Eq
+    + fmt::Debug
+    + hash::Hash
+    + Zero
+    + One
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+{
+}
+
+impl<T> SimpleRing for T where
+    T: Clone
+        + Eq
+        + fmt::Debug
+        + hash::Hash
+        + Zero
+        + One
+        + Add<Output = Self>
+        + Sub<Output = Self>
+        + Mul<Output = Self>
+{
+}
+
+/// wraps a [`SimpleRing`] so it can be used as [`Polynomial`] coefficients:
+/// every coefficient is its own element, and there's no shared divisor
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SimpleRingCoefficient<T>(pub T);
+
+/// computes `element * multiplier` using only `T`'s ring operations, by
+/// repeated doubling; used since [`SimpleRing`] doesn't require a way to
+/// convert a `usize` directly into `T`
+fn mul_by_repeated_doubling<T: Add<Output = T> + Clone + Zero>(
+    mut element: T,
+    mut multiplier: usize,
+) -> T {
+    let mut retval = T::zero();
+    while multiplier != 0 {
+        if multiplier & 1 != 0 {
+            retval = retval + element.clone();
+        }
+        multiplier >>= 1;
+        if multiplier != 0 {
+            element = element.clone() + element;
+        }
+    }
+    retval
+}
+
+impl<T: SimpleRing> Zero for SimpleRingCoefficient<T> {
+    fn zero() -> Self {
+        SimpleRingCoefficient(T::zero())
+    }
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl<T: SimpleRing> One for SimpleRingCoefficient<T> {
+    fn one() -> Self {
+        SimpleRingCoefficient(T::one())
+    }
+    fn is_one(&self) -> bool {
+        self.0.is_one()
+    }
+}
+
+impl<T: SimpleRing> Add for SimpleRingCoefficient<T> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        SimpleRingCoefficient(self.0 + rhs.0)
+    }
+}
+
+impl<T: SimpleRing> Sub for SimpleRingCoefficient<T> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        SimpleRingCoefficient(self.0 - rhs.0)
+    }
+}
+
+impl<T: SimpleRing> Mul for SimpleRingCoefficient<T> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        SimpleRingCoefficient(self.0 * rhs.0)
+    }
+}
+
+impl<T: SimpleRing> Neg for SimpleRingCoefficient<T> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        SimpleRingCoefficient(T::zero() - self.0)
+    }
+}
+
+impl<'a, T: SimpleRing> Add<&'a Self> for SimpleRingCoefficient<T> {
+    type Output = Self;
+    fn add(self, rhs: &'a Self) -> Self {
+        self + rhs.clone()
+    }
+}
+
+impl<'a, T: SimpleRing> Sub<&'a Self> for SimpleRingCoefficient<T> {
+    type Output = Self;
+    fn sub(self, rhs: &'a Self) -> Self {
+        self - rhs.clone()
+    }
+}
+
+impl<'a, T: SimpleRing> Mul<&'a Self> for SimpleRingCoefficient<T> {
+    type Output = Self;
+    fn mul(self, rhs: &'a Self) -> Self {
+        self * rhs.clone()
+    }
+}
+
+impl<T: SimpleRing> AddAssign for SimpleRingCoefficient<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = self.clone() + rhs;
+    }
+}
+
+impl<T: SimpleRing> SubAssign for SimpleRingCoefficient<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.clone() - rhs;
+    }
+}
+
+impl<T: SimpleRing> MulAssign for SimpleRingCoefficient<T> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.clone() * rhs;
+    }
+}
+
+impl<'a, T: SimpleRing> AddAssign<&'a Self> for SimpleRingCoefficient<T> {
+    fn add_assign(&mut self, rhs: &'a Self) {
+        *self = self.clone() + rhs;
+    }
+}
+
+impl<'a, T: SimpleRing> SubAssign<&'a Self> for SimpleRingCoefficient<T> {
+    fn sub_assign(&mut self, rhs: &'a Self) {
+        *self = self.clone() - rhs;
+    }
+}
+
+impl<'a, T: SimpleRing> MulAssign<&'a Self> for SimpleRingCoefficient<T> {
+    fn mul_assign(&mut self, rhs: &'a Self) {
+        *self = self.clone() * rhs;
+    }
+}
+
+impl<T: SimpleRing> PolynomialCoefficient for SimpleRingCoefficient<T> {
+    type Element = Self;
+    type Divisor = DivisorIsOne;
+    const NESTING_DEPTH: usize = 0;
+    fn is_element_zero(element: &Self::Element) -> bool {
+        element.is_zero()
+    }
+    fn is_element_one(element: &Self::Element) -> bool {
+        element.is_one()
+    }
+    fn is_coefficient_zero(coefficient: &Self) -> bool {
+        coefficient.is_zero()
+    }
+    fn is_coefficient_one(coefficient: &Self) -> bool {
+        coefficient.is_one()
+    }
+    fn set_element_zero(element: &mut Self::Element) {
+        element.set_zero();
+    }
+    fn set_element_one(element: &mut Self::Element) {
+        element.set_one();
+    }
+    fn set_coefficient_zero(coefficient: &mut Self) {
+        coefficient.set_zero();
+    }
+    fn set_coefficient_one(coefficient: &mut Self) {
+        coefficient.set_one();
+    }
+    fn make_zero_coefficient_from_element(element: Cow<Self::Element>) -> Self {
+        Self::make_zero_element(element)
+    }
+    fn make_one_coefficient_from_element(element: Cow<Self::Element>) -> Self {
+        Self::make_one_element(element)
+    }
+    fn make_zero_coefficient_from_coefficient(coefficient: Cow<Self>) -> Self {
+        Self::make_zero_element(coefficient)
+    }
+    fn make_one_coefficient_from_coefficient(coefficient: Cow<Self>) -> Self {
+        Self::make_one_element(coefficient)
+    }
+    fn negate_element(element: &mut Self::Element) {
+        *element = -mem::replace(element, Zero::zero());
+    }
+    fn mul_element_by_usize(element: Cow<Self::Element>, multiplier: usize) -> Self::Element {
+        SimpleRingCoefficient(mul_by_repeated_doubling(element.into_owned().0, multiplier))
+    }
+    fn mul_assign_element_by_usize(element: &mut Self::Element, multiplier: usize) {
+        let value = mem::replace(element, Zero::zero());
+        *element = SimpleRingCoefficient(mul_by_repeated_doubling(value.0, multiplier));
+    }
+    fn divisor_to_element(_v: Cow<Self::Divisor>, _: Cow<Self::Element>) -> Self::Element {
+        One::one()
+    }
+    fn coefficients_to_elements(coefficients: Cow<[Self]>) -> (Vec<Self::Element>, Self::Divisor) {
+        (coefficients.into_owned(), DivisorIsOne)
+    }
+    fn make_coefficient(element: Cow<Self::Element>, _divisor: Cow<Self::Divisor>) -> Self {
+        element.into_owned()
+    }
+    fn reduce_divisor(_elements: &mut [Self::Element], _divisor: &mut Self::Divisor) {}
+    fn coefficient_to_element(coefficient: Cow<Self>) -> (Self::Element, Self::Divisor) {
+        (coefficient.into_owned(), DivisorIsOne)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polynomial::Polynomial;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct IntMod5(u8);
+
+    impl Zero for IntMod5 {
+        fn zero() -> Self {
+            IntMod5(0)
+        }
+        fn is_zero(&self) -> bool {
+            self.0 == 0
+        }
+    }
+
+    impl One for IntMod5 {
+        fn one() -> Self {
+            IntMod5(1)
+        }
+    }
+
+    impl Add for IntMod5 {
+        type Output = Self;
+        fn add(self, rhs: Self) -> Self {
+            IntMod5((self.0 + rhs.0) % 5)
+        }
+    }
+
+    impl Sub for IntMod5 {
+        type Output = Self;
+        fn sub(self, rhs: Self) -> Self {
+            IntMod5((self.0 + 5 - rhs.0) % 5)
+        }
+    }
+
+    impl Mul for IntMod5 {
+        type Output = Self;
+        fn mul(self, rhs: Self) -> Self {
+            IntMod5((self.0 * rhs.0) % 5)
+        }
+    }
+
+    fn c(v: u8) -> SimpleRingCoefficient<IntMod5> {
+        SimpleRingCoefficient(IntMod5(v))
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        assert_eq!(c(3) + c(4), c(2));
+        assert_eq!(c(3) - c(4), c(4));
+        assert_eq!(c(3) * c(4), c(2));
+        assert_eq!(-c(3), c(2));
+    }
+
+    #[test]
+    fn test_polynomial_eval() {
+        // 3 + 4*X, evaluated at X = 2, over Z/5
+        let poly = Polynomial::from(vec![c(3), c(4)]);
+        assert_eq!(poly.eval(&c(2)), c(1));
+    }
+
+    #[test]
+    fn test_polynomial_add_mul() {
+        let a = Polynomial::from(vec![c(1), c(2)]);
+        let b = Polynomial::from(vec![c(3), c(4)]);
+        assert_eq!(a.clone() + b.clone(), Polynomial::from(vec![c(4), c(1)]));
+        assert_eq!(
+            a * b,
+            Polynomial::from(vec![c(3), c(0), c(3)])
+        );
+    }
+}
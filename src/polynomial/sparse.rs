@@ -0,0 +1,359 @@
+// SPDX-License-Identifier: LGPL-2.1-or-later
+// See Notices.txt for copyright information
+
+use crate::polynomial::{Polynomial, PolynomialCoefficient};
+use num_traits::Zero;
+use std::{
+    borrow::Cow,
+    cmp::Ordering,
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+/// A single-variable polynomial stored as a list of `(exponent,
+/// coefficient)` terms rather than a dense coefficient vector.
+///
+/// Only nonzero terms are kept, sorted in ascending order by exponent,
+/// with at most one term per exponent. This makes polynomials like
+/// `x^1000000 - 2` cheap to represent and manipulate, unlike
+/// [`Polynomial`], which would need a million-element coefficient
+/// vector.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SparsePolynomial<T: PolynomialCoefficient> {
+    terms: Vec<(usize, T)>,
+}
+
+impl<T: PolynomialCoefficient> Default for SparsePolynomial<T> {
+    fn default() -> Self {
+        Self { terms: Vec::new() }
+    }
+}
+
+impl<T: PolynomialCoefficient> Zero for SparsePolynomial<T> {
+    fn zero() -> Self {
+        Default::default()
+    }
+    fn set_zero(&mut self) {
+        self.terms.clear();
+    }
+    fn is_zero(&self) -> bool {
+        self.terms.is_empty()
+    }
+}
+
+impl<T: PolynomialCoefficient> SparsePolynomial<T> {
+    /// builds a polynomial from `terms`, summing coefficients that share
+    /// an exponent and dropping any term whose coefficient is zero
+    pub fn from_terms(mut terms: Vec<(usize, T)>) -> Self {
+        terms.sort_by_key(|(exponent, _)| *exponent);
+        let mut merged: Vec<(usize, T)> = Vec::with_capacity(terms.len());
+        for (exponent, coefficient) in terms {
+            match merged.last_mut() {
+                Some((last_exponent, last_coefficient)) if *last_exponent == exponent => {
+                    *last_coefficient += coefficient;
+                }
+                _ => merged.push((exponent, coefficient)),
+            }
+        }
+        merged.retain(|(_, coefficient)| !T::is_coefficient_zero(coefficient));
+        Self { terms: merged }
+    }
+    pub fn monomial(exponent: usize, coefficient: T) -> Self {
+        Self::from_terms(vec![(exponent, coefficient)])
+    }
+    /// the nonzero `(exponent, coefficient)` pairs, sorted by ascending exponent
+    pub fn terms(&self) -> &[(usize, T)] {
+        &self.terms
+    }
+    pub fn into_terms(self) -> Vec<(usize, T)> {
+        self.terms
+    }
+    pub fn degree(&self) -> Option<usize> {
+        self.terms.last().map(|(exponent, _)| *exponent)
+    }
+    pub fn nonzero_coefficient(&self, exponent: usize) -> Option<&T> {
+        let index = self
+            .terms
+            .binary_search_by_key(&exponent, |(exponent, _)| *exponent)
+            .ok()?;
+        Some(&self.terms[index].1)
+    }
+    pub fn coefficient(&self, exponent: usize) -> T
+    where
+        T: Zero,
+    {
+        self.nonzero_coefficient(exponent)
+            .cloned()
+            .unwrap_or_else(T::zero)
+    }
+    fn do_add_assign(&mut self, rhs: Cow<Self>) {
+        self.terms = merge_terms(&self.terms, &rhs.terms, Add::add, |v| v);
+    }
+    fn do_sub_assign(&mut self, rhs: Cow<Self>) {
+        self.terms = merge_terms(&self.terms, &rhs.terms, Sub::sub, Neg::neg);
+    }
+    fn do_mul_assign(&mut self, rhs: &Self) {
+        let mut terms = Vec::with_capacity(self.terms.len() * rhs.terms.len());
+        for (lhs_exponent, lhs_coefficient) in &self.terms {
+            for (rhs_exponent, rhs_coefficient) in &rhs.terms {
+                terms.push((
+                    lhs_exponent + rhs_exponent,
+                    lhs_coefficient.clone() * rhs_coefficient,
+                ));
+            }
+        }
+        *self = Self::from_terms(terms);
+    }
+}
+
+/// merges two sorted, deduplicated term lists, combining terms that share
+/// an exponent with `combine` and mapping exponent-only-in-`rhs` terms'
+/// coefficients through `rhs_only` (identity for addition, negation for
+/// subtraction); drops any resulting term whose coefficient is zero
+fn merge_terms<T: PolynomialCoefficient>(
+    lhs: &[(usize, T)],
+    rhs: &[(usize, T)],
+    combine: impl Fn(T, T) -> T,
+    rhs_only: impl Fn(T) -> T,
+) -> Vec<(usize, T)> {
+    let mut result = Vec::with_capacity(lhs.len() + rhs.len());
+    let mut lhs_iter = lhs.iter().cloned().peekable();
+    let mut rhs_iter = rhs.iter().cloned().peekable();
+    loop {
+        let ordering = match (lhs_iter.peek(), rhs_iter.peek()) {
+            (Some((lhs_exponent, _)), Some((rhs_exponent, _))) => lhs_exponent.cmp(rhs_exponent),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => break,
+        };
+        match ordering {
+            Ordering::Less => result.push(lhs_iter.next().expect("known to be Some")),
+            Ordering::Greater => {
+                let (exponent, coefficient) = rhs_iter.next().expect("known to be Some");
+                result.push((exponent, rhs_only(coefficient)));
+            }
+            Ordering::Equal => {
+                let (exponent, lhs_coefficient) = lhs_iter.next().expect("known to be Some");
+                let (_, rhs_coefficient) = rhs_iter.next().expect("known to be Some");
+                let coefficient = combine(lhs_coefficient, rhs_coefficient);
+                if !T::is_coefficient_zero(&coefficient) {
+                    result.push((exponent, coefficient));
+                }
+            }
+        }
+    }
+    result
+}
+
+impl<T: PolynomialCoefficient> From<Polynomial<T>> for SparsePolynomial<T> {
+    fn from(value: Polynomial<T>) -> Self {
+        let terms = value
+            .into_iter()
+            .enumerate()
+            .filter(|(_, coefficient)| !T::is_coefficient_zero(coefficient))
+            .collect();
+        Self { terms }
+    }
+}
+
+impl<T: PolynomialCoefficient + Zero> From<SparsePolynomial<T>> for Polynomial<T> {
+    fn from(value: SparsePolynomial<T>) -> Self {
+        let degree = match value.degree() {
+            Some(degree) => degree,
+            None => return Polynomial::zero(),
+        };
+        let mut coefficients = vec![T::zero(); degree + 1];
+        for (exponent, coefficient) in value.terms {
+            coefficients[exponent] = coefficient;
+        }
+        coefficients.into()
+    }
+}
+
+impl<T: PolynomialCoefficient> AddAssign<SparsePolynomial<T>> for SparsePolynomial<T> {
+    fn add_assign(&mut self, rhs: SparsePolynomial<T>) {
+        self.do_add_assign(Cow::Owned(rhs));
+    }
+}
+
+impl<T: PolynomialCoefficient> AddAssign<&'_ SparsePolynomial<T>> for SparsePolynomial<T> {
+    fn add_assign(&mut self, rhs: &SparsePolynomial<T>) {
+        self.do_add_assign(Cow::Borrowed(rhs));
+    }
+}
+
+impl<T: PolynomialCoefficient> SubAssign<SparsePolynomial<T>> for SparsePolynomial<T> {
+    fn sub_assign(&mut self, rhs: SparsePolynomial<T>) {
+        self.do_sub_assign(Cow::Owned(rhs));
+    }
+}
+
+impl<T: PolynomialCoefficient> SubAssign<&'_ SparsePolynomial<T>> for SparsePolynomial<T> {
+    fn sub_assign(&mut self, rhs: &SparsePolynomial<T>) {
+        self.do_sub_assign(Cow::Borrowed(rhs));
+    }
+}
+
+impl<T: PolynomialCoefficient> MulAssign<SparsePolynomial<T>> for SparsePolynomial<T> {
+    fn mul_assign(&mut self, rhs: SparsePolynomial<T>) {
+        self.do_mul_assign(&rhs);
+    }
+}
+
+impl<T: PolynomialCoefficient> MulAssign<&'_ SparsePolynomial<T>> for SparsePolynomial<T> {
+    fn mul_assign(&mut self, rhs: &SparsePolynomial<T>) {
+        self.do_mul_assign(rhs);
+    }
+}
+
+macro_rules! forward_sparse_polynomial_op_to_op_assign {
+    ($op_trait:ident, $op:ident, $op_assign:ident) => {
+        impl<T: PolynomialCoefficient> $op_trait<SparsePolynomial<T>> for SparsePolynomial<T> {
+            type Output = SparsePolynomial<T>;
+            fn $op(mut self, rhs: SparsePolynomial<T>) -> SparsePolynomial<T> {
+                self.$op_assign(rhs);
+                self
+            }
+        }
+        impl<T: PolynomialCoefficient> $op_trait<&'_ SparsePolynomial<T>> for SparsePolynomial<T> {
+            type Output = SparsePolynomial<T>;
+            fn $op(mut self, rhs: &SparsePolynomial<T>) -> SparsePolynomial<T> {
+                self.$op_assign(rhs);
+                self
+            }
+        }
+        impl<T: PolynomialCoefficient> $op_trait<SparsePolynomial<T>> for &'_ SparsePolynomial<T> {
+            type Output = SparsePolynomial<T>;
+            fn $op(self, rhs: SparsePolynomial<T>) -> SparsePolynomial<T> {
+                self.clone().$op(rhs)
+            }
+        }
+        impl<'a, 'b, T: PolynomialCoefficient> $op_trait<&'a SparsePolynomial<T>>
+            for &'b SparsePolynomial<T>
+        {
+            type Output = SparsePolynomial<T>;
+            fn $op(self, rhs: &SparsePolynomial<T>) -> SparsePolynomial<T> {
+                self.clone().$op(rhs)
+            }
+        }
+    };
+}
+
+forward_sparse_polynomial_op_to_op_assign!(Add, add, add_assign);
+forward_sparse_polynomial_op_to_op_assign!(Sub, sub, sub_assign);
+forward_sparse_polynomial_op_to_op_assign!(Mul, mul, mul_assign);
+
+impl<T: PolynomialCoefficient> Neg for SparsePolynomial<T> {
+    type Output = SparsePolynomial<T>;
+    fn neg(self) -> SparsePolynomial<T> {
+        Self {
+            terms: self
+                .terms
+                .into_iter()
+                .map(|(exponent, coefficient)| (exponent, -coefficient))
+                .collect(),
+        }
+    }
+}
+
+impl<T: PolynomialCoefficient> Neg for &'_ SparsePolynomial<T> {
+    type Output = SparsePolynomial<T>;
+    fn neg(self) -> SparsePolynomial<T> {
+        -self.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::tests::test_op_helper;
+
+    #[test]
+    fn test_from_terms_merges_and_drops_zeros() {
+        let poly = SparsePolynomial::from_terms(vec![(2, 3), (0, 1), (2, -3), (5, 0), (0, 4)]);
+        assert_eq!(poly.terms(), &[(0, 5)]);
+    }
+
+    #[test]
+    fn test_conversions() {
+        let dense = Polynomial::from(vec![1, 0, 0, 4]);
+        let sparse = SparsePolynomial::from(dense.clone());
+        assert_eq!(sparse.terms(), &[(0, 1), (3, 4)]);
+        let round_tripped: Polynomial<i32> = sparse.into();
+        assert_eq!(round_tripped, dense);
+    }
+
+    #[test]
+    fn test_large_sparse_exponent() {
+        let poly = SparsePolynomial::monomial(1_000_000, 1) - SparsePolynomial::monomial(0, 2);
+        assert_eq!(poly.degree(), Some(1_000_000));
+        assert_eq!(poly.coefficient(1_000_000), 1);
+        assert_eq!(poly.coefficient(0), -2);
+    }
+
+    #[test]
+    fn test_add() {
+        let test = |l: SparsePolynomial<i32>, r: SparsePolynomial<i32>, expected: &SparsePolynomial<i32>| {
+            test_op_helper(
+                l,
+                r,
+                expected,
+                |l, r| *l += r,
+                |l, r| *l += r,
+                |l, r| l + r,
+                |l, r| l + r,
+                |l, r| l + r,
+                |l, r| l + r,
+            );
+        };
+        test(
+            SparsePolynomial::from_terms(vec![(0, 1), (3, 4)]),
+            SparsePolynomial::from_terms(vec![(0, 5), (3, -4)]),
+            &SparsePolynomial::from_terms(vec![(0, 6)]),
+        );
+    }
+
+    #[test]
+    fn test_sub() {
+        let test = |l: SparsePolynomial<i32>, r: SparsePolynomial<i32>, expected: &SparsePolynomial<i32>| {
+            test_op_helper(
+                l,
+                r,
+                expected,
+                |l, r| *l -= r,
+                |l, r| *l -= r,
+                |l, r| l - r,
+                |l, r| l - r,
+                |l, r| l - r,
+                |l, r| l - r,
+            );
+        };
+        test(
+            SparsePolynomial::from_terms(vec![(0, 1), (3, 4)]),
+            SparsePolynomial::from_terms(vec![(0, 1), (3, -4)]),
+            &SparsePolynomial::from_terms(vec![(3, 8)]),
+        );
+    }
+
+    #[test]
+    fn test_mul() {
+        let test = |l: SparsePolynomial<i32>, r: SparsePolynomial<i32>, expected: &SparsePolynomial<i32>| {
+            test_op_helper(
+                l,
+                r,
+                expected,
+                |l, r| *l *= r,
+                |l, r| *l *= r,
+                |l, r| l * r,
+                |l, r| l * r,
+                |l, r| l * r,
+                |l, r| l * r,
+            );
+        };
+        // (x + 1) * (x - 1) == x^2 - 1
+        test(
+            SparsePolynomial::from_terms(vec![(0, 1), (1, 1)]),
+            SparsePolynomial::from_terms(vec![(0, -1), (1, 1)]),
+            &SparsePolynomial::from_terms(vec![(0, -1), (2, 1)]),
+        );
+    }
+}
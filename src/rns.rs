@@ -0,0 +1,251 @@
+// SPDX-License-Identifier: LGPL-2.1-or-later
+// See Notices.txt for copyright information
+
+#![allow(dead_code)]
+
+//! residue number system (RNS) integers: values represented by their
+//! residues modulo a basis of pairwise coprime word-size moduli instead of
+//! as a single big integer; arithmetic on residues is independent across
+//! the basis, which is what makes RNS representations fast for
+//! multi-modular algorithms
+
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::ToPrimitive;
+use std::sync::Arc;
+
+/// a basis of pairwise coprime, nonzero word-size moduli used by [`RnsInteger`]
+///
+/// coprimality of the moduli is not checked; using a basis whose moduli
+/// aren't pairwise coprime makes reconstruction produce meaningless results
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct RnsBasis {
+    moduli: Vec<u64>,
+}
+
+impl RnsBasis {
+    pub(crate) fn new(moduli: Vec<u64>) -> Self {
+        assert!(
+            !moduli.is_empty(),
+            "RNS basis must have at least one modulus"
+        );
+        assert!(
+            moduli.iter().all(|&modulus| modulus != 0),
+            "RNS moduli must be nonzero"
+        );
+        RnsBasis { moduli }
+    }
+    pub(crate) fn moduli(&self) -> &[u64] {
+        &self.moduli
+    }
+    pub(crate) fn len(&self) -> usize {
+        self.moduli.len()
+    }
+    pub(crate) fn is_empty(&self) -> bool {
+        self.moduli.is_empty()
+    }
+    /// the product of all moduli in the basis, i.e. the number of distinct
+    /// values that can be exactly represented in this basis
+    pub(crate) fn product(&self) -> BigInt {
+        self.moduli.iter().map(|&modulus| BigInt::from(modulus)).product()
+    }
+}
+
+/// the inverse of `a` modulo `modulus`, assuming `gcd(a, modulus) == 1`
+fn mod_inverse_u64(a: u64, modulus: u64) -> u64 {
+    let (mut old_r, mut r) = (a as i128, modulus as i128);
+    let (mut old_s, mut s) = (1i128, 0i128);
+    while r != 0 {
+        let quotient = old_r / r;
+        old_r -= quotient * r;
+        std::mem::swap(&mut old_r, &mut r);
+        old_s -= quotient * s;
+        std::mem::swap(&mut old_s, &mut s);
+    }
+    old_s.rem_euclid(modulus as i128) as u64
+}
+
+/// an integer represented by its residues against a fixed [`RnsBasis`] of
+/// pairwise coprime word-size moduli
+///
+/// values are always in the range `0..basis.product()`; there is no
+/// separate sign representation
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct RnsInteger {
+    residues: Vec<u64>,
+    basis: Arc<RnsBasis>,
+}
+
+impl RnsInteger {
+    /// `residues[i]` must be less than `basis.moduli()[i]`
+    pub(crate) fn new(residues: Vec<u64>, basis: Arc<RnsBasis>) -> Self {
+        assert_eq!(
+            residues.len(),
+            basis.len(),
+            "wrong number of residues for basis"
+        );
+        assert!(
+            residues
+                .iter()
+                .zip(basis.moduli())
+                .all(|(&residue, &modulus)| residue < modulus),
+            "residue out of range for its modulus"
+        );
+        RnsInteger { residues, basis }
+    }
+    pub(crate) fn zero(basis: Arc<RnsBasis>) -> Self {
+        let residues = vec![0; basis.len()];
+        RnsInteger { residues, basis }
+    }
+    /// converts `value` to its residues against `basis`; `value` is reduced
+    /// into the range `0..basis.product()` first
+    pub(crate) fn from_bigint(value: &BigInt, basis: Arc<RnsBasis>) -> Self {
+        let residues = basis
+            .moduli()
+            .iter()
+            .map(|&modulus| {
+                value
+                    .mod_floor(&BigInt::from(modulus))
+                    .to_u64()
+                    .expect("residue always fits in a u64")
+            })
+            .collect();
+        RnsInteger { residues, basis }
+    }
+    pub(crate) fn residues(&self) -> &[u64] {
+        &self.residues
+    }
+    pub(crate) fn basis(&self) -> &Arc<RnsBasis> {
+        &self.basis
+    }
+    fn require_matching_basis(&self, rhs: &Self) {
+        assert_eq!(self.basis, rhs.basis, "RNS bases don't match");
+    }
+    pub(crate) fn add(&self, rhs: &Self) -> Self {
+        self.require_matching_basis(rhs);
+        let residues = self
+            .residues
+            .iter()
+            .zip(&rhs.residues)
+            .zip(self.basis.moduli())
+            .map(|((&a, &b), &modulus)| ((a as u128 + b as u128) % modulus as u128) as u64)
+            .collect();
+        RnsInteger {
+            residues,
+            basis: self.basis.clone(),
+        }
+    }
+    pub(crate) fn sub(&self, rhs: &Self) -> Self {
+        self.require_matching_basis(rhs);
+        let residues = self
+            .residues
+            .iter()
+            .zip(&rhs.residues)
+            .zip(self.basis.moduli())
+            .map(|((&a, &b), &modulus)| {
+                ((a as u128 + modulus as u128 - b as u128) % modulus as u128) as u64
+            })
+            .collect();
+        RnsInteger {
+            residues,
+            basis: self.basis.clone(),
+        }
+    }
+    pub(crate) fn mul(&self, rhs: &Self) -> Self {
+        self.require_matching_basis(rhs);
+        let residues = self
+            .residues
+            .iter()
+            .zip(&rhs.residues)
+            .zip(self.basis.moduli())
+            .map(|((&a, &b), &modulus)| ((a as u128 * b as u128) % modulus as u128) as u64)
+            .collect();
+        RnsInteger {
+            residues,
+            basis: self.basis.clone(),
+        }
+    }
+    pub(crate) fn neg(&self) -> Self {
+        let residues = self
+            .residues
+            .iter()
+            .zip(self.basis.moduli())
+            .map(|(&a, &modulus)| if a == 0 { 0 } else { modulus - a })
+            .collect();
+        RnsInteger {
+            residues,
+            basis: self.basis.clone(),
+        }
+    }
+    /// exactly reconstructs the value represented by `self` as a `BigInt`
+    /// in the range `0..self.basis().product()`, using Garner's algorithm
+    /// to compute the mixed-radix representation of the value one modulus
+    /// at a time
+    pub(crate) fn to_bigint(&self) -> BigInt {
+        let moduli = self.basis.moduli();
+        let mut mixed_radix_digits: Vec<u64> = Vec::with_capacity(moduli.len());
+        for (i, &modulus_i) in moduli.iter().enumerate() {
+            let mut value = self.residues[i] as i128;
+            for (j, &digit_j) in mixed_radix_digits.iter().enumerate() {
+                let difference = (value - digit_j as i128).rem_euclid(modulus_i as i128);
+                let inverse = mod_inverse_u64(moduli[j], modulus_i) as i128;
+                value = difference * inverse % modulus_i as i128;
+            }
+            mixed_radix_digits.push(value as u64);
+        }
+        let mut value = BigInt::from(*mixed_radix_digits.last().expect("basis is non-empty"));
+        for i in (0..mixed_radix_digits.len() - 1).rev() {
+            value = value * moduli[i] + mixed_radix_digits[i];
+        }
+        value
+    }
+    /// re-expresses `self` against `new_basis` by exactly reconstructing
+    /// its value and reducing it into the new basis; `new_basis` must have
+    /// a product at least as large as `self.basis().product()` for the
+    /// result to represent the same value rather than being reduced modulo
+    /// `new_basis.product()`
+    pub(crate) fn base_extend(&self, new_basis: Arc<RnsBasis>) -> Self {
+        Self::from_bigint(&self.to_bigint(), new_basis)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let basis = Arc::new(RnsBasis::new(vec![7, 11, 13]));
+        for value in 0..(7 * 11 * 13) {
+            let rns = RnsInteger::from_bigint(&BigInt::from(value), basis.clone());
+            assert_eq!(rns.to_bigint(), BigInt::from(value));
+        }
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let basis = Arc::new(RnsBasis::new(vec![7, 11, 13]));
+        let product = 7 * 11 * 13;
+        let a = RnsInteger::from_bigint(&BigInt::from(500), basis.clone());
+        let b = RnsInteger::from_bigint(&BigInt::from(800), basis.clone());
+        assert_eq!(a.add(&b).to_bigint(), BigInt::from((500 + 800) % product));
+        assert_eq!(
+            a.sub(&b).to_bigint(),
+            BigInt::from((500 - 800i64).rem_euclid(product))
+        );
+        assert_eq!(a.mul(&b).to_bigint(), BigInt::from((500 * 800) % product));
+        assert_eq!(
+            a.neg().to_bigint(),
+            BigInt::from((-500i64).rem_euclid(product))
+        );
+    }
+
+    #[test]
+    fn test_base_extend() {
+        let small_basis = Arc::new(RnsBasis::new(vec![7, 11]));
+        let large_basis = Arc::new(RnsBasis::new(vec![7, 11, 13, 17]));
+        let value = RnsInteger::from_bigint(&BigInt::from(42), small_basis);
+        let extended = value.base_extend(large_basis);
+        assert_eq!(extended.to_bigint(), BigInt::from(42));
+    }
+}
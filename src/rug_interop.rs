@@ -0,0 +1,159 @@
+// SPDX-License-Identifier: LGPL-2.1-or-later
+// See Notices.txt for copyright information
+
+#![cfg(feature = "rug")]
+
+//! conversions between [`DyadicFractionInterval`] and `rug`'s
+//! arbitrary-precision `Rational`/`Float` types, for handing enclosures
+//! back and forth with MPFR-based pipelines; conversions go through the
+//! types' limbs/digits rather than through decimal strings
+
+use crate::interval_arithmetic::{DyadicFractionInterval, RoundingMode};
+use num_bigint::{BigInt, Sign};
+use num_rational::Ratio;
+use rug::{float::Round, integer::Order, Float, Integer, Rational};
+use std::cmp::Ordering;
+
+fn bigint_to_rug_integer(value: &BigInt) -> Integer {
+    let (sign, bytes) = value.to_bytes_le();
+    let mut result = Integer::from_digits(&bytes, Order::Lsf);
+    if sign == Sign::Minus {
+        result = -result;
+    }
+    result
+}
+
+fn rug_integer_to_bigint(value: &Integer) -> BigInt {
+    let bytes = value.to_digits::<u8>(Order::Lsf);
+    let sign = if value.cmp0() == Ordering::Less {
+        Sign::Minus
+    } else {
+        Sign::Plus
+    };
+    BigInt::from_bytes_le(sign, &bytes)
+}
+
+/// maps [`RoundingMode`] onto `rug`'s rounding directions; `outward_round`
+/// is used for [`RoundingMode::Outward`] since which direction is
+/// "outward" depends on whether a lower or upper bound is being rounded
+fn rounding_mode_to_rug_round(rounding: RoundingMode, outward_round: Round) -> Round {
+    match rounding {
+        RoundingMode::Floor => Round::Down,
+        RoundingMode::Ceil => Round::Up,
+        RoundingMode::Nearest => Round::Nearest,
+        RoundingMode::Outward => outward_round,
+    }
+}
+
+fn ratio_to_rug_rational(value: &Ratio<BigInt>) -> Rational {
+    Rational::from((
+        bigint_to_rug_integer(value.numer()),
+        bigint_to_rug_integer(value.denom()),
+    ))
+}
+
+fn rug_rational_to_ratio(value: &Rational) -> Ratio<BigInt> {
+    Ratio::new(
+        rug_integer_to_bigint(value.numer()),
+        rug_integer_to_bigint(value.denom()),
+    )
+}
+
+impl DyadicFractionInterval {
+    /// the exact value of the lower bound, as a `rug::Rational`
+    pub fn lower_bound_rug_rational(&self) -> Rational {
+        ratio_to_rug_rational(&self.lower_bound())
+    }
+    /// the exact value of the upper bound, as a `rug::Rational`
+    pub fn upper_bound_rug_rational(&self) -> Rational {
+        ratio_to_rug_rational(&self.upper_bound())
+    }
+    /// builds an interval that exactly represents `[lower_bound, upper_bound]`
+    pub fn from_rug_rational_range(
+        lower_bound: &Rational,
+        upper_bound: &Rational,
+        log2_denom: usize,
+    ) -> Self {
+        Self::from_ratio_range(
+            rug_rational_to_ratio(lower_bound),
+            rug_rational_to_ratio(upper_bound),
+            log2_denom,
+        )
+    }
+    /// the lower bound, rounded down to a `rug::Float` with `precision`
+    /// bits, so the result never overestimates the true lower bound
+    pub fn lower_bound_rug_float(&self, precision: u32) -> Float {
+        self.lower_bound_rug_float_rounding(precision, RoundingMode::Floor)
+    }
+    /// the upper bound, rounded up to a `rug::Float` with `precision`
+    /// bits, so the result never underestimates the true upper bound
+    pub fn upper_bound_rug_float(&self, precision: u32) -> Float {
+        self.upper_bound_rug_float_rounding(precision, RoundingMode::Ceil)
+    }
+    /// like [`Self::lower_bound_rug_float`], but lets the caller pick the
+    /// rounding direction instead of always rounding down; `Outward` is
+    /// treated the same as `Floor` since a lower bound rounding down is
+    /// what keeps it a valid lower bound
+    pub fn lower_bound_rug_float_rounding(&self, precision: u32, rounding: RoundingMode) -> Float {
+        Float::with_val_round(
+            precision,
+            self.lower_bound_rug_rational(),
+            rounding_mode_to_rug_round(rounding, Round::Down),
+        )
+        .0
+    }
+    /// like [`Self::upper_bound_rug_float`], but lets the caller pick the
+    /// rounding direction instead of always rounding up; `Outward` is
+    /// treated the same as `Ceil` since an upper bound rounding up is
+    /// what keeps it a valid upper bound
+    pub fn upper_bound_rug_float_rounding(&self, precision: u32, rounding: RoundingMode) -> Float {
+        Float::with_val_round(
+            precision,
+            self.upper_bound_rug_rational(),
+            rounding_mode_to_rug_round(rounding, Round::Up),
+        )
+        .0
+    }
+    /// builds an interval that is guaranteed to contain `[lower_bound,
+    /// upper_bound]`; finite `Float`s are exactly representable as
+    /// fractions, so converting them loses no precision
+    pub fn from_rug_float_range(lower_bound: &Float, upper_bound: &Float, log2_denom: usize) -> Self {
+        let lower_bound = lower_bound
+            .to_rational()
+            .expect("lower_bound must be finite");
+        let upper_bound = upper_bound
+            .to_rational()
+            .expect("upper_bound must be finite");
+        Self::from_rug_rational_range(&lower_bound, &upper_bound, log2_denom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_traits::One;
+
+    #[test]
+    fn test_rug_rational_round_trip() {
+        let interval = DyadicFractionInterval::from_ratio_range(
+            Ratio::new(BigInt::from(-5), BigInt::from(3)),
+            Ratio::new(BigInt::from(7), BigInt::from(2)),
+            8,
+        );
+        let lower = interval.lower_bound_rug_rational();
+        let upper = interval.upper_bound_rug_rational();
+        let round_tripped = DyadicFractionInterval::from_rug_rational_range(&lower, &upper, 8);
+        assert!(round_tripped.contains_interval(&interval));
+        assert!(interval.contains_interval(&round_tripped));
+    }
+
+    #[test]
+    fn test_rug_float_bounds_are_conservative() {
+        let interval =
+            DyadicFractionInterval::from_ratio(Ratio::new(BigInt::one(), BigInt::from(3)), 32);
+        let lower = interval.lower_bound_rug_float(53);
+        let upper = interval.upper_bound_rug_float(53);
+        assert!(rug_rational_to_ratio(&lower.to_rational().unwrap()) <= interval.lower_bound());
+        assert!(rug_rational_to_ratio(&upper.to_rational().unwrap()) >= interval.upper_bound());
+    }
+}
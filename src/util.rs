@@ -4,6 +4,7 @@ use crate::{
     mod_int::{ModularInteger, ModularReducePow, Modulus},
     traits::{CharacteristicZero, FloorLog2, RingCharacteristic, TrailingZeros},
 };
+use num_bigint::{BigInt, BigUint};
 use num_integer::{Integer, Roots};
 use num_traits::{FromPrimitive, One, ToPrimitive, Zero};
 use std::{
@@ -593,6 +594,7 @@ fn is_prime_check_small_divisors(v: u128) -> Option<bool> {
     None
 }
 
+/// returns `true` if `base` does not prove that `n` is composite
 fn is_pseudo_prime_miller_rabin_test_for_base<T: IsPseudoPrime>(
     n: &T,
     d: &T,
@@ -601,13 +603,16 @@ fn is_pseudo_prime_miller_rabin_test_for_base<T: IsPseudoPrime>(
 ) -> bool {
     let x = base.pow_modular_reduce(d, &n);
     if x.is_one() || (n.clone() - x.clone()).is_one() {
-        return false;
+        return true;
     }
     let mut x = ModularInteger::new(x, n);
     for _ in 0..(r - 1) {
         x *= x.clone();
+        if (n.clone() - x.value().clone()).is_one() {
+            return true;
+        }
     }
-    unimplemented!()
+    false
 }
 
 pub trait IsPseudoPrime:
@@ -676,6 +681,92 @@ pub trait IsPseudoPrime:
     }
 }
 
+/// factors `n` (which must be positive) into its prime factors with
+/// multiplicity, using trial division by small primes and treating any
+/// large remaining cofactor as prime
+pub fn factorize<T: IsPseudoPrime>(mut n: T) -> Vec<(T, u32)> {
+    assert!(n > T::zero(), "can only factor positive values");
+    let mut factors = Vec::new();
+    for &small_prime in PRIMES_THAT_FIT_IN_U16 as &[_] {
+        let prime = T::from_u16(small_prime).expect("can't convert small prime to T");
+        if prime.clone() * prime.clone() > n {
+            break;
+        }
+        if n.is_multiple_of(&prime) {
+            let mut exponent = 0u32;
+            while n.is_multiple_of(&prime) {
+                n = n / prime.clone();
+                exponent += 1;
+            }
+            factors.push((prime, exponent));
+        }
+    }
+    if !n.is_one() {
+        factors.push((n, 1));
+    }
+    factors
+}
+
+/// Euler's totient function: the number of integers in `1..=n` coprime to `n`
+pub fn euler_phi<T: IsPseudoPrime>(n: &T) -> T {
+    assert!(*n > T::zero(), "euler_phi is only defined for positive integers");
+    if n.is_one() {
+        return T::one();
+    }
+    let mut result = n.clone();
+    for (prime, _) in factorize(n.clone()) {
+        result = result.clone() - result / prime;
+    }
+    result
+}
+
+/// the Carmichael function: the exponent of the multiplicative group of
+/// integers modulo `n`, i.e. the smallest `m` such that `a.pow(m) == 1`
+/// for every `a` coprime to `n`
+pub fn carmichael_lambda<T: IsPseudoPrime>(n: &T) -> T {
+    assert!(
+        *n > T::zero(),
+        "carmichael_lambda is only defined for positive integers"
+    );
+    if n.is_one() {
+        return T::one();
+    }
+    let two = T::from_u8(2).expect("2 doesn't fit in T");
+    let mut result = T::one();
+    for (prime, exponent) in factorize(n.clone()) {
+        let component = if prime == two && exponent >= 3 {
+            let mut power = T::one();
+            for _ in 0..(exponent - 2) {
+                power = power * two.clone();
+            }
+            power
+        } else {
+            let mut power = T::one();
+            for _ in 0..(exponent - 1) {
+                power = power * prime.clone();
+            }
+            power * (prime - T::one())
+        };
+        result = result.lcm(&component);
+    }
+    result
+}
+
+impl IsPseudoPrime for u8 {}
+impl IsPseudoPrime for i8 {}
+impl IsPseudoPrime for u16 {}
+impl IsPseudoPrime for i16 {}
+impl IsPseudoPrime for u32 {}
+impl IsPseudoPrime for i32 {}
+impl IsPseudoPrime for u64 {}
+impl IsPseudoPrime for i64 {}
+impl IsPseudoPrime for u128 {}
+impl IsPseudoPrime for i128 {}
+impl IsPseudoPrime for usize {}
+impl IsPseudoPrime for isize {}
+impl IsPseudoPrime for BigUint {}
+impl IsPseudoPrime for BigInt {}
+
 #[derive(Copy, Clone, Hash, Eq, PartialEq, Debug, Default)]
 pub struct NotAPseudoPrimePower;
 
@@ -1085,6 +1176,24 @@ impl<T: fmt::Display> fmt::Debug for DebugAsDisplay<T> {
 pub(crate) mod tests {
     use std::fmt;
 
+    #[test]
+    fn test_euler_phi() {
+        use super::*;
+        assert_eq!(euler_phi(&1i64), 1);
+        assert_eq!(euler_phi(&9i64), 6);
+        assert_eq!(euler_phi(&36i64), 12);
+        assert_eq!(euler_phi(&97i64), 96);
+    }
+
+    #[test]
+    fn test_carmichael_lambda() {
+        use super::*;
+        assert_eq!(carmichael_lambda(&1i64), 1);
+        assert_eq!(carmichael_lambda(&8i64), 2);
+        assert_eq!(carmichael_lambda(&20i64), 4);
+        assert_eq!(carmichael_lambda(&97i64), 96);
+    }
+
     #[test]
     fn test_for_subsets_of_size() {
         use super::*;